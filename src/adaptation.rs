@@ -0,0 +1,236 @@
+use crate::converter::Conversion;
+use crate::errors;
+use crate::errors::Result;
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::white_point::WhitePoint;
+use crate::xyz::XyzColor;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AdaptationMethod {
+    Bradford,
+    VonKries,
+    Cat02,
+    Cat16,
+}
+
+impl AdaptationMethod {
+    // XYZ => cone response (LMS)
+    pub fn cone_response_matrix(&self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Bradford => [
+                [0.8951, 0.2664, -0.1614],
+                [-0.7502, 1.7135, 0.0367],
+                [0.0389, -0.0685, 1.0296],
+            ],
+            Self::VonKries => [
+                [0.40024, 0.70760, -0.08081],
+                [-0.22630, 1.16532, 0.04570],
+                [0f64, 0f64, 0.91822],
+            ],
+            Self::Cat02 => [
+                [0.7328, 0.4296, -0.1624],
+                [-0.7036, 1.6975, 0.0061],
+                [0.0030, 0.0136, 0.9834],
+            ],
+            Self::Cat16 => [
+                [0.401288, 0.650173, -0.051461],
+                [-0.250268, 1.204414, 0.045854],
+                [-0.002079, 0.048952, 0.953127],
+            ],
+        }
+    }
+}
+
+// http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ChromaticAdaptation {
+    source: WhitePoint,
+    destination: WhitePoint,
+    method: AdaptationMethod,
+    matrix: Matrix3,
+}
+
+impl ChromaticAdaptation {
+    pub fn new(source: WhitePoint, destination: WhitePoint) -> Result<Self> {
+        Self::with_method(source, destination, AdaptationMethod::Bradford)
+    }
+
+    // white points need a positive luminance and non-zero cone responses
+    pub fn with_method(
+        source: WhitePoint,
+        destination: WhitePoint,
+        method: AdaptationMethod,
+    ) -> Result<Self> {
+        let cone = method.cone_response_matrix();
+        // the cone response matrices above are all invertible
+        let inverse_cone = matrix::inverse(&cone).unwrap();
+
+        let source_lms = cone_response(&cone, &source)?;
+        let destination_lms = cone_response(&cone, &destination)?;
+        let scale = matrix::diagonal([
+            destination_lms[0] / source_lms[0],
+            destination_lms[1] / source_lms[1],
+            destination_lms[2] / source_lms[2],
+        ]);
+
+        Ok(Self {
+            source,
+            destination,
+            method,
+            matrix: matrix::multiply(&inverse_cone, &matrix::multiply(&scale, &cone)),
+        })
+    }
+
+    pub fn source(&self) -> WhitePoint {
+        self.source
+    }
+
+    pub fn destination(&self) -> WhitePoint {
+        self.destination
+    }
+
+    pub fn method(&self) -> AdaptationMethod {
+        self.method
+    }
+
+    pub fn matrix(&self) -> [[f64; 3]; 3] {
+        self.matrix
+    }
+
+    pub fn adapt(&self, color: &XyzColor) -> XyzColor {
+        XyzColor::from_array(
+            matrix::multiply_vector(&self.matrix, color.to_array()),
+            color.a(),
+        )
+    }
+}
+
+// white points that can be adapted with Bradford
+pub(crate) fn validate_white_point(white: &WhitePoint) -> Result<()> {
+    cone_response(&AdaptationMethod::Bradford.cone_response_matrix(), white).map(|_| ())
+}
+
+fn cone_response(cone: &Matrix3, white: &WhitePoint) -> Result<[f64; 3]> {
+    let xyz = white.xyz();
+    let lms = matrix::multiply_vector(cone, xyz.to_array());
+    if xyz.y().is_nan() || xyz.y() <= 0_f64 || lms.iter().any(|v| !v.is_finite() || *v == 0_f64) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("{}", white),
+        ));
+    }
+    Ok(lms)
+}
+
+impl Conversion<&XyzColor, XyzColor> for ChromaticAdaptation {
+    fn convert(&self, color: &XyzColor) -> Result<XyzColor> {
+        Ok(self.adapt(color))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_xyz_eq(actual: XyzColor, expected: XyzColor, tolerance: f64) {
+        assert!(
+            (actual.x() - expected.x()).abs() < tolerance,
+            "{} {}",
+            actual,
+            expected
+        );
+        assert!(
+            (actual.y() - expected.y()).abs() < tolerance,
+            "{} {}",
+            actual,
+            expected
+        );
+        assert!(
+            (actual.z() - expected.z()).abs() < tolerance,
+            "{} {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn adaptation_white_to_white_test() {
+        let methods = [
+            AdaptationMethod::Bradford,
+            AdaptationMethod::VonKries,
+            AdaptationMethod::Cat02,
+            AdaptationMethod::Cat16,
+        ];
+        for method in methods.iter() {
+            let adaptation =
+                ChromaticAdaptation::with_method(WhitePoint::D65, WhitePoint::D50, *method)
+                    .unwrap();
+            assert_xyz_eq(
+                adaptation.adapt(&WhitePoint::D65.xyz()),
+                WhitePoint::D50.xyz(),
+                1e-9,
+            );
+        }
+    }
+
+    #[test]
+    fn adaptation_bradford_matrix_test() {
+        // http://www.brucelindbloom.com/index.html?Eqn_ChromAdapt.html
+        let expected = [
+            [1.0478112, 0.0228866, -0.0501270],
+            [0.0295424, 0.9904844, -0.0170491],
+            [-0.0092345, 0.0150436, 0.7521316],
+        ];
+        let matrix = ChromaticAdaptation::new(WhitePoint::D65, WhitePoint::D50)
+            .unwrap()
+            .matrix();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((matrix[i][j] - expected[i][j]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn adaptation_round_trip_test() {
+        let color = XyzColor::from_xyza(0.2, 0.3, 0.4, 0.5);
+        let forward = ChromaticAdaptation::with_method(
+            WhitePoint::A,
+            WhitePoint::F2,
+            AdaptationMethod::Cat16,
+        )
+        .unwrap();
+        let backward = ChromaticAdaptation::with_method(
+            WhitePoint::F2,
+            WhitePoint::A,
+            AdaptationMethod::Cat16,
+        )
+        .unwrap();
+
+        let result = backward.convert(&forward.convert(&color).unwrap()).unwrap();
+        assert_xyz_eq(result, color, 1e-12);
+        assert_eq!(result.a(), 0.5);
+    }
+
+    #[test]
+    fn adaptation_same_white_test() {
+        let color = XyzColor::new(0.2, 0.3, 0.4);
+        let adaptation = ChromaticAdaptation::new(WhitePoint::E, WhitePoint::E).unwrap();
+        assert_xyz_eq(adaptation.adapt(&color), color, 1e-12);
+    }
+
+    #[test]
+    fn adaptation_invalid_white_test() {
+        for white in [
+            XyzColor::new(0_f64, 0_f64, 0_f64),
+            XyzColor::new(0.9, -1_f64, 0.8),
+            XyzColor::new(f64::NAN, 1_f64, 1_f64),
+        ] {
+            let white = WhitePoint::Custom(white);
+            assert!(ChromaticAdaptation::new(white, WhitePoint::D65).is_err());
+            assert!(ChromaticAdaptation::new(WhitePoint::D65, white).is_err());
+        }
+    }
+}
@@ -0,0 +1,354 @@
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+use crate::chromaticity::{Chromaticity, UvChromaticity};
+use crate::illuminant::Observer;
+use crate::spectral::SampledSpectrum;
+use crate::utils;
+use crate::xyz::XyzColor;
+
+pub const MIN_KELVIN: f64 = 1000_f64;
+pub const MAX_KELVIN: f64 = 40000_f64;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum CctMethod {
+    // A. R. Robertson, "Computation of Correlated Color Temperature and Distribution Temperature", JOSA 1968
+    // interpolates the published isotemperature lines (1667K - 40000K)
+    Robertson,
+    // Y. Ohno, "Practical Use and Calculation of CCT and Duv", LEUKOS 2014
+    #[default]
+    Ohno,
+}
+
+// correlated color temperature with distance from the Planckian locus in CIE 1960 uv
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct ColorTemperature {
+    kelvin: f64,
+    duv: f64,
+}
+
+impl Display for ColorTemperature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ColorTemperature CCT = {}K Duv = {}",
+            self.kelvin, self.duv
+        )
+    }
+}
+
+impl ColorTemperature {
+    pub fn new(kelvin: f64) -> Self {
+        Self {
+            kelvin: kelvin.clamp(MIN_KELVIN, MAX_KELVIN),
+            duv: 0_f64,
+        }
+    }
+
+    // positive Duv is above the Planckian locus (greenish), negative is below (pinkish)
+    pub fn with_duv(self, duv: f64) -> Self {
+        Self { duv, ..self }
+    }
+
+    pub fn kelvin(&self) -> f64 {
+        self.kelvin
+    }
+
+    pub fn duv(&self) -> f64 {
+        self.duv
+    }
+
+    pub fn to_uv(&self) -> UvChromaticity {
+        let point = planckian_uv(self.kelvin);
+        if self.duv == 0_f64 {
+            return point;
+        }
+        let [nu, nv] = normal(self.kelvin);
+        UvChromaticity::new(point.u() + self.duv * nu, point.v() + self.duv * nv)
+    }
+
+    pub fn to_xy(&self) -> Chromaticity {
+        self.to_uv().to_xy()
+    }
+
+    pub fn to_xyz(&self, luminance: f64) -> XyzColor {
+        self.to_xy().to_xyz(luminance)
+    }
+
+    pub fn from_uv(uv: &UvChromaticity, method: CctMethod) -> Self {
+        match method {
+            CctMethod::Robertson => robertson(uv),
+            CctMethod::Ohno => ohno(uv),
+        }
+    }
+
+    pub fn from_xy(xy: &Chromaticity, method: CctMethod) -> Self {
+        Self::from_uv(&xy.to_uv(), method)
+    }
+
+    pub fn from_xyz(color: &XyzColor, method: CctMethod) -> Self {
+        Self::from_uv(&UvChromaticity::from_xyz(color), method)
+    }
+}
+
+// the Planckian locus is sampled once every LOCUS_STEP mired and interpolated between the samples
+const LOCUS_STEP: f64 = 0.5;
+const LOCUS_MIN_MIRED: f64 = 1e6 / MAX_KELVIN - 2_f64 * LOCUS_STEP;
+
+fn planckian_locus() -> &'static Vec<UvChromaticity> {
+    static LOCUS: OnceLock<Vec<UvChromaticity>> = OnceLock::new();
+    LOCUS.get_or_init(|| {
+        let count = ((1e6 / MIN_KELVIN - LOCUS_MIN_MIRED) / LOCUS_STEP).ceil() as usize + 3;
+        (0..count)
+            .map(|i| {
+                let kelvin = 1e6 / (LOCUS_MIN_MIRED + i as f64 * LOCUS_STEP);
+                UvChromaticity::from_xyz(
+                    &SampledSpectrum::blackbody(kelvin).to_xyz(Observer::Cie1931),
+                )
+            })
+            .collect()
+    })
+}
+
+// Catmull-Rom spline through the samples of the locus
+fn planckian_uv(kelvin: f64) -> UvChromaticity {
+    let locus = planckian_locus();
+    let position = (1e6 / kelvin - LOCUS_MIN_MIRED) / LOCUS_STEP;
+    let index = utils::crop_range(position.floor() as usize, 1, locus.len() - 3);
+    let t = position - index as f64;
+    let spline = |p0: f64, p1: f64, p2: f64, p3: f64| {
+        p1 + 0.5
+            * t
+            * (p2 - p0
+                + t * (2_f64 * p0 - 5_f64 * p1 + 4_f64 * p2 - p3
+                    + t * (3_f64 * (p1 - p2) + p3 - p0)))
+    };
+    let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|i| locus[index - 1 + i]);
+    UvChromaticity::new(
+        spline(p0.u(), p1.u(), p2.u(), p3.u()),
+        spline(p0.v(), p1.v(), p2.v(), p3.v()),
+    )
+}
+
+// unit normal of the Planckian locus pointing to positive Duv
+fn normal(kelvin: f64) -> [f64; 2] {
+    let delta = kelvin * 1e-4;
+    let low = planckian_uv(kelvin - delta);
+    let high = planckian_uv(kelvin + delta);
+    let du = high.u() - low.u();
+    let dv = high.v() - low.v();
+    let length = du.hypot(dv);
+    [dv / length, -du / length]
+}
+
+fn signed_duv(uv: &UvChromaticity, kelvin: f64) -> f64 {
+    let point = planckian_uv(kelvin);
+    let [nu, nv] = normal(kelvin);
+    let distance = uv.distance(&point);
+    if (uv.u() - point.u()) * nu + (uv.v() - point.v()) * nv < 0_f64 {
+        -distance
+    } else {
+        distance
+    }
+}
+
+// Robertson's isotemperature lines (mired, u, v, slope), the table covers 1667K and above
+// G. Wyszecki, W. S. Stiles, "Color Science" (2nd ed.), the 325 mired u is the corrected 0.24792
+const ROBERTSON_LINES: [[f64; 4]; 31] = [
+    [0_f64, 0.18006, 0.26352, -0.24341],
+    [10_f64, 0.18066, 0.26589, -0.25479],
+    [20_f64, 0.18133, 0.26846, -0.26876],
+    [30_f64, 0.18208, 0.27119, -0.28539],
+    [40_f64, 0.18293, 0.27407, -0.30470],
+    [50_f64, 0.18388, 0.27709, -0.32675],
+    [60_f64, 0.18494, 0.28021, -0.35156],
+    [70_f64, 0.18611, 0.28342, -0.37915],
+    [80_f64, 0.18740, 0.28668, -0.40955],
+    [90_f64, 0.18880, 0.28997, -0.44278],
+    [100_f64, 0.19032, 0.29326, -0.47888],
+    [125_f64, 0.19462, 0.30141, -0.58204],
+    [150_f64, 0.19962, 0.30921, -0.70471],
+    [175_f64, 0.20525, 0.31647, -0.84901],
+    [200_f64, 0.21142, 0.32312, -1.0182],
+    [225_f64, 0.21807, 0.32909, -1.2168],
+    [250_f64, 0.22511, 0.33439, -1.4512],
+    [275_f64, 0.23247, 0.33904, -1.7298],
+    [300_f64, 0.24010, 0.34308, -2.0637],
+    [325_f64, 0.24792, 0.34655, -2.4681],
+    [350_f64, 0.25591, 0.34951, -2.9641],
+    [375_f64, 0.26400, 0.35200, -3.5814],
+    [400_f64, 0.27218, 0.35407, -4.3633],
+    [425_f64, 0.28039, 0.35577, -5.3762],
+    [450_f64, 0.28863, 0.35714, -6.7262],
+    [475_f64, 0.29685, 0.35823, -8.5955],
+    [500_f64, 0.30505, 0.35907, -11.324],
+    [525_f64, 0.31320, 0.35968, -15.628],
+    [550_f64, 0.32129, 0.36011, -23.325],
+    [575_f64, 0.32931, 0.36038, -40.770],
+    [600_f64, 0.33724, 0.36051, -116.45],
+];
+
+fn robertson(uv: &UvChromaticity) -> ColorTemperature {
+    // signed distance to the isotemperature line, the sign changes when crossing the line
+    let distance = |[_, u, v, slope]: [f64; 4]| {
+        ((uv.v() - v) - slope * (uv.u() - u)) / (1_f64 + slope * slope).sqrt()
+    };
+
+    let distances = ROBERTSON_LINES.map(distance);
+    let last = ROBERTSON_LINES.len() - 1;
+    let mired = match (1..ROBERTSON_LINES.len())
+        .find(|&i| (distances[i - 1] < 0_f64) != (distances[i] < 0_f64))
+    {
+        Some(i) => {
+            let ratio = distances[i - 1] / (distances[i - 1] - distances[i]);
+            let (low, high) = (ROBERTSON_LINES[i - 1][0], ROBERTSON_LINES[i][0]);
+            low + (high - low) * ratio
+        }
+        None if distances[0].abs() < distances[last].abs() => ROBERTSON_LINES[0][0],
+        None => ROBERTSON_LINES[last][0],
+    };
+
+    let kelvin = if mired > 0_f64 {
+        (1e6 / mired).clamp(MIN_KELVIN, MAX_KELVIN)
+    } else {
+        MAX_KELVIN
+    };
+    ColorTemperature::new(kelvin).with_duv(signed_duv(uv, kelvin))
+}
+
+fn ohno(uv: &UvChromaticity) -> ColorTemperature {
+    // cascade: 1% steps over the whole range, then narrow down around the nearest point
+    let mut low = MIN_KELVIN;
+    let mut high = MAX_KELVIN;
+    let mut count = ((MAX_KELVIN / MIN_KELVIN).ln() / 1.01_f64.ln()).ceil() as usize;
+    let mut table: Vec<(f64, f64)>;
+    let mut index;
+    loop {
+        let ratio = (high / low).powf(1_f64 / count as f64);
+        table = (0..=count)
+            .map(|i| {
+                let kelvin = low * ratio.powi(i as i32);
+                (kelvin, uv.distance(&planckian_uv(kelvin)))
+            })
+            .collect();
+        index = table
+            .iter()
+            .enumerate()
+            .min_by(|a, b| a.1 .1.total_cmp(&b.1 .1))
+            .map(|(i, _)| i)
+            .unwrap_or_default();
+        index = utils::crop_range(index, 1, table.len() - 2);
+        if table[index + 1].0 / table[index - 1].0 < 1.0001 {
+            break;
+        }
+        low = table[index - 1].0;
+        high = table[index + 1].0;
+        count = 10;
+    }
+
+    let (t0, d0) = table[index - 1];
+    let (t1, d1) = table[index];
+    let (t2, d2) = table[index + 1];
+
+    let uv0 = planckian_uv(t0);
+    let uv2 = planckian_uv(t2);
+    let l = uv0.distance(&uv2);
+    let x = (d0 * d0 - d2 * d2 + l * l) / (2_f64 * l);
+    let kelvin = t0 + (t2 - t0) * x / l;
+    let duv = signed_duv(uv, kelvin);
+    if duv.abs() < 0.002 {
+        return ColorTemperature::new(kelvin).with_duv(duv);
+    }
+
+    // parabolic solution
+    let denominator = (t2 - t1) * (t0 - t2) * (t1 - t0);
+    let a = (t0 * (d2 - d1) + t1 * (d0 - d2) + t2 * (d1 - d0)) / denominator;
+    let b = -(t0 * t0 * (d2 - d1) + t1 * t1 * (d0 - d2) + t2 * t2 * (d1 - d0)) / denominator;
+    let kelvin = -b / (2_f64 * a);
+    ColorTemperature::new(kelvin).with_duv(signed_duv(uv, kelvin))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_temperature_new_test() {
+        let cct = ColorTemperature::new(500_f64);
+        assert_eq!(cct.kelvin(), MIN_KELVIN);
+        assert_eq!(cct.duv(), 0_f64);
+        assert_eq!(ColorTemperature::new(1e6).kelvin(), MAX_KELVIN);
+    }
+
+    #[test]
+    fn planckian_locus_test() {
+        // CIE illuminant A (2856K)
+        let xy = ColorTemperature::new(2856_f64).to_xy();
+        assert!(xy.distance(&Chromaticity::new(0.44757, 0.40745)) < 1e-4);
+
+        let xy = ColorTemperature::new(6500_f64).to_xy();
+        assert!(xy.distance(&Chromaticity::new(0.3135, 0.3237)) < 1e-4);
+    }
+
+    #[test]
+    fn duv_offset_test() {
+        let base = ColorTemperature::new(4000_f64);
+        let above = base.with_duv(0.01).to_uv();
+        let below = base.with_duv(-0.01).to_uv();
+        assert!(above.v() > base.to_uv().v());
+        assert!(below.v() < base.to_uv().v());
+        assert!((above.distance(&base.to_uv()) - 0.01).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cct_round_trip_test() {
+        // the published lines are rounded to five decimals
+        for (method, tolerance) in [(CctMethod::Robertson, 5e-3), (CctMethod::Ohno, 1e-3)] {
+            for kelvin in [
+                1000_f64, 1500_f64, 2700_f64, 4000_f64, 6500_f64, 10000_f64, 25000_f64,
+            ] {
+                // the published lines end at 600 mired
+                if method == CctMethod::Robertson && kelvin < 1e6 / 600_f64 {
+                    continue;
+                }
+                for duv in [-0.02, 0_f64, 0.01] {
+                    let uv = ColorTemperature::new(kelvin).with_duv(duv).to_uv();
+                    let actual = ColorTemperature::from_uv(&uv, method);
+                    assert!(
+                        (actual.kelvin() - kelvin).abs() / kelvin < tolerance,
+                        "{:?} {} {}",
+                        method,
+                        kelvin,
+                        actual
+                    );
+                    assert!((actual.duv() - duv).abs() < 1e-4, "{:?} {}", method, actual);
+                }
+            }
+        }
+
+        let uv = ColorTemperature::new(1000_f64).to_uv();
+        let actual = ColorTemperature::from_uv(&uv, CctMethod::Robertson);
+        assert!((actual.kelvin() - 1e6 / 600_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn robertson_lines_test() {
+        // the published lines lie on the locus and are normal to it
+        for [mired, u, v, slope] in ROBERTSON_LINES.into_iter().skip(1) {
+            let kelvin = 1e6 / mired;
+            assert!(planckian_uv(kelvin).distance(&UvChromaticity::new(u, v)) < 1e-4);
+            let [nu, nv] = normal(kelvin);
+            assert!(((nv / nu).atan() - slope.atan()).abs() < 0.02, "{}", mired);
+        }
+    }
+
+    #[test]
+    fn d65_cct_test() {
+        let d65 = Chromaticity::new(0.31271, 0.32902);
+        for method in [CctMethod::Robertson, CctMethod::Ohno] {
+            let cct = ColorTemperature::from_xy(&d65, method);
+            assert!((cct.kelvin() - 6504_f64).abs() < 3_f64, "{}", cct);
+            assert!((cct.duv() - 0.0032).abs() < 1e-4, "{}", cct);
+        }
+    }
+}
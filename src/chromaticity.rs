@@ -0,0 +1,224 @@
+use std::fmt::Display;
+
+use crate::xyz::XyzColor;
+
+// CIE 1931 xy chromaticity coordinates
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct Chromaticity {
+    x: f64,
+    y: f64,
+}
+
+impl Display for Chromaticity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Chromaticity x = {} y = {}", self.x, self.y)
+    }
+}
+
+impl Chromaticity {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    pub fn from_xyz(color: &XyzColor) -> Self {
+        let sum = color.x() + color.y() + color.z();
+        if sum == 0_f64 {
+            return Self::default();
+        }
+        Self::new(color.x() / sum, color.y() / sum)
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn to_xyz(&self, luminance: f64) -> XyzColor {
+        if self.y == 0_f64 {
+            return XyzColor::new(0_f64, 0_f64, 0_f64);
+        }
+        XyzColor::new(
+            self.x * luminance / self.y,
+            luminance,
+            (1_f64 - self.x - self.y) * luminance / self.y,
+        )
+    }
+
+    pub fn to_uv(&self) -> UvChromaticity {
+        UvChromaticity::from_xy(self)
+    }
+
+    pub fn to_uv_prime(&self) -> UvPrimeChromaticity {
+        UvPrimeChromaticity::from_xy(self)
+    }
+
+    // Δxy
+    pub fn distance(&self, other: &Chromaticity) -> f64 {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+}
+
+// CIE 1960 UCS uv chromaticity coordinates
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct UvChromaticity {
+    u: f64,
+    v: f64,
+}
+
+impl Display for UvChromaticity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UvChromaticity u = {} v = {}", self.u, self.v)
+    }
+}
+
+impl UvChromaticity {
+    pub fn new(u: f64, v: f64) -> Self {
+        Self { u, v }
+    }
+
+    pub fn from_xy(xy: &Chromaticity) -> Self {
+        let denominator = -2_f64 * xy.x() + 12_f64 * xy.y() + 3_f64;
+        Self::new(4_f64 * xy.x() / denominator, 6_f64 * xy.y() / denominator)
+    }
+
+    pub fn from_xyz(color: &XyzColor) -> Self {
+        Self::from_xy(&Chromaticity::from_xyz(color))
+    }
+
+    pub fn to_xy(&self) -> Chromaticity {
+        let denominator = 2_f64 * self.u - 8_f64 * self.v + 4_f64;
+        Chromaticity::new(3_f64 * self.u / denominator, 2_f64 * self.v / denominator)
+    }
+
+    pub fn u(&self) -> f64 {
+        self.u
+    }
+
+    pub fn v(&self) -> f64 {
+        self.v
+    }
+
+    // Δuv
+    pub fn distance(&self, other: &UvChromaticity) -> f64 {
+        (self.u - other.u).hypot(self.v - other.v)
+    }
+}
+
+// CIE 1976 UCS u'v' chromaticity coordinates
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct UvPrimeChromaticity {
+    u: f64,
+    v: f64,
+}
+
+impl Display for UvPrimeChromaticity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "UvPrimeChromaticity u' = {} v' = {}", self.u, self.v)
+    }
+}
+
+impl UvPrimeChromaticity {
+    pub fn new(u: f64, v: f64) -> Self {
+        Self { u, v }
+    }
+
+    pub fn from_xy(xy: &Chromaticity) -> Self {
+        let denominator = -2_f64 * xy.x() + 12_f64 * xy.y() + 3_f64;
+        Self::new(4_f64 * xy.x() / denominator, 9_f64 * xy.y() / denominator)
+    }
+
+    pub fn from_xyz(color: &XyzColor) -> Self {
+        Self::from_xy(&Chromaticity::from_xyz(color))
+    }
+
+    pub fn to_xy(&self) -> Chromaticity {
+        let denominator = 6_f64 * self.u - 16_f64 * self.v + 12_f64;
+        Chromaticity::new(9_f64 * self.u / denominator, 4_f64 * self.v / denominator)
+    }
+
+    pub fn to_uv(&self) -> UvChromaticity {
+        UvChromaticity::new(self.u, self.v * 2_f64 / 3_f64)
+    }
+
+    pub fn u(&self) -> f64 {
+        self.u
+    }
+
+    pub fn v(&self) -> f64 {
+        self.v
+    }
+
+    // Δu'v'
+    pub fn distance(&self, other: &UvPrimeChromaticity) -> f64 {
+        (self.u - other.u).hypot(self.v - other.v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chromaticity_to_xyz_test() {
+        let xyz = Chromaticity::new(0.3127, 0.3290).to_xyz(1_f64);
+        assert!((xyz.x() - 0.950456).abs() < 1e-6);
+        assert_eq!(xyz.y(), 1_f64);
+        assert!((xyz.z() - 1.089058).abs() < 1e-6);
+    }
+
+    #[test]
+    fn chromaticity_from_xyz_test() {
+        let xy = Chromaticity::from_xyz(&XyzColor::new(0.95047, 1_f64, 1.08883));
+        assert!((xy.x() - 0.312727).abs() < 1e-6);
+        assert!((xy.y() - 0.329023).abs() < 1e-6);
+
+        assert_eq!(
+            Chromaticity::from_xyz(&XyzColor::new(0_f64, 0_f64, 0_f64)),
+            Chromaticity::default()
+        );
+    }
+
+    #[test]
+    fn uv_chromaticity_test() {
+        // D65
+        let xy = Chromaticity::new(0.3127, 0.3290);
+        let uv = xy.to_uv();
+        assert!((uv.u() - 0.197830).abs() < 1e-6);
+        assert!((uv.v() - 0.312213).abs() < 1e-6);
+
+        let actual = uv.to_xy();
+        assert!((actual.x() - xy.x()).abs() < 1e-12);
+        assert!((actual.y() - xy.y()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn uv_prime_chromaticity_test() {
+        // D65
+        let xy = Chromaticity::new(0.3127, 0.3290);
+        let uv = xy.to_uv_prime();
+        assert!((uv.u() - 0.197830).abs() < 1e-6);
+        assert!((uv.v() - 0.468320).abs() < 1e-6);
+        let (actual, expected) = (uv.to_uv(), xy.to_uv());
+        assert!((actual.u() - expected.u()).abs() < 1e-12);
+        assert!((actual.v() - expected.v()).abs() < 1e-12);
+
+        let actual = uv.to_xy();
+        assert!((actual.x() - xy.x()).abs() < 1e-12);
+        assert!((actual.y() - xy.y()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn chromaticity_distance_test() {
+        let a = Chromaticity::new(0.64, 0.33);
+        let b = Chromaticity::new(0.643, 0.334);
+        assert!((a.distance(&b) - 0.005).abs() < 1e-12);
+
+        let a = UvPrimeChromaticity::new(0.2, 0.4);
+        let b = UvPrimeChromaticity::new(0.203, 0.404);
+        assert!((a.distance(&b) - 0.005).abs() < 1e-12);
+        assert_eq!(a.distance(&a), 0_f64);
+    }
+}
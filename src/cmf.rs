@@ -0,0 +1,260 @@
+// CIE standard observer color matching functions and daylight basis functions (CIE 15:2004)
+pub(crate) const MIN_WAVELENGTH: u32 = 380;
+pub(crate) const MAX_WAVELENGTH: u32 = 780;
+pub(crate) const WAVELENGTH_STEP: u32 = 5;
+
+// second radiation constant (m K) used by CIE 15
+const C2: f64 = 1.4388e-2;
+
+// CIE 1931 2° standard observer (Table T.4, 5nm)
+pub(crate) const CIE1931: [[f64; 3]; 81] = [
+    [0.001368, 3.9e-05, 0.00645],
+    [0.002236, 6.4e-05, 0.01055],
+    [0.004243, 0.00012, 0.02005],
+    [0.00765, 0.000217, 0.03621],
+    [0.01431, 0.000396, 0.06785],
+    [0.02319, 0.00064, 0.1102],
+    [0.04351, 0.00121, 0.2074],
+    [0.07763, 0.00218, 0.3713],
+    [0.13438, 0.004, 0.6456],
+    [0.21477, 0.0073, 1.03905],
+    [0.2839, 0.0116, 1.3856],
+    [0.3285, 0.01684, 1.62296],
+    [0.34828, 0.023, 1.74706],
+    [0.34806, 0.0298, 1.7826],
+    [0.3362, 0.038, 1.77211],
+    [0.3187, 0.048, 1.7441],
+    [0.2908, 0.06, 1.6692],
+    [0.2511, 0.0739, 1.5281],
+    [0.19536, 0.09098, 1.28764],
+    [0.1421, 0.1126, 1.0419],
+    [0.09564, 0.13902, 0.81295],
+    [0.05795, 0.1693, 0.6162],
+    [0.03201, 0.20802, 0.46518],
+    [0.0147, 0.2586, 0.3533],
+    [0.0049, 0.323, 0.272],
+    [0.0024, 0.4073, 0.2123],
+    [0.0093, 0.503, 0.1582],
+    [0.0291, 0.6082, 0.1117],
+    [0.06327, 0.71, 0.07825],
+    [0.1096, 0.7932, 0.05725],
+    [0.1655, 0.862, 0.04216],
+    [0.22575, 0.91485, 0.02984],
+    [0.2904, 0.954, 0.0203],
+    [0.3597, 0.9803, 0.0134],
+    [0.43345, 0.99495, 0.00875],
+    [0.51205, 1.0, 0.00575],
+    [0.5945, 0.995, 0.0039],
+    [0.6784, 0.9786, 0.00275],
+    [0.7621, 0.952, 0.0021],
+    [0.8425, 0.9154, 0.0018],
+    [0.9163, 0.87, 0.00165],
+    [0.9786, 0.8163, 0.0014],
+    [1.0263, 0.757, 0.0011],
+    [1.0567, 0.6949, 0.001],
+    [1.0622, 0.631, 0.0008],
+    [1.0456, 0.5668, 0.0006],
+    [1.0026, 0.503, 0.00034],
+    [0.9384, 0.4412, 0.00024],
+    [0.85445, 0.381, 0.00019],
+    [0.7514, 0.321, 0.0001],
+    [0.6424, 0.265, 5e-05],
+    [0.5419, 0.217, 3e-05],
+    [0.4479, 0.175, 2e-05],
+    [0.3608, 0.1382, 1e-05],
+    [0.2835, 0.107, 0.0],
+    [0.2187, 0.0816, 0.0],
+    [0.1649, 0.061, 0.0],
+    [0.1212, 0.04458, 0.0],
+    [0.0874, 0.032, 0.0],
+    [0.0636, 0.0232, 0.0],
+    [0.04677, 0.017, 0.0],
+    [0.0329, 0.01192, 0.0],
+    [0.0227, 0.00821, 0.0],
+    [0.01584, 0.005723, 0.0],
+    [0.011359, 0.004102, 0.0],
+    [0.008111, 0.002929, 0.0],
+    [0.00579, 0.002091, 0.0],
+    [0.004109, 0.001484, 0.0],
+    [0.002899, 0.001047, 0.0],
+    [0.002049, 0.00074, 0.0],
+    [0.00144, 0.00052, 0.0],
+    [0.001, 0.000361, 0.0],
+    [0.00069, 0.000249, 0.0],
+    [0.000476, 0.000172, 0.0],
+    [0.000332, 0.00012, 0.0],
+    [0.000235, 8.5e-05, 0.0],
+    [0.000166, 6e-05, 0.0],
+    [0.000117, 4.2e-05, 0.0],
+    [8.3e-05, 3e-05, 0.0],
+    [5.9e-05, 2.1e-05, 0.0],
+    [4.2e-05, 1.5e-05, 0.0],
+];
+
+// CIE 1964 10° standard observer (Table T.5, 5nm)
+pub(crate) const CIE1964: [[f64; 3]; 81] = [
+    [0.00016, 1.7e-05, 0.000705],
+    [0.000662, 7.2e-05, 0.002928],
+    [0.002362, 0.000253, 0.010482],
+    [0.007242, 0.000769, 0.032344],
+    [0.01911, 0.002004, 0.086011],
+    [0.0434, 0.004509, 0.19712],
+    [0.084736, 0.008756, 0.389366],
+    [0.140638, 0.014456, 0.65676],
+    [0.204492, 0.021391, 0.972542],
+    [0.264737, 0.029497, 1.2825],
+    [0.314679, 0.038676, 1.55348],
+    [0.357719, 0.049602, 1.7985],
+    [0.383734, 0.062077, 1.96728],
+    [0.386726, 0.074704, 2.0273],
+    [0.370702, 0.089456, 1.9948],
+    [0.342957, 0.106256, 1.9007],
+    [0.302273, 0.128201, 1.74537],
+    [0.254085, 0.152761, 1.5549],
+    [0.195618, 0.18519, 1.31756],
+    [0.132349, 0.21994, 1.0302],
+    [0.080507, 0.253589, 0.772125],
+    [0.041072, 0.297665, 0.57006],
+    [0.016172, 0.339133, 0.415254],
+    [0.005132, 0.395379, 0.302356],
+    [0.003816, 0.460777, 0.218502],
+    [0.015444, 0.53136, 0.159249],
+    [0.037465, 0.606741, 0.112044],
+    [0.071358, 0.68566, 0.082248],
+    [0.117749, 0.761757, 0.060709],
+    [0.172953, 0.82333, 0.04305],
+    [0.236491, 0.875211, 0.030451],
+    [0.304213, 0.92381, 0.020584],
+    [0.376772, 0.961988, 0.013676],
+    [0.451584, 0.9822, 0.007918],
+    [0.529826, 0.991761, 0.003988],
+    [0.616053, 0.99911, 0.001091],
+    [0.705224, 0.99734, 0.0],
+    [0.793832, 0.98238, 0.0],
+    [0.878655, 0.955552, 0.0],
+    [0.951162, 0.915175, 0.0],
+    [1.01416, 0.868934, 0.0],
+    [1.0743, 0.825623, 0.0],
+    [1.11852, 0.777405, 0.0],
+    [1.1343, 0.720353, 0.0],
+    [1.12399, 0.658341, 0.0],
+    [1.0891, 0.593878, 0.0],
+    [1.03048, 0.527963, 0.0],
+    [0.95074, 0.461834, 0.0],
+    [0.856297, 0.398057, 0.0],
+    [0.75493, 0.339554, 0.0],
+    [0.647467, 0.283493, 0.0],
+    [0.53511, 0.228254, 0.0],
+    [0.431567, 0.179828, 0.0],
+    [0.34369, 0.140211, 0.0],
+    [0.268329, 0.107633, 0.0],
+    [0.2043, 0.081187, 0.0],
+    [0.152568, 0.060281, 0.0],
+    [0.11221, 0.044096, 0.0],
+    [0.081261, 0.0318, 0.0],
+    [0.05793, 0.022602, 0.0],
+    [0.040851, 0.015905, 0.0],
+    [0.028623, 0.01113, 0.0],
+    [0.019941, 0.007749, 0.0],
+    [0.013842, 0.005375, 0.0],
+    [0.009577, 0.003718, 0.0],
+    [0.006605, 0.002565, 0.0],
+    [0.004553, 0.001768, 0.0],
+    [0.003145, 0.001222, 0.0],
+    [0.002175, 0.000846, 0.0],
+    [0.001506, 0.000586, 0.0],
+    [0.001045, 0.000407, 0.0],
+    [0.000727, 0.000284, 0.0],
+    [0.000508, 0.000199, 0.0],
+    [0.000356, 0.00014, 0.0],
+    [0.000251, 9.8e-05, 0.0],
+    [0.000178, 7e-05, 0.0],
+    [0.000126, 5e-05, 0.0],
+    [9e-05, 3.6e-05, 0.0],
+    [6.5e-05, 2.5e-05, 0.0],
+    [4.6e-05, 1.8e-05, 0.0],
+    [3.3e-05, 1.3e-05, 0.0],
+];
+
+// S0, S1 and S2 of the daylight illuminants (Table T.2, 10nm)
+pub(crate) const DAYLIGHT_COMPONENTS: [[f64; 3]; 41] = [
+    [63.4, 38.5, 3.0],
+    [65.8, 35.0, 1.2],
+    [94.8, 43.4, -1.1],
+    [104.8, 46.3, -0.5],
+    [105.9, 43.9, -0.7],
+    [96.8, 37.1, -1.2],
+    [113.9, 36.7, -2.6],
+    [125.6, 35.9, -2.9],
+    [125.5, 32.6, -2.8],
+    [121.3, 27.9, -2.6],
+    [121.3, 24.3, -2.6],
+    [113.5, 20.1, -1.8],
+    [113.1, 16.2, -1.5],
+    [110.8, 13.2, -1.3],
+    [106.5, 8.6, -1.2],
+    [108.8, 6.1, -1.0],
+    [105.3, 4.2, -0.5],
+    [104.4, 1.9, -0.3],
+    [100.0, 0.0, 0.0],
+    [96.0, -1.6, 0.2],
+    [95.1, -3.5, 0.5],
+    [89.1, -3.5, 2.1],
+    [90.5, -5.8, 3.2],
+    [90.3, -7.2, 4.1],
+    [88.4, -8.6, 4.7],
+    [84.0, -9.5, 5.1],
+    [85.1, -10.9, 6.7],
+    [81.9, -10.7, 7.3],
+    [82.6, -12.0, 8.6],
+    [84.9, -14.0, 9.8],
+    [81.3, -13.6, 10.2],
+    [71.9, -12.0, 8.3],
+    [74.3, -13.3, 9.6],
+    [76.4, -12.9, 8.5],
+    [63.3, -10.6, 7.0],
+    [71.7, -11.6, 7.6],
+    [77.0, -12.2, 8.0],
+    [65.2, -10.2, 6.7],
+    [47.7, -7.8, 5.2],
+    [68.6, -11.2, 7.4],
+    [65.0, -10.4, 6.8],
+];
+
+// relative spectral radiance of a blackbody (wavelength in nm)
+pub(crate) fn planck(wavelength: f64, kelvin: f64) -> f64 {
+    let wavelength = wavelength * 1e-9;
+    1_f64 / (wavelength.powi(5) * ((C2 / (wavelength * kelvin)).exp() - 1_f64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cie1931_sum_test() {
+        // the integrals of the three functions are equal (equal energy white is x = y = 1/3)
+        let mut sum = [0_f64; 3];
+        for row in CIE1931 {
+            for (total, value) in sum.iter_mut().zip(row) {
+                *total += value * WAVELENGTH_STEP as f64;
+            }
+        }
+        assert!((sum[0] - 106.857).abs() < 1e-2);
+        assert!((sum[1] - 106.857).abs() < 1e-2);
+        assert!((sum[2] - 106.857).abs() < 1e-2);
+    }
+
+    #[test]
+    fn cie1964_sum_test() {
+        let mut sum = [0_f64; 3];
+        for row in CIE1964 {
+            for (total, value) in sum.iter_mut().zip(row) {
+                *total += value * WAVELENGTH_STEP as f64;
+            }
+        }
+        assert!((sum[0] - 116.65).abs() < 3e-2);
+        assert!((sum[1] - 116.65).abs() < 3e-2);
+        assert!((sum[2] - 116.65).abs() < 3e-2);
+    }
+}
@@ -0,0 +1,282 @@
+use std::fmt::Display;
+
+use crate::rgb::RgbColor;
+use crate::utils;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct CmykColor {
+    c: u8,
+    m: u8,
+    y: u8,
+    k: u8,
+    a: u8,
+}
+
+impl Display for CmykColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "CmykColor C = {} M = {} Y = {} K = {} A = {}",
+            self.c, self.m, self.y, self.k, self.a
+        )
+    }
+}
+
+impl CmykColor {
+    pub fn new(c: u8, m: u8, y: u8, k: u8) -> Self {
+        Self::from_cmyka(c, m, y, k, 255)
+    }
+
+    pub fn from_cmyka(c: u8, m: u8, y: u8, k: u8, a: u8) -> Self {
+        Self {
+            c: utils::crop_range(c, 0, 100),
+            m: utils::crop_range(m, 0, 100),
+            y: utils::crop_range(y, 0, 100),
+            k: utils::crop_range(k, 0, 100),
+            a,
+        }
+    }
+
+    pub fn c(&self) -> u8 {
+        self.c
+    }
+
+    pub fn m(&self) -> u8 {
+        self.m
+    }
+
+    pub fn y(&self) -> u8 {
+        self.y
+    }
+
+    pub fn k(&self) -> u8 {
+        self.k
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+
+    // sum of the four inks (0 - 400%)
+    pub fn total_ink(&self) -> u16 {
+        self.c as u16 + self.m as u16 + self.y as u16 + self.k as u16
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum BlackGeneration {
+    // K = 1 - max(R, G, B)
+    #[default]
+    Naive,
+    // gray component replacement: K = amount * min(C, M, Y), the same amount is removed from C, M, Y
+    GrayComponentReplacement(f64),
+    // under color removal: black replaces the gray component only above threshold (shadows)
+    // removed from C, M, Y like gray component replacement
+    UnderColorRemoval {
+        threshold: f64,
+        amount: f64,
+    },
+}
+
+pub const MAX_INK_LIMIT: u16 = 400;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct CmykSeparation {
+    black_generation: BlackGeneration,
+    ink_limit: u16,
+}
+
+impl Default for CmykSeparation {
+    fn default() -> Self {
+        Self {
+            black_generation: BlackGeneration::default(),
+            ink_limit: MAX_INK_LIMIT,
+        }
+    }
+}
+
+impl CmykSeparation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_black_generation(self, black_generation: BlackGeneration) -> Self {
+        Self {
+            black_generation,
+            ..self
+        }
+    }
+
+    // total ink limit in percent (e.g. 300)
+    pub fn with_ink_limit(self, ink_limit: u16) -> Self {
+        Self {
+            ink_limit: utils::crop_range(ink_limit, 0, MAX_INK_LIMIT),
+            ..self
+        }
+    }
+
+    pub fn black_generation(&self) -> BlackGeneration {
+        self.black_generation
+    }
+
+    pub fn ink_limit(&self) -> u16 {
+        self.ink_limit
+    }
+
+    pub fn separate(&self, color: &RgbColor) -> CmykColor {
+        let cmy = [
+            1_f64 - color.r() as f64 / 255_f64,
+            1_f64 - color.g() as f64 / 255_f64,
+            1_f64 - color.b() as f64 / 255_f64,
+        ];
+        let gray = cmy[0].min(cmy[1]).min(cmy[2]);
+
+        let k = match self.black_generation {
+            BlackGeneration::Naive => gray,
+            BlackGeneration::GrayComponentReplacement(amount) => amount.clamp(0_f64, 1_f64) * gray,
+            BlackGeneration::UnderColorRemoval { threshold, amount } => {
+                let threshold = threshold.clamp(0_f64, 1_f64);
+                if gray <= threshold || threshold >= 1_f64 {
+                    0_f64
+                } else {
+                    amount.clamp(0_f64, 1_f64) * gray * (gray - threshold) / (1_f64 - threshold)
+                }
+            }
+        };
+
+        let mut inks = match self.black_generation {
+            // (1 - C)(1 - K) = R
+            BlackGeneration::Naive if k >= 1_f64 => [0_f64, 0_f64, 0_f64],
+            BlackGeneration::Naive => cmy.map(|v| (v - k) / (1_f64 - k)),
+            // subtractive, the total ink never increases
+            _ => cmy.map(|v| (v - k).max(0_f64)),
+        };
+
+        let limit = self.ink_limit as f64 / 100_f64;
+        let total = inks[0] + inks[1] + inks[2] + k;
+        if total > limit {
+            let scale = ((limit - k) / (inks[0] + inks[1] + inks[2])).max(0_f64);
+            inks.iter_mut().for_each(|ink| *ink *= scale);
+        }
+
+        let percentage = |value: f64| (value * 100_f64).round().clamp(0_f64, 100_f64) as u8;
+        let mut cmy = [
+            percentage(inks[0]),
+            percentage(inks[1]),
+            percentage(inks[2]),
+        ];
+        let k = percentage(k.min(limit));
+
+        // rounding must not push the total over the limit
+        while cmy.iter().map(|&v| v as u16).sum::<u16>() + k as u16 > self.ink_limit {
+            match cmy.iter_mut().max() {
+                Some(max) if *max > 0 => *max -= 1,
+                _ => break,
+            }
+        }
+
+        CmykColor::from_cmyka(cmy[0], cmy[1], cmy[2], k, color.a())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmyk_new_test() {
+        let cyan = CmykColor::new(100, 0, 0, 0);
+
+        assert_eq!(cyan.c(), 100);
+        assert_eq!(cyan.m(), 0);
+        assert_eq!(cyan.y(), 0);
+        assert_eq!(cyan.k(), 0);
+        assert_eq!(cyan.a(), 255);
+        assert_eq!(CmykColor::new(120, 100, 100, 100).total_ink(), 400);
+    }
+
+    #[test]
+    fn cmyk_separation_naive_test() {
+        let separation = CmykSeparation::new();
+        assert_eq!(
+            separation.separate(&RgbColor::new(255, 0, 0)),
+            CmykColor::new(0, 100, 100, 0)
+        );
+        assert_eq!(
+            separation.separate(&RgbColor::new(0, 0, 0)),
+            CmykColor::new(0, 0, 0, 100)
+        );
+        assert_eq!(
+            separation.separate(&RgbColor::new(255, 255, 255)),
+            CmykColor::new(0, 0, 0, 0)
+        );
+        assert_eq!(
+            separation.separate(&RgbColor::new(102, 153, 51)),
+            CmykColor::new(33, 0, 67, 40)
+        );
+    }
+
+    #[test]
+    fn cmyk_separation_gcr_test() {
+        let separation = CmykSeparation::new()
+            .with_black_generation(BlackGeneration::GrayComponentReplacement(0_f64));
+        assert_eq!(
+            separation.separate(&RgbColor::new(0, 0, 0)),
+            CmykColor::new(100, 100, 100, 0)
+        );
+
+        let separation = CmykSeparation::new()
+            .with_black_generation(BlackGeneration::GrayComponentReplacement(0.5));
+        assert_eq!(
+            separation.separate(&RgbColor::new(0, 0, 0)),
+            CmykColor::new(50, 50, 50, 50)
+        );
+
+        // black replaces the gray component, the total ink decreases with the amount
+        for color in [
+            RgbColor::new(0, 0, 0),
+            RgbColor::new(40, 60, 90),
+            RgbColor::new(128, 128, 128),
+        ] {
+            let mut previous = u16::MAX;
+            for amount in [0_f64, 0.25, 0.5, 0.75, 1_f64] {
+                let cmyk = CmykSeparation::new()
+                    .with_black_generation(BlackGeneration::GrayComponentReplacement(amount))
+                    .separate(&color);
+                assert!(cmyk.total_ink() <= previous, "{} {}", amount, cmyk);
+                previous = cmyk.total_ink();
+            }
+        }
+        let cmyk = CmykSeparation::new()
+            .with_black_generation(BlackGeneration::GrayComponentReplacement(0.5))
+            .separate(&RgbColor::new(40, 60, 90));
+        assert_eq!(cmyk, CmykColor::new(52, 44, 32, 32));
+    }
+
+    #[test]
+    fn cmyk_separation_ucr_test() {
+        let separation =
+            CmykSeparation::new().with_black_generation(BlackGeneration::UnderColorRemoval {
+                threshold: 0.5,
+                amount: 1_f64,
+            });
+        // highlights carry no black
+        assert_eq!(separation.separate(&RgbColor::new(204, 204, 204)).k(), 0);
+        assert_eq!(
+            separation.separate(&RgbColor::new(0, 0, 0)),
+            CmykColor::new(0, 0, 0, 100)
+        );
+    }
+
+    #[test]
+    fn cmyk_separation_ink_limit_test() {
+        let separation = CmykSeparation::new()
+            .with_black_generation(BlackGeneration::GrayComponentReplacement(0.5))
+            .with_ink_limit(150);
+        let cmyk = separation.separate(&RgbColor::new(0, 0, 0));
+        assert!(cmyk.total_ink() <= 150);
+        assert_eq!(cmyk.k(), 50);
+
+        assert_eq!(CmykSeparation::new().with_ink_limit(500).ink_limit(), 400);
+    }
+}
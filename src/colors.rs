@@ -1,5 +1,6 @@
 use crate::hsl::HslColor;
 use crate::hsv::HsvColor;
+use crate::hwb::HwbColor;
 use crate::rgb::RgbColor;
 
 macro_rules! colors_iterable_enum {
@@ -110,7 +111,29 @@ impl Colors {
     }
 }
 
-impl Colors {}
+impl Colors {
+    pub fn to_hwb(&self) -> HwbColor {
+        match self {
+            Self::Aqua => HwbColor::new(180, 0, 0),
+            Self::Black => HwbColor::new(0, 0, 100),
+            Self::Blue => HwbColor::new(240, 0, 0),
+            Self::Fuchsia => HwbColor::new(300, 0, 0),
+            Self::Gray => HwbColor::new(0, 50, 50),
+            Self::Green => HwbColor::new(120, 0, 50),
+            Self::Lime => HwbColor::new(120, 0, 0),
+            Self::Maroon => HwbColor::new(0, 0, 50),
+            Self::Navy => HwbColor::new(240, 0, 50),
+            Self::Olive => HwbColor::new(60, 0, 50),
+            Self::Orange => HwbColor::new(38, 0, 0),
+            Self::Purple => HwbColor::new(300, 0, 50),
+            Self::Red => HwbColor::new(0, 0, 0),
+            Self::Silver => HwbColor::new(0, 75, 25),
+            Self::Teal => HwbColor::new(180, 0, 50),
+            Self::White => HwbColor::new(0, 100, 0),
+            Self::Yellow => HwbColor::new(60, 0, 0),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -143,6 +166,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn colors_to_hwb_test() {
+        use crate::converter::{Conversion, RgbToHwbConverter};
+
+        for color in Colors::as_enumerable() {
+            assert_eq!(
+                RgbToHwbConverter {}.convert(&color.to_rgb()).unwrap(),
+                color.to_hwb()
+            );
+        }
+    }
+
     #[test]
     fn colors_total_number_test() {
         println!(
@@ -1,11 +1,37 @@
 use std::fmt::Display;
+use std::sync::OnceLock;
 
+use crate::adaptation::ChromaticAdaptation;
+use crate::cct::{CctMethod, ColorTemperature};
+use crate::cmyk::{CmykColor, CmykSeparation};
 use crate::errors;
 use crate::errors::Result;
+use crate::float_rgb::FloatRgbColor;
+use crate::gamut::GamutMapping;
 use crate::hsl::HslColor;
+use crate::hsluv::{HpluvColor, HsluvColor};
 use crate::hsv::HsvColor;
+use crate::hwb::HwbColor;
+use crate::ictcp::{IctcpColor, IctcpTransfer};
+use crate::illuminant::{Illuminant, Observer};
+use crate::jzazbz::{JzazbzColor, JzczhzColor};
+use crate::luv::{LchuvColor, LuvColor};
+use crate::matrix;
+use crate::oklab::{OklabColor, OklchColor};
 use crate::rgb::RgbColor;
+use crate::rgb_space;
+use crate::rgb_space::RgbSpace;
+use crate::spectral::SampledSpectrum;
+use crate::tone_mapping::ToneMapping;
+use crate::transfer::TransferFunction;
+use crate::upsampling;
+use crate::upsampling::SpectralUpsampling;
 use crate::utils;
+use crate::white_point::WhitePoint;
+use crate::xyy::XyYColor;
+use crate::xyz::XyzColor;
+use crate::ycbcr::{YCbCrColor, YCbCrFormat};
+use crate::ycocg::{YCoCgColor, YCoCgRColor};
 
 pub trait Conversion<TInput, TOutput> {
     fn convert(&self, value: TInput) -> Result<TOutput>;
@@ -35,9 +61,36 @@ macro_rules! same_color_converter {
     };
 }
 
+// create converter(struct) with CMYK separation options
+#[allow(unused_macros)]
+macro_rules! cmyk_color_converter {
+    ($converter_name: ident) => {
+        #[derive(PartialEq, Clone, Copy, Debug, Default)]
+        pub struct $converter_name {
+            separation: CmykSeparation,
+        }
+
+        impl $converter_name {
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            pub fn with_separation(self, separation: CmykSeparation) -> Self {
+                Self { separation }
+            }
+
+            pub fn separation(&self) -> CmykSeparation {
+                self.separation
+            }
+        }
+    };
+}
+
 same_color_converter!(RgbToRgbConverter, RgbColor);
 same_color_converter!(HsvToHsvConverter, HsvColor);
 same_color_converter!(HslToHslConverter, HslColor);
+same_color_converter!(HwbToHwbConverter, HwbColor);
+same_color_converter!(CmykToCmykConverter, CmykColor);
 
 color_converter!(RgbToHsvConverter);
 color_converter!(RgbToHslConverter);
@@ -45,6 +98,17 @@ color_converter!(HsvToRgbConverter);
 color_converter!(HsvToHslConverter);
 color_converter!(HslToRgbConverter);
 color_converter!(HslToHsvConverter);
+color_converter!(RgbToHwbConverter);
+color_converter!(HsvToHwbConverter);
+color_converter!(HslToHwbConverter);
+color_converter!(HwbToRgbConverter);
+color_converter!(HwbToHsvConverter);
+color_converter!(HwbToHslConverter);
+color_converter!(CmykToRgbConverter);
+color_converter!(CmykToHsvConverter);
+color_converter!(CmykToHslConverter);
+color_converter!(CmykToHwbConverter);
+color_converter!(RgbToXyzConverter);
 
 impl Conversion<&RgbColor, HsvColor> for RgbToHsvConverter {
     fn convert(&self, color: &RgbColor) -> Result<HsvColor> {
@@ -91,7 +155,7 @@ impl Conversion<&RgbColor, HslColor> for RgbToHslConverter {
         let mut l = 0;
 
         if max != min {
-            l = (((max as u16 + min as u16) as f64 / 2_f64) as f64 / 255_f64 * 100_f64) as u8;
+            l = (((max as u16 + min as u16) as f64 / 2_f64) / 255_f64 * 100_f64) as u8;
 
             if max == r {
                 h = (g as i32 - b as i32) * 60 / (max - min) as i32;
@@ -115,7 +179,7 @@ impl Conversion<&RgbColor, HslColor> for RgbToHslConverter {
             }
         }
 
-        Ok(HslColor::from_hsla(h as u16, s, l as u8, color.a()))
+        Ok(HslColor::from_hsla(h as u16, s, l, color.a()))
     }
 }
 
@@ -249,304 +313,1429 @@ impl Conversion<&HslColor, HsvColor> for HslToHsvConverter {
     }
 }
 
-#[allow(dead_code)]
-mod core {
-    const HEX_LOWER_TABLE : &[u8] = b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
-
-    const HEX_UPPER_TABLE : &[u8] = b"000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F202122232425262728292A2B2C2D2E2F303132333435363738393A3B3C3D3E3F404142434445464748494A4B4C4D4E4F505152535455565758595A5B5C5D5E5F606162636465666768696A6B6C6D6E6F707172737475767778797A7B7C7D7E7F808182838485868788898A8B8C8D8E8F909192939495969798999A9B9C9D9E9FA0A1A2A3A4A5A6A7A8A9AAABACADAEAFB0B1B2B3B4B5B6B7B8B9BABBBCBDBEBFC0C1C2C3C4C5C6C7C8C9CACBCCCDCECFD0D1D2D3D4D5D6D7D8D9DADBDCDDDEDFE0E1E2E3E4E5E6E7E8E9EAEBECEDEEEFF0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF";
+fn percentage(value: f64) -> u8 {
+    (value * 100_f64).round().clamp(0_f64, 100_f64) as u8
+}
 
-    pub(crate) const HASH_MARK_NUMBER: u8 = 35; // #
+impl Conversion<&RgbColor, HwbColor> for RgbToHwbConverter {
+    fn convert(&self, color: &RgbColor) -> Result<HwbColor> {
+        let hsv = RgbToHsvConverter {}.convert(color)?;
+        let max = utils::max(color.r(), color.g(), color.b());
+        let min = utils::min(color.r(), color.g(), color.b());
 
-    pub(crate) fn decimal_to_lower_hexstring(value: u8, arr: &mut [u8]) {
-        let index: usize = (value as usize) << 1;
-        arr[0] = HEX_LOWER_TABLE[index];
-        arr[1] = HEX_LOWER_TABLE[index + 1];
+        Ok(HwbColor::from_hwba(
+            hsv.h(),
+            percentage(min as f64 / 255_f64),
+            percentage(1_f64 - max as f64 / 255_f64),
+            color.a(),
+        ))
     }
+}
 
-    pub(crate) fn decimal_to_upper_hexstring(value: u8, arr: &mut [u8]) {
-        let index: usize = (value as usize) << 1;
-        arr[0] = HEX_UPPER_TABLE[index];
-        arr[1] = HEX_UPPER_TABLE[index + 1];
+impl Conversion<&HsvColor, HwbColor> for HsvToHwbConverter {
+    fn convert(&self, color: &HsvColor) -> Result<HwbColor> {
+        let s = color.s() as f64 / 100_f64;
+        let v = color.v() as f64 / 100_f64;
+
+        Ok(HwbColor::from_hwba(
+            color.h(),
+            percentage((1_f64 - s) * v),
+            percentage(1_f64 - v),
+            color.a(),
+        ))
     }
+}
 
-    pub(crate) fn hexstring_to_decimal(hexstring: &[u8]) -> u8 {
-        match hexstring.len() {
-            1 => hex_char_to_decimal(hexstring[0]),
-            2 => 16 * hex_char_to_decimal(hexstring[0]) + hex_char_to_decimal(hexstring[1]),
-            _ => 0,
-        }
+impl Conversion<&HslColor, HwbColor> for HslToHwbConverter {
+    fn convert(&self, color: &HslColor) -> Result<HwbColor> {
+        let rgb = HslToRgbConverter {}.convert(color)?;
+        RgbToHwbConverter {}.convert(&rgb)
     }
+}
 
-    fn hex_char_to_decimal(value: u8) -> u8 {
-        let v = value | 0x20u8;
-        match v {
-            48..=57 => v - 48,
-            97..=102 => v - 87,
-            _ => 0,
+impl Conversion<&HwbColor, RgbColor> for HwbToRgbConverter {
+    fn convert(&self, color: &HwbColor) -> Result<RgbColor> {
+        let w = color.w() as f64 / 100_f64;
+        let b = color.b() as f64 / 100_f64;
+
+        if w + b >= 1_f64 {
+            let gray = utils::float_to_u8(w / (w + b));
+            return Ok(RgbColor::from_rgba(gray, gray, gray, color.a()));
         }
+
+        let h = if color.h() == 360 { 0 } else { color.h() } as f64 / 60_f64;
+        let x = 1_f64 - (h % 2_f64 - 1_f64).abs();
+        let hue = match h.floor() as u16 {
+            0 => [1_f64, x, 0_f64],
+            1 => [x, 1_f64, 0_f64],
+            2 => [0_f64, 1_f64, x],
+            3 => [0_f64, x, 1_f64],
+            4 => [x, 0_f64, 1_f64],
+            5 => [1_f64, 0_f64, x],
+            _ => {
+                return Err(errors::Error::new(
+                    errors::ErrorCode::InvalidArgument,
+                    color.to_string(),
+                ))
+            }
+        };
+
+        let scale = 1_f64 - w - b;
+        Ok(RgbColor::from_rgba(
+            utils::float_to_u8(hue[0] * scale + w),
+            utils::float_to_u8(hue[1] * scale + w),
+            utils::float_to_u8(hue[2] * scale + w),
+            color.a(),
+        ))
     }
+}
 
-    pub(crate) fn validate_hex_char(value: u8) -> bool {
-        match value {
-            48..=57 => true,
-            65..=70 => true,
-            97..=102 => true,
-            _ => false,
+impl Conversion<&HwbColor, HsvColor> for HwbToHsvConverter {
+    fn convert(&self, color: &HwbColor) -> Result<HsvColor> {
+        let w = color.w() as f64 / 100_f64;
+        let b = color.b() as f64 / 100_f64;
+
+        if w + b >= 1_f64 {
+            return Ok(HsvColor::from_hsva(
+                color.h(),
+                0,
+                percentage(w / (w + b)),
+                color.a(),
+            ));
         }
+
+        let v = 1_f64 - b;
+        Ok(HsvColor::from_hsva(
+            color.h(),
+            percentage(1_f64 - w / v),
+            percentage(v),
+            color.a(),
+        ))
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub enum CharCase {
-    Lower,
-    Upper,
+impl Conversion<&HwbColor, HslColor> for HwbToHslConverter {
+    fn convert(&self, color: &HwbColor) -> Result<HslColor> {
+        let rgb = HwbToRgbConverter {}.convert(color)?;
+        RgbToHslConverter {}.convert(&rgb)
+    }
 }
 
-#[derive(PartialEq, Eq, Clone, Debug)]
-pub struct HtmlColorCode {
-    value: String,
-}
+cmyk_color_converter!(RgbToCmykConverter);
+cmyk_color_converter!(HsvToCmykConverter);
+cmyk_color_converter!(HslToCmykConverter);
+cmyk_color_converter!(HwbToCmykConverter);
+cmyk_color_converter!(HtmlToCmykConverter);
 
-impl Display for HtmlColorCode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value_ref())
+impl Conversion<&RgbColor, CmykColor> for RgbToCmykConverter {
+    fn convert(&self, color: &RgbColor) -> Result<CmykColor> {
+        Ok(self.separation.separate(color))
     }
 }
 
-impl HtmlColorCode {
-    pub fn new(code: impl Into<String>) -> Self {
-        Self { value: code.into() }
+impl Conversion<&HsvColor, CmykColor> for HsvToCmykConverter {
+    fn convert(&self, color: &HsvColor) -> Result<CmykColor> {
+        let rgb = HsvToRgbConverter {}.convert(color)?;
+        Ok(self.separation.separate(&rgb))
     }
+}
 
-    pub fn value(&self) -> String {
-        self.value.to_string()
+impl Conversion<&HslColor, CmykColor> for HslToCmykConverter {
+    fn convert(&self, color: &HslColor) -> Result<CmykColor> {
+        let rgb = HslToRgbConverter {}.convert(color)?;
+        Ok(self.separation.separate(&rgb))
     }
+}
 
-    pub fn value_ref(&self) -> &str {
-        &self.value
+impl Conversion<&HwbColor, CmykColor> for HwbToCmykConverter {
+    fn convert(&self, color: &HwbColor) -> Result<CmykColor> {
+        let rgb = HwbToRgbConverter {}.convert(color)?;
+        Ok(self.separation.separate(&rgb))
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct RgbToHtmlConverter {}
+impl Conversion<&CmykColor, RgbColor> for CmykToRgbConverter {
+    fn convert(&self, color: &CmykColor) -> Result<RgbColor> {
+        let k = 1_f64 - color.k() as f64 / 100_f64;
+        let channel = |ink: u8| utils::float_to_u8((1_f64 - ink as f64 / 100_f64) * k);
 
-impl Conversion<&RgbColor, HtmlColorCode> for RgbToHtmlConverter {
-    fn convert(&self, rgb: &RgbColor) -> Result<HtmlColorCode> {
-        self.convert_with_charcase(&rgb, CharCase::Lower)
+        Ok(RgbColor::from_rgba(
+            channel(color.c()),
+            channel(color.m()),
+            channel(color.y()),
+            color.a(),
+        ))
     }
 }
 
-impl RgbToHtmlConverter {
-    fn convert_with_charcase(&self, rgb: &RgbColor, charcase: CharCase) -> Result<HtmlColorCode> {
-        match charcase {
-            CharCase::Lower => Ok(self.to_html_lower(&rgb)),
-            CharCase::Upper => Ok(self.to_html_upper(&rgb)),
-        }
+impl Conversion<&CmykColor, HsvColor> for CmykToHsvConverter {
+    fn convert(&self, color: &CmykColor) -> Result<HsvColor> {
+        let rgb = CmykToRgbConverter {}.convert(color)?;
+        RgbToHsvConverter {}.convert(&rgb)
     }
+}
 
-    fn to_html_lower(&self, rgb: &RgbColor) -> HtmlColorCode {
-        let mut html_array: [u8; 7] = Default::default();
-        html_array[0] = core::HASH_MARK_NUMBER;
-        core::decimal_to_lower_hexstring(rgb.r(), &mut html_array[1..3]);
-        core::decimal_to_lower_hexstring(rgb.g(), &mut html_array[3..5]);
-        core::decimal_to_lower_hexstring(rgb.b(), &mut html_array[5..7]);
-        HtmlColorCode::new(html_array.iter().map(|&s| s as char).collect::<String>())
+impl Conversion<&CmykColor, HslColor> for CmykToHslConverter {
+    fn convert(&self, color: &CmykColor) -> Result<HslColor> {
+        let rgb = CmykToRgbConverter {}.convert(color)?;
+        RgbToHslConverter {}.convert(&rgb)
     }
+}
 
-    fn to_html_upper(&self, rgb: &RgbColor) -> HtmlColorCode {
-        let mut html_array: [u8; 7] = Default::default();
-        html_array[0] = core::HASH_MARK_NUMBER;
-        core::decimal_to_upper_hexstring(rgb.r(), &mut html_array[1..3]);
-        core::decimal_to_upper_hexstring(rgb.g(), &mut html_array[3..5]);
-        core::decimal_to_upper_hexstring(rgb.b(), &mut html_array[5..7]);
-        HtmlColorCode::new(html_array.iter().map(|&s| s as char).collect::<String>())
+impl Conversion<&CmykColor, HwbColor> for CmykToHwbConverter {
+    fn convert(&self, color: &CmykColor) -> Result<HwbColor> {
+        let rgb = CmykToRgbConverter {}.convert(color)?;
+        RgbToHwbConverter {}.convert(&rgb)
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct HsvToHtmlConverter {}
-
-impl Conversion<&HsvColor, HtmlColorCode> for HsvToHtmlConverter {
-    fn convert(&self, hsv: &HsvColor) -> Result<HtmlColorCode> {
-        self.convert_with_charcase(&hsv, CharCase::Lower)
-    }
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct RgbToYCbCrConverter {
+    format: YCbCrFormat,
 }
 
-impl HsvToHtmlConverter {
-    fn convert_with_charcase(&self, hsv: &HsvColor, charcase: CharCase) -> Result<HtmlColorCode> {
-        let result = HsvToRgbConverter {}.convert(hsv);
+impl RgbToYCbCrConverter {
+    pub fn new(format: YCbCrFormat) -> Self {
+        Self { format }
+    }
 
-        if let Ok(rgb) = result {
-            RgbToHtmlConverter {}.convert_with_charcase(&rgb, charcase)
-        } else {
-            Err(errors::Error::new(
-                errors::ErrorCode::InvalidArgument,
-                hsv.to_string(),
-            ))
-        }
+    pub fn format(&self) -> YCbCrFormat {
+        self.format
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct HslToHtmlConverter {}
-
-impl Conversion<&HslColor, HtmlColorCode> for HslToHtmlConverter {
-    fn convert(&self, hsl: &HslColor) -> Result<HtmlColorCode> {
-        self.convert_with_charcase(&hsl, CharCase::Lower)
+impl Conversion<&RgbColor, YCbCrColor> for RgbToYCbCrConverter {
+    fn convert(&self, color: &RgbColor) -> Result<YCbCrColor> {
+        Ok(self.format.encode(&FloatRgbColor::from_rgba(
+            color.r() as f64 / 255_f64,
+            color.g() as f64 / 255_f64,
+            color.b() as f64 / 255_f64,
+            color.a() as f64 / 255_f64,
+        )))
     }
 }
 
-impl HslToHtmlConverter {
-    fn convert_with_charcase(&self, hsl: &HslColor, charcase: CharCase) -> Result<HtmlColorCode> {
-        let result = HslToRgbConverter {}.convert(hsl);
-
-        if let Ok(rgb) = result {
-            RgbToHtmlConverter {}.convert_with_charcase(&rgb, charcase)
-        } else {
-            Err(errors::Error::new(
-                errors::ErrorCode::InvalidArgument,
-                hsl.to_string(),
-            ))
-        }
-    }
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct YCbCrToRgbConverter {
+    format: YCbCrFormat,
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct HtmlToRgbConverter {}
+impl YCbCrToRgbConverter {
+    pub fn new(format: YCbCrFormat) -> Self {
+        Self { format }
+    }
 
-impl Conversion<&HtmlColorCode, RgbColor> for HtmlToRgbConverter {
-    fn convert(&self, html: &HtmlColorCode) -> Result<RgbColor> {
-        self.to_rgb(html)
+    pub fn format(&self) -> YCbCrFormat {
+        self.format
     }
 }
 
-impl HtmlToRgbConverter {
-    fn to_rgb(&self, html: &HtmlColorCode) -> Result<RgbColor> {
-        let s = html.value.as_bytes();
-
-        if !self.validate_length(s) {
+impl Conversion<&YCbCrColor, RgbColor> for YCbCrToRgbConverter {
+    fn convert(&self, color: &YCbCrColor) -> Result<RgbColor> {
+        let max = self.format.max_code_value();
+        if color.y() > max || color.cb() > max || color.cr() > max {
             return Err(errors::Error::new(
                 errors::ErrorCode::InvalidArgument,
-                html.value.to_string(),
+                color.to_string(),
             ));
         }
+        let rgb = self.format.decode(color);
+        Ok(RgbColor::from_rgba(
+            utils::float_to_u8(rgb.r()),
+            utils::float_to_u8(rgb.g()),
+            utils::float_to_u8(rgb.b()),
+            color.a(),
+        ))
+    }
+}
 
-        if !self.validate_format(&s[1..]) {
-            return Err(errors::Error::new(
-                errors::ErrorCode::InvalidArgumentFormat,
-                html.value.to_string(),
-            ));
-        }
+color_converter!(RgbToYCoCgConverter);
+color_converter!(YCoCgToRgbConverter);
+color_converter!(RgbToYCoCgRConverter);
+color_converter!(YCoCgRToRgbConverter);
 
-        let length = s.len();
-        if s[0] == core::HASH_MARK_NUMBER {
-            match length {
-                7 => Ok(RgbColor::new(
-                    core::hexstring_to_decimal(&s[1..=2]),
-                    core::hexstring_to_decimal(&s[3..=4]),
-                    core::hexstring_to_decimal(&s[5..=6]),
-                )),
-                9 => Ok(RgbColor::from_rgba(
-                    core::hexstring_to_decimal(&s[1..=2]),
-                    core::hexstring_to_decimal(&s[3..=4]),
-                    core::hexstring_to_decimal(&s[5..=6]),
-                    core::hexstring_to_decimal(&s[7..=8]),
-                )),
-                _ => Err(errors::Error::new(
-                    errors::ErrorCode::InvalidArgument,
-                    html.value.to_string(),
-                )),
-            }
-        } else {
-            match length {
-                6 => Ok(RgbColor::new(
-                    core::hexstring_to_decimal(&s[0..=1]),
-                    core::hexstring_to_decimal(&s[2..=3]),
-                    core::hexstring_to_decimal(&s[4..=5]),
-                )),
-                8 => Ok(RgbColor::from_rgba(
-                    core::hexstring_to_decimal(&s[0..=1]),
-                    core::hexstring_to_decimal(&s[2..=3]),
-                    core::hexstring_to_decimal(&s[4..=5]),
-                    core::hexstring_to_decimal(&s[6..=7]),
-                )),
-                _ => Err(errors::Error::new(
-                    errors::ErrorCode::InvalidArgument,
-                    html.value.to_string(),
-                )),
+// convert slices of pixels (the lengths of input and output must be equal)
+macro_rules! slice_conversion {
+    ($converter_name: ident, $from: ident, $to: ident) => {
+        slice_conversion!($converter_name, convert_slice, $from, $to);
+    };
+    ($converter_name: ident, $method: ident, $from: ident, $to: ident) => {
+        impl $converter_name {
+            pub fn $method(&self, colors: &[$from], output: &mut [$to]) -> Result<()> {
+                if colors.len() != output.len() {
+                    return Err(errors::Error::new(
+                        errors::ErrorCode::InvalidArgument,
+                        format!("{} {}", colors.len(), output.len()),
+                    ));
+                }
+                for (color, out) in colors.iter().zip(output.iter_mut()) {
+                    *out = self.convert(color)?;
+                }
+                Ok(())
             }
         }
+    };
+}
+
+pub(crate) use slice_conversion;
+
+slice_conversion!(RgbToYCoCgConverter, RgbColor, YCoCgColor);
+slice_conversion!(YCoCgToRgbConverter, YCoCgColor, RgbColor);
+slice_conversion!(RgbToYCoCgRConverter, RgbColor, YCoCgRColor);
+slice_conversion!(YCoCgRToRgbConverter, YCoCgRColor, RgbColor);
+slice_conversion!(RgbToYCbCrConverter, RgbColor, YCbCrColor);
+slice_conversion!(YCbCrToRgbConverter, YCbCrColor, RgbColor);
+
+impl Conversion<&RgbColor, YCoCgColor> for RgbToYCoCgConverter {
+    fn convert(&self, color: &RgbColor) -> Result<YCoCgColor> {
+        let r = color.r() as f64 / 255_f64;
+        let g = color.g() as f64 / 255_f64;
+        let b = color.b() as f64 / 255_f64;
+
+        Ok(YCoCgColor::from_ycocga(
+            r / 4_f64 + g / 2_f64 + b / 4_f64,
+            r / 2_f64 - b / 2_f64,
+            -r / 4_f64 + g / 2_f64 - b / 4_f64,
+            color.a() as f64 / 255_f64,
+        ))
     }
+}
 
-    fn validate_length(&self, raw_html: &[u8]) -> bool {
-        match raw_html.len() {
-            6..=9 => true,
-            _ => false,
-        }
+impl Conversion<&YCoCgColor, RgbColor> for YCoCgToRgbConverter {
+    fn convert(&self, color: &YCoCgColor) -> Result<RgbColor> {
+        let t = color.y() - color.cg();
+
+        Ok(RgbColor::from_rgba(
+            utils::float_to_u8(t + color.co()),
+            utils::float_to_u8(color.y() + color.cg()),
+            utils::float_to_u8(t - color.co()),
+            utils::float_to_u8(color.a()),
+        ))
     }
+}
 
-    fn validate_format(&self, raw_html: &[u8]) -> bool {
-        !raw_html.iter().any(|&c| !core::validate_hex_char(c))
+// https://www.microsoft.com/en-us/research/publication/ycocg-r-a-color-space-with-rgb-reversibility-and-low-dynamic-range/
+impl Conversion<&RgbColor, YCoCgRColor> for RgbToYCoCgRConverter {
+    fn convert(&self, color: &RgbColor) -> Result<YCoCgRColor> {
+        let co = color.r() as i16 - color.b() as i16;
+        let t = color.b() as i16 + (co >> 1);
+        let cg = color.g() as i16 - t;
+        let y = t + (cg >> 1);
+
+        Ok(YCoCgRColor::from_ycocga(y as u8, co, cg, color.a()))
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct HtmlToHsvConverter {}
+impl Conversion<&YCoCgRColor, RgbColor> for YCoCgRToRgbConverter {
+    fn convert(&self, color: &YCoCgRColor) -> Result<RgbColor> {
+        let t = color.y() as i16 - (color.cg() >> 1);
+        let g = color.cg() + t;
+        let b = t - (color.co() >> 1);
+        let r = b + color.co();
+        let channel = |value: i16| value.clamp(0, 255) as u8;
+
+        Ok(RgbColor::from_rgba(
+            channel(r),
+            channel(g),
+            channel(b),
+            color.a(),
+        ))
+    }
+}
 
-impl Conversion<&HtmlColorCode, HsvColor> for HtmlToHsvConverter {
-    fn convert(&self, html: &HtmlColorCode) -> Result<HsvColor> {
-        let html_to_rgb_converter = HtmlToRgbConverter {};
-        let rgb = html_to_rgb_converter.convert(html)?;
+// sRGB(D65) <=> XYZ, the gamut mapping uses the same space
+impl Conversion<&RgbColor, XyzColor> for RgbToXyzConverter {
+    fn convert(&self, color: &RgbColor) -> Result<XyzColor> {
+        let linear = [
+            TransferFunction::Srgb.decode(color.r() as f64 / 255_f64),
+            TransferFunction::Srgb.decode(color.g() as f64 / 255_f64),
+            TransferFunction::Srgb.decode(color.b() as f64 / 255_f64),
+        ];
 
-        let rgb_to_hsv_converter = RgbToHsvConverter {};
-        rgb_to_hsv_converter.convert(&rgb)
+        Ok(XyzColor::from_array(
+            matrix::multiply_vector(&rgb_space::srgb().to_xyz_matrix(), linear),
+            color.a() as f64 / 255_f64,
+        ))
     }
 }
 
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
-pub struct HtmlToHslConverter {}
+fn linear_srgb_to_rgb(linear: [f64; 3], alpha: f64, gamut_mapping: GamutMapping) -> RgbColor {
+    let encoded = RgbSpace::srgb_transfer_encode(linear, alpha);
+    let mapped = match gamut_mapping {
+        GamutMapping::Clip => encoded,
+        _ => gamut_mapping.map(&encoded, rgb_space::srgb()),
+    };
 
-impl Conversion<&HtmlColorCode, HslColor> for HtmlToHslConverter {
-    fn convert(&self, html: &HtmlColorCode) -> Result<HslColor> {
-        let html_to_rgb_converter = HtmlToRgbConverter {};
-        let rgb = html_to_rgb_converter.convert(html)?;
+    RgbColor::from_rgba(
+        utils::float_to_u8(mapped.r()),
+        utils::float_to_u8(mapped.g()),
+        utils::float_to_u8(mapped.b()),
+        utils::float_to_u8(mapped.a()),
+    )
+}
 
-        let rgb_to_hsl_converter = RgbToHslConverter {};
-        rgb_to_hsl_converter.convert(&rgb)
-    }
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct ColorTemperatureToRgbConverter {
+    gamut_mapping: GamutMapping,
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl ColorTemperatureToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-    #[test]
-    fn rgb_to_hsv_converter_error_check_test() {
-        for r in 0..=255 {
-            for g in 0..=255 {
-                for b in 0..=255 {
-                    let rgb = RgbColor::new(r, g, b);
-                    let converter = RgbToHsvConverter {};
-                    assert!(converter.convert(&rgb).is_ok());
-                }
-            }
-        }
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
     }
 
-    #[test]
-    fn rgb_to_hsl_converter_error_check_test() {
-        for r in 0..=255 {
-            for g in 0..=255 {
-                for b in 0..=255 {
-                    let rgb = RgbColor::new(r, g, b);
-                    let converter = RgbToHslConverter {};
-                    assert!(converter.convert(&rgb).is_ok());
-                }
-            }
-        }
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
     }
+}
 
-    #[test]
+// the brightest sRGB color of the light color
+impl Conversion<&ColorTemperature, RgbColor> for ColorTemperatureToRgbConverter {
+    fn convert(&self, color: &ColorTemperature) -> Result<RgbColor> {
+        let linear = matrix::multiply_vector(
+            &rgb_space::srgb().from_xyz_matrix(),
+            color.to_xyz(1_f64).to_array(),
+        );
+        let max = linear.iter().copied().fold(f64::MIN, f64::max);
+        let linear = linear.map(|value| value / max);
+        Ok(linear_srgb_to_rgb(linear, 1_f64, self.gamut_mapping))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct RgbToColorTemperatureConverter {
+    method: CctMethod,
+}
+
+impl RgbToColorTemperatureConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_method(self, method: CctMethod) -> Self {
+        Self { method }
+    }
+
+    pub fn method(&self) -> CctMethod {
+        self.method
+    }
+}
+
+impl Conversion<&RgbColor, ColorTemperature> for RgbToColorTemperatureConverter {
+    fn convert(&self, color: &RgbColor) -> Result<ColorTemperature> {
+        let xyz = RgbToXyzConverter {}.convert(color)?;
+        if xyz.y() == 0_f64 {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                color.to_string(),
+            ));
+        }
+        Ok(ColorTemperature::from_xyz(&xyz, self.method))
+    }
+}
+
+// reflectance spectrum under an illuminant to sRGB (CIE 1931 2° observer)
+#[derive(PartialEq, Clone, Debug)]
+pub struct ReflectanceToRgbConverter {
+    illuminant: SampledSpectrum,
+    adaptation: ChromaticAdaptation,
+    gamut_mapping: GamutMapping,
+}
+
+impl Default for ReflectanceToRgbConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ReflectanceToRgbConverter {
+    pub fn new() -> Self {
+        let illuminant = SampledSpectrum::from_illuminant(Illuminant::D65)
+            .unwrap_or_else(|| SampledSpectrum::constant(100_f64));
+        // D65 (or the equal energy fallback) has a valid white
+        Self::with_illuminant(illuminant).unwrap()
+    }
+
+    // the white of the illuminant is adapted to D65 (Bradford)
+    // an illuminant without luminance (e.g. all zero) is an error
+    pub fn with_illuminant(illuminant: SampledSpectrum) -> Result<Self> {
+        let white = illuminant.to_xyz(Observer::Cie1931);
+        Ok(Self {
+            illuminant,
+            adaptation: ChromaticAdaptation::new(WhitePoint::Custom(white), WhitePoint::D65)?,
+            gamut_mapping: GamutMapping::default(),
+        })
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self {
+            gamut_mapping,
+            ..self
+        }
+    }
+
+    pub fn illuminant(&self) -> &SampledSpectrum {
+        &self.illuminant
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl ReflectanceToRgbConverter {
+    fn to_linear_srgb(&self, reflectance: &SampledSpectrum) -> [f64; 3] {
+        let xyz = reflectance.reflectance_to_xyz(&self.illuminant, Observer::Cie1931);
+        matrix::multiply_vector(
+            &rgb_space::srgb().from_xyz_matrix(),
+            self.adaptation.adapt(&xyz).to_array(),
+        )
+    }
+}
+
+impl Conversion<&SampledSpectrum, RgbColor> for ReflectanceToRgbConverter {
+    fn convert(&self, reflectance: &SampledSpectrum) -> Result<RgbColor> {
+        let linear = self.to_linear_srgb(reflectance);
+        Ok(linear_srgb_to_rgb(linear, 1_f64, self.gamut_mapping))
+    }
+}
+
+// linear sRGB of the reflectance which is 1 at a single upsampling wavelength (D65)
+fn reflectance_response() -> &'static [[f64; upsampling::SAMPLES]; 3] {
+    static RESPONSE: OnceLock<[[f64; upsampling::SAMPLES]; 3]> = OnceLock::new();
+    RESPONSE.get_or_init(|| {
+        let converter = ReflectanceToRgbConverter::new();
+        let mut response = [[0_f64; upsampling::SAMPLES]; 3];
+        for i in 0..upsampling::SAMPLES {
+            let mut values = vec![0_f64; upsampling::SAMPLES];
+            values[i] = 1_f64;
+            let basis = SampledSpectrum::new(
+                upsampling::START_WAVELENGTH,
+                upsampling::WAVELENGTH_INTERVAL,
+                values,
+            )
+            .unwrap_or_else(|_| SampledSpectrum::constant(0_f64));
+            for (channel, value) in response.iter_mut().zip(converter.to_linear_srgb(&basis)) {
+                channel[i] = value;
+            }
+        }
+        response
+    })
+}
+
+// sRGB to reflectance spectrum (380nm - 730nm, 10nm)
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct RgbToReflectanceConverter {
+    upsampling: SpectralUpsampling,
+}
+
+impl RgbToReflectanceConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_upsampling(self, upsampling: SpectralUpsampling) -> Self {
+        Self { upsampling }
+    }
+
+    pub fn upsampling(&self) -> SpectralUpsampling {
+        self.upsampling
+    }
+}
+
+impl Conversion<&RgbColor, SampledSpectrum> for RgbToReflectanceConverter {
+    fn convert(&self, color: &RgbColor) -> Result<SampledSpectrum> {
+        let linear = [color.r(), color.g(), color.b()]
+            .map(|value| TransferFunction::Srgb.decode(value as f64 / 255_f64));
+        Ok(match self.upsampling {
+            SpectralUpsampling::Smits => upsampling::smits(linear),
+            SpectralUpsampling::Smooth => upsampling::smooth(linear, reflectance_response()),
+        })
+    }
+}
+
+// linear HDR sRGB (rec.709 primaries) to displayable sRGB
+#[derive(PartialEq, Clone, Copy, Debug, Default)]
+pub struct LinearRgbToRgbConverter {
+    tone_mapping: ToneMapping,
+    exposure: f64,
+}
+
+impl LinearRgbToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_tone_mapping(self, tone_mapping: ToneMapping) -> Self {
+        Self {
+            tone_mapping,
+            ..self
+        }
+    }
+
+    // stops (EV), the linear values are scaled by 2^exposure before the tone mapping
+    pub fn with_exposure(self, exposure: f64) -> Self {
+        Self { exposure, ..self }
+    }
+
+    pub fn tone_mapping(&self) -> ToneMapping {
+        self.tone_mapping
+    }
+
+    pub fn exposure(&self) -> f64 {
+        self.exposure
+    }
+}
+
+slice_conversion!(LinearRgbToRgbConverter, FloatRgbColor, RgbColor);
+
+impl Conversion<&FloatRgbColor, RgbColor> for LinearRgbToRgbConverter {
+    fn convert(&self, color: &FloatRgbColor) -> Result<RgbColor> {
+        let gain = self.exposure.exp2();
+        let exposed = FloatRgbColor::from_array(color.to_array().map(|v| v * gain), color.a());
+        let display = self.tone_mapping.map(&exposed);
+        Ok(linear_srgb_to_rgb(
+            display.to_array(),
+            display.a(),
+            GamutMapping::Clip,
+        ))
+    }
+}
+
+color_converter!(RgbToXyYConverter);
+
+impl Conversion<&RgbColor, XyYColor> for RgbToXyYConverter {
+    fn convert(&self, color: &RgbColor) -> Result<XyYColor> {
+        let xyz = RgbToXyzConverter {}.convert(color)?;
+        Ok(XyYColor::from_xyz(&xyz))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct XyYToRgbConverter {
+    gamut_mapping: GamutMapping,
+}
+
+impl XyYToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&XyYColor, RgbColor> for XyYToRgbConverter {
+    fn convert(&self, color: &XyYColor) -> Result<RgbColor> {
+        let xyz_converter = XyzToRgbConverter::new().with_gamut_mapping(self.gamut_mapping);
+        xyz_converter.convert(&color.to_xyz())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct XyzToRgbConverter {
+    gamut_mapping: GamutMapping,
+}
+
+impl XyzToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&XyzColor, RgbColor> for XyzToRgbConverter {
+    fn convert(&self, color: &XyzColor) -> Result<RgbColor> {
+        let linear =
+            matrix::multiply_vector(&rgb_space::srgb().from_xyz_matrix(), color.to_array());
+        Ok(linear_srgb_to_rgb(linear, color.a(), self.gamut_mapping))
+    }
+}
+
+color_converter!(RgbToOklabConverter);
+color_converter!(RgbToOklchConverter);
+
+impl Conversion<&RgbColor, OklabColor> for RgbToOklabConverter {
+    fn convert(&self, color: &RgbColor) -> Result<OklabColor> {
+        let xyz = RgbToXyzConverter {}.convert(color)?;
+        Ok(OklabColor::from_xyz(&xyz))
+    }
+}
+
+impl Conversion<&RgbColor, OklchColor> for RgbToOklchConverter {
+    fn convert(&self, color: &RgbColor) -> Result<OklchColor> {
+        let oklab = RgbToOklabConverter {}.convert(color)?;
+        Ok(OklchColor::from_oklab(&oklab))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct OklabToRgbConverter {
+    gamut_mapping: GamutMapping,
+}
+
+impl OklabToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&OklabColor, RgbColor> for OklabToRgbConverter {
+    fn convert(&self, color: &OklabColor) -> Result<RgbColor> {
+        let xyz_converter = XyzToRgbConverter::new().with_gamut_mapping(self.gamut_mapping);
+        xyz_converter.convert(&color.to_xyz())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct OklchToRgbConverter {
+    gamut_mapping: GamutMapping,
+}
+
+impl OklchToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&OklchColor, RgbColor> for OklchToRgbConverter {
+    fn convert(&self, color: &OklchColor) -> Result<RgbColor> {
+        let oklab_converter = OklabToRgbConverter::new().with_gamut_mapping(self.gamut_mapping);
+        oklab_converter.convert(&color.to_oklab())
+    }
+}
+
+// sRGB white is displayed at the graphics white of ITU-R BT.2408 in HDR
+pub const SDR_WHITE_LUMINANCE: f64 = 203_f64;
+
+fn rgb_to_absolute_xyz(color: &RgbColor) -> Result<XyzColor> {
+    let xyz = RgbToXyzConverter {}.convert(color)?;
+    Ok(XyzColor::from_array(
+        xyz.to_array().map(|value| value * SDR_WHITE_LUMINANCE),
+        xyz.a(),
+    ))
+}
+
+fn absolute_xyz_to_rgb(color: &XyzColor, gamut_mapping: GamutMapping) -> Result<RgbColor> {
+    let xyz = XyzColor::from_array(
+        color.to_array().map(|value| value / SDR_WHITE_LUMINANCE),
+        color.a(),
+    );
+    XyzToRgbConverter::new()
+        .with_gamut_mapping(gamut_mapping)
+        .convert(&xyz)
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct RgbToIctcpConverter {
+    transfer: IctcpTransfer,
+}
+
+impl RgbToIctcpConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_transfer(self, transfer: IctcpTransfer) -> Self {
+        Self { transfer }
+    }
+
+    pub fn transfer(&self) -> IctcpTransfer {
+        self.transfer
+    }
+}
+
+impl Conversion<&RgbColor, IctcpColor> for RgbToIctcpConverter {
+    fn convert(&self, color: &RgbColor) -> Result<IctcpColor> {
+        Ok(IctcpColor::from_xyz(
+            &rgb_to_absolute_xyz(color)?,
+            self.transfer,
+        ))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct IctcpToRgbConverter {
+    transfer: IctcpTransfer,
+    gamut_mapping: GamutMapping,
+}
+
+impl IctcpToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_transfer(self, transfer: IctcpTransfer) -> Self {
+        Self { transfer, ..self }
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self {
+            gamut_mapping,
+            ..self
+        }
+    }
+
+    pub fn transfer(&self) -> IctcpTransfer {
+        self.transfer
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&IctcpColor, RgbColor> for IctcpToRgbConverter {
+    fn convert(&self, color: &IctcpColor) -> Result<RgbColor> {
+        absolute_xyz_to_rgb(&color.to_xyz(self.transfer), self.gamut_mapping)
+    }
+}
+
+color_converter!(RgbToJzazbzConverter);
+color_converter!(RgbToJzczhzConverter);
+
+impl Conversion<&RgbColor, JzazbzColor> for RgbToJzazbzConverter {
+    fn convert(&self, color: &RgbColor) -> Result<JzazbzColor> {
+        Ok(JzazbzColor::from_xyz(&rgb_to_absolute_xyz(color)?))
+    }
+}
+
+impl Conversion<&RgbColor, JzczhzColor> for RgbToJzczhzConverter {
+    fn convert(&self, color: &RgbColor) -> Result<JzczhzColor> {
+        let jzazbz = RgbToJzazbzConverter {}.convert(color)?;
+        Ok(JzczhzColor::from_jzazbz(&jzazbz))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct JzazbzToRgbConverter {
+    gamut_mapping: GamutMapping,
+}
+
+impl JzazbzToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&JzazbzColor, RgbColor> for JzazbzToRgbConverter {
+    fn convert(&self, color: &JzazbzColor) -> Result<RgbColor> {
+        absolute_xyz_to_rgb(&color.to_xyz(), self.gamut_mapping)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct JzczhzToRgbConverter {
+    gamut_mapping: GamutMapping,
+}
+
+impl JzczhzToRgbConverter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self { gamut_mapping }
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&JzczhzColor, RgbColor> for JzczhzToRgbConverter {
+    fn convert(&self, color: &JzczhzColor) -> Result<RgbColor> {
+        let jzazbz_converter = JzazbzToRgbConverter::new().with_gamut_mapping(self.gamut_mapping);
+        jzazbz_converter.convert(&color.to_jzazbz())
+    }
+}
+
+color_converter!(RgbToLuvConverter);
+color_converter!(RgbToLchuvConverter);
+color_converter!(RgbToHsluvConverter);
+color_converter!(RgbToHpluvConverter);
+color_converter!(LuvToRgbConverter);
+color_converter!(LchuvToRgbConverter);
+color_converter!(HsluvToRgbConverter);
+color_converter!(HpluvToRgbConverter);
+
+impl Conversion<&RgbColor, LuvColor> for RgbToLuvConverter {
+    fn convert(&self, color: &RgbColor) -> Result<LuvColor> {
+        let xyz = RgbToXyzConverter {}.convert(color)?;
+        Ok(LuvColor::from_xyz(&xyz, &WhitePoint::D65))
+    }
+}
+
+impl Conversion<&RgbColor, LchuvColor> for RgbToLchuvConverter {
+    fn convert(&self, color: &RgbColor) -> Result<LchuvColor> {
+        let luv = RgbToLuvConverter {}.convert(color)?;
+        Ok(LchuvColor::from_luv(&luv))
+    }
+}
+
+impl Conversion<&RgbColor, HsluvColor> for RgbToHsluvConverter {
+    fn convert(&self, color: &RgbColor) -> Result<HsluvColor> {
+        let lch = RgbToLchuvConverter {}.convert(color)?;
+        Ok(HsluvColor::from_lchuv(&lch))
+    }
+}
+
+impl Conversion<&RgbColor, HpluvColor> for RgbToHpluvConverter {
+    fn convert(&self, color: &RgbColor) -> Result<HpluvColor> {
+        let lch = RgbToLchuvConverter {}.convert(color)?;
+        Ok(HpluvColor::from_lchuv(&lch))
+    }
+}
+
+impl Conversion<&LuvColor, RgbColor> for LuvToRgbConverter {
+    fn convert(&self, color: &LuvColor) -> Result<RgbColor> {
+        XyzToRgbConverter::new().convert(&color.to_xyz(&WhitePoint::D65))
+    }
+}
+
+impl Conversion<&LchuvColor, RgbColor> for LchuvToRgbConverter {
+    fn convert(&self, color: &LchuvColor) -> Result<RgbColor> {
+        LuvToRgbConverter {}.convert(&color.to_luv())
+    }
+}
+
+impl Conversion<&HsluvColor, RgbColor> for HsluvToRgbConverter {
+    fn convert(&self, color: &HsluvColor) -> Result<RgbColor> {
+        LchuvToRgbConverter {}.convert(&color.to_lchuv())
+    }
+}
+
+impl Conversion<&HpluvColor, RgbColor> for HpluvToRgbConverter {
+    fn convert(&self, color: &HpluvColor) -> Result<RgbColor> {
+        LchuvToRgbConverter {}.convert(&color.to_lchuv())
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct RgbToRgbSpaceConverter {
+    space: RgbSpace,
+    matrix: matrix::Matrix3,
+}
+
+impl RgbToRgbSpaceConverter {
+    pub fn new(space: RgbSpace) -> Self {
+        let matrix = RgbSpace::srgb().conversion_matrix(&space);
+        Self { space, matrix }
+    }
+
+    pub fn space(&self) -> &RgbSpace {
+        &self.space
+    }
+}
+
+impl Conversion<&RgbColor, FloatRgbColor> for RgbToRgbSpaceConverter {
+    fn convert(&self, color: &RgbColor) -> Result<FloatRgbColor> {
+        let linear = [
+            TransferFunction::Srgb.decode(color.r() as f64 / 255_f64),
+            TransferFunction::Srgb.decode(color.g() as f64 / 255_f64),
+            TransferFunction::Srgb.decode(color.b() as f64 / 255_f64),
+        ];
+
+        Ok(self.space.encode(&FloatRgbColor::from_array(
+            matrix::multiply_vector(&self.matrix, linear),
+            color.a() as f64 / 255_f64,
+        )))
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct RgbSpaceToRgbConverter {
+    space: RgbSpace,
+    matrix: matrix::Matrix3,
+    gamut_mapping: GamutMapping,
+}
+
+impl RgbSpaceToRgbConverter {
+    pub fn new(space: RgbSpace) -> Self {
+        let matrix = space.conversion_matrix(&RgbSpace::srgb());
+        Self {
+            space,
+            matrix,
+            gamut_mapping: GamutMapping::default(),
+        }
+    }
+
+    pub fn with_gamut_mapping(self, gamut_mapping: GamutMapping) -> Self {
+        Self {
+            gamut_mapping,
+            ..self
+        }
+    }
+
+    pub fn space(&self) -> &RgbSpace {
+        &self.space
+    }
+
+    pub fn gamut_mapping(&self) -> GamutMapping {
+        self.gamut_mapping
+    }
+}
+
+impl Conversion<&FloatRgbColor, RgbColor> for RgbSpaceToRgbConverter {
+    fn convert(&self, color: &FloatRgbColor) -> Result<RgbColor> {
+        let linear = matrix::multiply_vector(&self.matrix, self.space.decode(color).to_array());
+        Ok(linear_srgb_to_rgb(linear, color.a(), self.gamut_mapping))
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct RgbSpaceToRgbSpaceConverter {
+    source: RgbSpace,
+    destination: RgbSpace,
+    matrix: matrix::Matrix3,
+}
+
+impl RgbSpaceToRgbSpaceConverter {
+    pub fn new(source: RgbSpace, destination: RgbSpace) -> Self {
+        let matrix = source.conversion_matrix(&destination);
+        Self {
+            source,
+            destination,
+            matrix,
+        }
+    }
+
+    pub fn source(&self) -> &RgbSpace {
+        &self.source
+    }
+
+    pub fn destination(&self) -> &RgbSpace {
+        &self.destination
+    }
+}
+
+impl Conversion<&FloatRgbColor, FloatRgbColor> for RgbSpaceToRgbSpaceConverter {
+    fn convert(&self, color: &FloatRgbColor) -> Result<FloatRgbColor> {
+        let linear = matrix::multiply_vector(&self.matrix, self.source.decode(color).to_array());
+        Ok(self
+            .destination
+            .encode(&FloatRgbColor::from_array(linear, color.a())))
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct RgbSpaceToXyzConverter {
+    space: RgbSpace,
+}
+
+impl RgbSpaceToXyzConverter {
+    pub fn new(space: RgbSpace) -> Self {
+        Self { space }
+    }
+}
+
+impl Conversion<&FloatRgbColor, XyzColor> for RgbSpaceToXyzConverter {
+    fn convert(&self, color: &FloatRgbColor) -> Result<XyzColor> {
+        Ok(self.space.to_xyz(color))
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct XyzToRgbSpaceConverter {
+    space: RgbSpace,
+}
+
+impl XyzToRgbSpaceConverter {
+    pub fn new(space: RgbSpace) -> Self {
+        Self { space }
+    }
+}
+
+impl Conversion<&XyzColor, FloatRgbColor> for XyzToRgbSpaceConverter {
+    fn convert(&self, color: &XyzColor) -> Result<FloatRgbColor> {
+        Ok(self.space.from_xyz(color))
+    }
+}
+
+#[allow(dead_code)]
+mod core {
+    const HEX_LOWER_TABLE : &[u8] = b"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff";
+
+    const HEX_UPPER_TABLE : &[u8] = b"000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F202122232425262728292A2B2C2D2E2F303132333435363738393A3B3C3D3E3F404142434445464748494A4B4C4D4E4F505152535455565758595A5B5C5D5E5F606162636465666768696A6B6C6D6E6F707172737475767778797A7B7C7D7E7F808182838485868788898A8B8C8D8E8F909192939495969798999A9B9C9D9E9FA0A1A2A3A4A5A6A7A8A9AAABACADAEAFB0B1B2B3B4B5B6B7B8B9BABBBCBDBEBFC0C1C2C3C4C5C6C7C8C9CACBCCCDCECFD0D1D2D3D4D5D6D7D8D9DADBDCDDDEDFE0E1E2E3E4E5E6E7E8E9EAEBECEDEEEFF0F1F2F3F4F5F6F7F8F9FAFBFCFDFEFF";
+
+    pub(crate) const HASH_MARK_NUMBER: u8 = 35; // #
+
+    pub(crate) fn decimal_to_lower_hexstring(value: u8, arr: &mut [u8]) {
+        let index: usize = (value as usize) << 1;
+        arr[0] = HEX_LOWER_TABLE[index];
+        arr[1] = HEX_LOWER_TABLE[index + 1];
+    }
+
+    pub(crate) fn decimal_to_upper_hexstring(value: u8, arr: &mut [u8]) {
+        let index: usize = (value as usize) << 1;
+        arr[0] = HEX_UPPER_TABLE[index];
+        arr[1] = HEX_UPPER_TABLE[index + 1];
+    }
+
+    pub(crate) fn hexstring_to_decimal(hexstring: &[u8]) -> u8 {
+        match hexstring.len() {
+            1 => hex_char_to_decimal(hexstring[0]),
+            2 => 16 * hex_char_to_decimal(hexstring[0]) + hex_char_to_decimal(hexstring[1]),
+            _ => 0,
+        }
+    }
+
+    fn hex_char_to_decimal(value: u8) -> u8 {
+        let v = value | 0x20u8;
+        match v {
+            48..=57 => v - 48,
+            97..=102 => v - 87,
+            _ => 0,
+        }
+    }
+
+    pub(crate) fn validate_hex_char(value: u8) -> bool {
+        matches!(value, 48..=57 | 65..=70 | 97..=102)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CharCase {
+    Lower,
+    Upper,
+}
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct HtmlColorCode {
+    value: String,
+}
+
+impl Display for HtmlColorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value_ref())
+    }
+}
+
+impl HtmlColorCode {
+    pub fn new(code: impl Into<String>) -> Self {
+        Self { value: code.into() }
+    }
+
+    pub fn value(&self) -> String {
+        self.value.to_string()
+    }
+
+    pub fn value_ref(&self) -> &str {
+        &self.value
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RgbToHtmlConverter {}
+
+impl Conversion<&RgbColor, HtmlColorCode> for RgbToHtmlConverter {
+    fn convert(&self, rgb: &RgbColor) -> Result<HtmlColorCode> {
+        self.convert_with_charcase(rgb, CharCase::Lower)
+    }
+}
+
+impl RgbToHtmlConverter {
+    fn convert_with_charcase(&self, rgb: &RgbColor, charcase: CharCase) -> Result<HtmlColorCode> {
+        match charcase {
+            CharCase::Lower => Ok(self.to_html_lower(rgb)),
+            CharCase::Upper => Ok(self.to_html_upper(rgb)),
+        }
+    }
+
+    fn to_html_lower(self, rgb: &RgbColor) -> HtmlColorCode {
+        let mut html_array: [u8; 7] = Default::default();
+        html_array[0] = core::HASH_MARK_NUMBER;
+        core::decimal_to_lower_hexstring(rgb.r(), &mut html_array[1..3]);
+        core::decimal_to_lower_hexstring(rgb.g(), &mut html_array[3..5]);
+        core::decimal_to_lower_hexstring(rgb.b(), &mut html_array[5..7]);
+        HtmlColorCode::new(html_array.iter().map(|&s| s as char).collect::<String>())
+    }
+
+    fn to_html_upper(self, rgb: &RgbColor) -> HtmlColorCode {
+        let mut html_array: [u8; 7] = Default::default();
+        html_array[0] = core::HASH_MARK_NUMBER;
+        core::decimal_to_upper_hexstring(rgb.r(), &mut html_array[1..3]);
+        core::decimal_to_upper_hexstring(rgb.g(), &mut html_array[3..5]);
+        core::decimal_to_upper_hexstring(rgb.b(), &mut html_array[5..7]);
+        HtmlColorCode::new(html_array.iter().map(|&s| s as char).collect::<String>())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HsvToHtmlConverter {}
+
+impl Conversion<&HsvColor, HtmlColorCode> for HsvToHtmlConverter {
+    fn convert(&self, hsv: &HsvColor) -> Result<HtmlColorCode> {
+        self.convert_with_charcase(hsv, CharCase::Lower)
+    }
+}
+
+impl HsvToHtmlConverter {
+    fn convert_with_charcase(&self, hsv: &HsvColor, charcase: CharCase) -> Result<HtmlColorCode> {
+        let result = HsvToRgbConverter {}.convert(hsv);
+
+        if let Ok(rgb) = result {
+            RgbToHtmlConverter {}.convert_with_charcase(&rgb, charcase)
+        } else {
+            Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                hsv.to_string(),
+            ))
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HslToHtmlConverter {}
+
+impl Conversion<&HslColor, HtmlColorCode> for HslToHtmlConverter {
+    fn convert(&self, hsl: &HslColor) -> Result<HtmlColorCode> {
+        self.convert_with_charcase(hsl, CharCase::Lower)
+    }
+}
+
+impl HslToHtmlConverter {
+    fn convert_with_charcase(&self, hsl: &HslColor, charcase: CharCase) -> Result<HtmlColorCode> {
+        let result = HslToRgbConverter {}.convert(hsl);
+
+        if let Ok(rgb) = result {
+            RgbToHtmlConverter {}.convert_with_charcase(&rgb, charcase)
+        } else {
+            Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                hsl.to_string(),
+            ))
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HwbToHtmlConverter {}
+
+impl Conversion<&HwbColor, HtmlColorCode> for HwbToHtmlConverter {
+    fn convert(&self, hwb: &HwbColor) -> Result<HtmlColorCode> {
+        self.convert_with_charcase(hwb, CharCase::Lower)
+    }
+}
+
+impl HwbToHtmlConverter {
+    fn convert_with_charcase(&self, hwb: &HwbColor, charcase: CharCase) -> Result<HtmlColorCode> {
+        let rgb = HwbToRgbConverter {}.convert(hwb)?;
+        RgbToHtmlConverter {}.convert_with_charcase(&rgb, charcase)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct CmykToHtmlConverter {}
+
+impl Conversion<&CmykColor, HtmlColorCode> for CmykToHtmlConverter {
+    fn convert(&self, cmyk: &CmykColor) -> Result<HtmlColorCode> {
+        self.convert_with_charcase(cmyk, CharCase::Lower)
+    }
+}
+
+impl CmykToHtmlConverter {
+    fn convert_with_charcase(&self, cmyk: &CmykColor, charcase: CharCase) -> Result<HtmlColorCode> {
+        let rgb = CmykToRgbConverter {}.convert(cmyk)?;
+        RgbToHtmlConverter {}.convert_with_charcase(&rgb, charcase)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HtmlToRgbConverter {}
+
+impl Conversion<&HtmlColorCode, RgbColor> for HtmlToRgbConverter {
+    fn convert(&self, html: &HtmlColorCode) -> Result<RgbColor> {
+        self.to_rgb(html)
+    }
+}
+
+impl HtmlToRgbConverter {
+    fn to_rgb(self, html: &HtmlColorCode) -> Result<RgbColor> {
+        let s = html.value.as_bytes();
+
+        if !self.validate_length(s) {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                html.value.to_string(),
+            ));
+        }
+
+        if !self.validate_format(&s[1..]) {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgumentFormat,
+                html.value.to_string(),
+            ));
+        }
+
+        let length = s.len();
+        if s[0] == core::HASH_MARK_NUMBER {
+            match length {
+                7 => Ok(RgbColor::new(
+                    core::hexstring_to_decimal(&s[1..=2]),
+                    core::hexstring_to_decimal(&s[3..=4]),
+                    core::hexstring_to_decimal(&s[5..=6]),
+                )),
+                9 => Ok(RgbColor::from_rgba(
+                    core::hexstring_to_decimal(&s[1..=2]),
+                    core::hexstring_to_decimal(&s[3..=4]),
+                    core::hexstring_to_decimal(&s[5..=6]),
+                    core::hexstring_to_decimal(&s[7..=8]),
+                )),
+                _ => Err(errors::Error::new(
+                    errors::ErrorCode::InvalidArgument,
+                    html.value.to_string(),
+                )),
+            }
+        } else {
+            match length {
+                6 => Ok(RgbColor::new(
+                    core::hexstring_to_decimal(&s[0..=1]),
+                    core::hexstring_to_decimal(&s[2..=3]),
+                    core::hexstring_to_decimal(&s[4..=5]),
+                )),
+                8 => Ok(RgbColor::from_rgba(
+                    core::hexstring_to_decimal(&s[0..=1]),
+                    core::hexstring_to_decimal(&s[2..=3]),
+                    core::hexstring_to_decimal(&s[4..=5]),
+                    core::hexstring_to_decimal(&s[6..=7]),
+                )),
+                _ => Err(errors::Error::new(
+                    errors::ErrorCode::InvalidArgument,
+                    html.value.to_string(),
+                )),
+            }
+        }
+    }
+
+    fn validate_length(&self, raw_html: &[u8]) -> bool {
+        matches!(raw_html.len(), 6..=9)
+    }
+
+    fn validate_format(&self, raw_html: &[u8]) -> bool {
+        !raw_html.iter().any(|&c| !core::validate_hex_char(c))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HtmlToHsvConverter {}
+
+impl Conversion<&HtmlColorCode, HsvColor> for HtmlToHsvConverter {
+    fn convert(&self, html: &HtmlColorCode) -> Result<HsvColor> {
+        let html_to_rgb_converter = HtmlToRgbConverter {};
+        let rgb = html_to_rgb_converter.convert(html)?;
+
+        let rgb_to_hsv_converter = RgbToHsvConverter {};
+        rgb_to_hsv_converter.convert(&rgb)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HtmlToHslConverter {}
+
+impl Conversion<&HtmlColorCode, HslColor> for HtmlToHslConverter {
+    fn convert(&self, html: &HtmlColorCode) -> Result<HslColor> {
+        let html_to_rgb_converter = HtmlToRgbConverter {};
+        let rgb = html_to_rgb_converter.convert(html)?;
+
+        let rgb_to_hsl_converter = RgbToHslConverter {};
+        rgb_to_hsl_converter.convert(&rgb)
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct HtmlToHwbConverter {}
+
+impl Conversion<&HtmlColorCode, HwbColor> for HtmlToHwbConverter {
+    fn convert(&self, html: &HtmlColorCode) -> Result<HwbColor> {
+        let html_to_rgb_converter = HtmlToRgbConverter {};
+        let rgb = html_to_rgb_converter.convert(html)?;
+
+        let rgb_to_hwb_converter = RgbToHwbConverter {};
+        rgb_to_hwb_converter.convert(&rgb)
+    }
+}
+
+impl Conversion<&HtmlColorCode, CmykColor> for HtmlToCmykConverter {
+    fn convert(&self, html: &HtmlColorCode) -> Result<CmykColor> {
+        let rgb = HtmlToRgbConverter {}.convert(html)?;
+        Ok(self.separation.separate(&rgb))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chromaticity::Chromaticity;
+    use crate::ycbcr::{YCbCrMatrix, YCbCrRange};
+
+    #[test]
+    fn rgb_to_hsv_converter_error_check_test() {
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let rgb = RgbColor::new(r, g, b);
+                    let converter = RgbToHsvConverter {};
+                    assert!(converter.convert(&rgb).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_to_hsl_converter_error_check_test() {
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let rgb = RgbColor::new(r, g, b);
+                    let converter = RgbToHslConverter {};
+                    assert!(converter.convert(&rgb).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
     fn hsv_to_rgb_converter_error_check_test() {
         for h in 0..=360 {
             for s in 0..=100 {
@@ -560,168 +1749,946 @@ mod tests {
     }
 
     #[test]
-    fn hsv_to_hsl_converter_error_check_test() {
-        for h in 0..=360 {
-            for s in 0..=100 {
-                for v in 0..=100 {
-                    let hsv = HsvColor::new(h, s, v);
-                    let converter = HsvToHslConverter {};
-                    assert!(converter.convert(&hsv).is_ok());
+    fn hsv_to_hsl_converter_error_check_test() {
+        for h in 0..=360 {
+            for s in 0..=100 {
+                for v in 0..=100 {
+                    let hsv = HsvColor::new(h, s, v);
+                    let converter = HsvToHslConverter {};
+                    assert!(converter.convert(&hsv).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsl_to_rgb_converter_error_check_test() {
+        for h in 0..=360 {
+            for s in 0..=100 {
+                for l in 0..=100 {
+                    let hsl = HslColor::new(h, s, l);
+                    let converter = HslToRgbConverter {};
+                    assert!(converter.convert(&hsl).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsl_to_hsv_converter_error_check_test() {
+        for h in 0..=360 {
+            for s in 0..=100 {
+                for l in 0..=100 {
+                    let hsl = HslColor::new(h, s, l);
+                    let converter = HslToHsvConverter {};
+                    assert!(converter.convert(&hsl).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_to_hsv_converter_convert_test() {
+        let converter = RgbToHsvConverter {};
+        let hsv = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert_eq!(hsv, HsvColor::new(0, 100, 100));
+    }
+
+    #[test]
+    fn rgb_to_hsv_converter_convert_test2() {
+        let converter = RgbToHsvConverter {};
+        let hsv = converter.convert(&RgbColor::new(0, 255, 0)).unwrap();
+        assert_eq!(hsv, HsvColor::new(120, 100, 100));
+    }
+
+    #[test]
+    fn rgb_to_hsv_converter_convert_test3() {
+        let converter = RgbToHsvConverter {};
+        let hsv = converter.convert(&RgbColor::new(0, 0, 255)).unwrap();
+        assert_eq!(hsv, HsvColor::new(240, 100, 100));
+    }
+
+    #[test]
+    fn rgb_to_hsl_converter_convert_test() {
+        let converter = RgbToHslConverter {};
+        let hsl = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert_eq!(hsl, HslColor::new(0, 100, 50));
+    }
+
+    #[test]
+    fn rgb_to_hsl_converter_convert_test2() {
+        let converter = RgbToHslConverter {};
+        let hsl = converter.convert(&RgbColor::new(0, 255, 0)).unwrap();
+        assert_eq!(hsl, HslColor::new(120, 100, 50));
+    }
+
+    #[test]
+    fn rgb_to_hsl_converter_convert_test3() {
+        let converter = RgbToHslConverter {};
+        let hsl = converter.convert(&RgbColor::new(0, 0, 255)).unwrap();
+        assert_eq!(hsl, HslColor::new(240, 100, 50));
+    }
+
+    #[test]
+    fn hsv_to_rgb_converter_convert_test() {
+        let converter = HsvToRgbConverter {};
+        let rgb = converter.convert(&HsvColor::new(0, 100, 100)).unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 0, 0));
+    }
+
+    #[test]
+    fn hsv_to_rgb_converter_convert_test2() {
+        let converter = HsvToRgbConverter {};
+        let rgb = converter.convert(&HsvColor::new(120, 100, 100)).unwrap();
+        assert_eq!(rgb, RgbColor::new(0, 255, 0));
+    }
+
+    #[test]
+    fn hsv_to_rgb_converter_convert_test3() {
+        let converter = HsvToRgbConverter {};
+        let rgb = converter.convert(&HsvColor::new(240, 100, 100)).unwrap();
+        assert_eq!(rgb, RgbColor::new(0, 0, 255));
+    }
+
+    #[test]
+    fn hsv_to_hsl_converter_convert_test() {
+        let converter = HsvToHslConverter {};
+        let hsl = converter.convert(&HsvColor::new(0, 100, 100)).unwrap();
+        assert_eq!(hsl, HslColor::new(0, 100, 50));
+    }
+
+    #[test]
+    fn hsv_to_hsl_converter_convert_test2() {
+        let converter = HsvToHslConverter {};
+        let hsl = converter.convert(&HsvColor::new(120, 100, 100)).unwrap();
+        assert_eq!(hsl, HslColor::new(120, 100, 50));
+    }
+
+    #[test]
+    fn hsv_to_hsl_converter_convert_test3() {
+        let converter = HsvToHslConverter {};
+        let hsl = converter.convert(&HsvColor::new(240, 100, 100)).unwrap();
+        assert_eq!(hsl, HslColor::new(240, 100, 50));
+    }
+
+    #[test]
+    fn hsl_to_rgb_converter_convert_test() {
+        let converter = HslToRgbConverter {};
+        let rgb = converter.convert(&HslColor::new(0, 100, 50)).unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 0, 0));
+    }
+
+    #[test]
+    fn hsl_to_rgb_converter_convert_test2() {
+        let converter = HslToRgbConverter {};
+        let rgb = converter.convert(&HslColor::new(120, 100, 50)).unwrap();
+        assert_eq!(rgb, RgbColor::new(0, 255, 0));
+    }
+
+    #[test]
+    fn hsl_to_rgb_converter_convert_test3() {
+        let converter = HslToRgbConverter {};
+        let rgb = converter.convert(&HslColor::new(240, 100, 50)).unwrap();
+        assert_eq!(rgb, RgbColor::new(0, 0, 255));
+    }
+
+    #[test]
+    fn hsl_to_hsv_converter_convert_test() {
+        let converter = HslToHsvConverter {};
+        let hsv = converter.convert(&HslColor::new(0, 100, 50)).unwrap();
+        assert_eq!(hsv, HsvColor::new(0, 100, 100));
+    }
+
+    #[test]
+    fn hsl_to_hsv_converter_convert_test2() {
+        let converter = HslToHsvConverter {};
+        let hsv = converter.convert(&HslColor::new(120, 100, 50)).unwrap();
+        assert_eq!(hsv, HsvColor::new(120, 100, 100));
+    }
+
+    #[test]
+    fn hsl_to_hsv_converter_convert_test3() {
+        let converter = HslToHsvConverter {};
+        let hsv = converter.convert(&HslColor::new(240, 100, 50)).unwrap();
+        assert_eq!(hsv, HsvColor::new(240, 100, 100));
+    }
+
+    #[test]
+    fn rgb_to_hwb_converter_error_check_test() {
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let rgb = RgbColor::new(r, g, b);
+                    let converter = RgbToHwbConverter {};
+                    assert!(converter.convert(&rgb).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hwb_to_rgb_converter_error_check_test() {
+        for h in 0..=360 {
+            for w in 0..=100 {
+                for b in 0..=100 {
+                    let hwb = HwbColor::new(h, w, b);
+                    let converter = HwbToRgbConverter {};
+                    assert!(converter.convert(&hwb).is_ok());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_to_hwb_converter_convert_test() {
+        let converter = RgbToHwbConverter {};
+        let hwb = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert_eq!(hwb, HwbColor::new(0, 0, 0));
+    }
+
+    #[test]
+    fn rgb_to_hwb_converter_convert_test2() {
+        let converter = RgbToHwbConverter {};
+        let hwb = converter.convert(&RgbColor::new(0x80, 0x80, 0x80)).unwrap();
+        assert_eq!(hwb, HwbColor::new(0, 50, 50));
+    }
+
+    #[test]
+    fn rgb_to_hwb_converter_convert_test3() {
+        let converter = RgbToHwbConverter {};
+        let hwb = converter.convert(&RgbColor::new(0, 0x80, 0)).unwrap();
+        assert_eq!(hwb, HwbColor::new(120, 0, 50));
+    }
+
+    #[test]
+    fn hwb_to_rgb_converter_convert_test() {
+        let converter = HwbToRgbConverter {};
+        let rgb = converter.convert(&HwbColor::new(240, 0, 0)).unwrap();
+        assert_eq!(rgb, RgbColor::new(0, 0, 255));
+    }
+
+    #[test]
+    fn hwb_to_rgb_converter_convert_test2() {
+        let converter = HwbToRgbConverter {};
+        let rgb = converter.convert(&HwbColor::new(120, 60, 60)).unwrap();
+        assert_eq!(rgb, RgbColor::new(128, 128, 128));
+    }
+
+    #[test]
+    fn hwb_to_rgb_converter_convert_test3() {
+        let converter = HwbToRgbConverter {};
+        let rgb = converter.convert(&HwbColor::new(30, 20, 40)).unwrap();
+        assert_eq!(rgb, RgbColor::new(153, 102, 51));
+    }
+
+    #[test]
+    fn hsv_to_hwb_converter_convert_test() {
+        let converter = HsvToHwbConverter {};
+        let hwb = converter.convert(&HsvColor::new(200, 40, 80)).unwrap();
+        assert_eq!(hwb, HwbColor::new(200, 48, 20));
+    }
+
+    #[test]
+    fn hwb_to_hsv_converter_convert_test() {
+        let converter = HwbToHsvConverter {};
+        let hsv = converter.convert(&HwbColor::new(200, 48, 20)).unwrap();
+        assert_eq!(hsv, HsvColor::new(200, 40, 80));
+    }
+
+    #[test]
+    fn hwb_to_hsv_converter_convert_test2() {
+        let converter = HwbToHsvConverter {};
+        let hsv = converter.convert(&HwbColor::new(0, 80, 80)).unwrap();
+        assert_eq!(hsv, HsvColor::new(0, 0, 50));
+    }
+
+    #[test]
+    fn hsl_to_hwb_converter_convert_test() {
+        let converter = HslToHwbConverter {};
+        let hwb = converter.convert(&HslColor::new(120, 100, 50)).unwrap();
+        assert_eq!(hwb, HwbColor::new(120, 0, 0));
+    }
+
+    #[test]
+    fn hwb_to_hsl_converter_convert_test() {
+        let converter = HwbToHslConverter {};
+        let hsl = converter.convert(&HwbColor::new(240, 0, 0)).unwrap();
+        assert_eq!(hsl, HslColor::new(240, 100, 50));
+    }
+
+    #[test]
+    fn hwb_to_html_converter_convert_test() {
+        let html = HwbToHtmlConverter {}
+            .convert(&HwbColor::new(30, 20, 40))
+            .unwrap();
+        assert_eq!(html, HtmlColorCode::new("#996633"));
+
+        let html = HwbToHtmlConverter {}
+            .convert_with_charcase(&HwbColor::new(0, 0, 0), CharCase::Upper)
+            .unwrap();
+        assert_eq!(html, HtmlColorCode::new("#FF0000"));
+    }
+
+    #[test]
+    fn html_to_hwb_converter_convert_test() {
+        let hwb = HtmlToHwbConverter {}
+            .convert(&HtmlColorCode::new("#996633"))
+            .unwrap();
+        assert_eq!(hwb, HwbColor::new(30, 20, 40));
+        assert!(HtmlToHwbConverter {}
+            .convert(&HtmlColorCode::new("#99663"))
+            .is_err());
+    }
+
+    #[test]
+    fn rgb_to_cmyk_converter_convert_test() {
+        let converter = RgbToCmykConverter::new();
+        let cmyk = converter.convert(&RgbColor::new(0, 255, 255)).unwrap();
+        assert_eq!(cmyk, CmykColor::new(100, 0, 0, 0));
+    }
+
+    #[test]
+    fn rgb_to_cmyk_converter_convert_test2() {
+        let separation = CmykSeparation::new()
+            .with_black_generation(crate::cmyk::BlackGeneration::GrayComponentReplacement(0.5))
+            .with_ink_limit(280);
+        let converter = RgbToCmykConverter::new().with_separation(separation);
+        assert_eq!(converter.separation(), separation);
+
+        let cmyk = converter.convert(&RgbColor::new(10, 0, 20)).unwrap();
+        assert!(cmyk.total_ink() <= 280);
+    }
+
+    #[test]
+    fn cmyk_to_rgb_converter_convert_test() {
+        let converter = CmykToRgbConverter {};
+        let rgb = converter.convert(&CmykColor::new(0, 100, 100, 0)).unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 0, 0));
+
+        let rgb = converter.convert(&CmykColor::new(0, 0, 0, 50)).unwrap();
+        assert_eq!(rgb, RgbColor::new(128, 128, 128));
+    }
+
+    #[test]
+    fn cmyk_round_trip_test() {
+        let to_cmyk = RgbToCmykConverter::new();
+        let to_rgb = CmykToRgbConverter {};
+        for r in (0..=255).step_by(51) {
+            for g in (0..=255).step_by(51) {
+                for b in (0..=255).step_by(51) {
+                    let rgb = RgbColor::new(r, g, b);
+                    let cmyk = to_cmyk.convert(&rgb).unwrap();
+                    let actual = to_rgb.convert(&cmyk).unwrap();
+                    // CMYK components are whole percentages
+                    assert!((actual.r() as i16 - rgb.r() as i16).abs() <= 2);
+                    assert!((actual.g() as i16 - rgb.g() as i16).abs() <= 2);
+                    assert!((actual.b() as i16 - rgb.b() as i16).abs() <= 2);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn hsv_to_cmyk_converter_convert_test() {
+        let cmyk = HsvToCmykConverter::new()
+            .convert(&HsvColor::new(240, 100, 100))
+            .unwrap();
+        assert_eq!(cmyk, CmykColor::new(100, 100, 0, 0));
+
+        let hsv = CmykToHsvConverter {}.convert(&cmyk).unwrap();
+        assert_eq!(hsv, HsvColor::new(240, 100, 100));
+    }
+
+    #[test]
+    fn hsl_to_cmyk_converter_convert_test() {
+        let cmyk = HslToCmykConverter::new()
+            .convert(&HslColor::new(120, 100, 50))
+            .unwrap();
+        assert_eq!(cmyk, CmykColor::new(100, 0, 100, 0));
+
+        let hsl = CmykToHslConverter {}.convert(&cmyk).unwrap();
+        assert_eq!(hsl, HslColor::new(120, 100, 50));
+    }
+
+    #[test]
+    fn html_to_cmyk_converter_convert_test() {
+        let cmyk = HtmlToCmykConverter::new()
+            .convert(&HtmlColorCode::new("#ffff00"))
+            .unwrap();
+        assert_eq!(cmyk, CmykColor::new(0, 0, 100, 0));
+
+        let html = CmykToHtmlConverter {}.convert(&cmyk).unwrap();
+        assert_eq!(html, HtmlColorCode::new("#ffff00"));
+        assert!(HtmlToCmykConverter::new()
+            .convert(&HtmlColorCode::new("#fffg00"))
+            .is_err());
+    }
+
+    #[test]
+    fn rgb_to_ycbcr_converter_convert_test() {
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt601, YCbCrRange::Limited);
+        let converter = RgbToYCbCrConverter::new(format);
+        assert_eq!(converter.format(), format);
+        assert_eq!(
+            converter.convert(&RgbColor::new(255, 0, 0)).unwrap(),
+            YCbCrColor::new(81, 90, 240)
+        );
+
+        let converter = RgbToYCbCrConverter::new(format.with_bit_depth(10));
+        assert_eq!(
+            converter.convert(&RgbColor::new(255, 255, 255)).unwrap(),
+            YCbCrColor::new(940, 512, 512)
+        );
+    }
+
+    #[test]
+    fn ycbcr_round_trip_test() {
+        for matrix in [YCbCrMatrix::Bt601, YCbCrMatrix::Bt709, YCbCrMatrix::Bt2020] {
+            let format = YCbCrFormat::new(matrix, YCbCrRange::Full).with_bit_depth(10);
+            let to_ycbcr = RgbToYCbCrConverter::new(format);
+            let to_rgb = YCbCrToRgbConverter::new(format);
+            for r in (0..=255).step_by(15) {
+                for g in (0..=255).step_by(15) {
+                    for b in (0..=255).step_by(15) {
+                        let rgb = RgbColor::new(r, g, b);
+                        let ycbcr = to_ycbcr.convert(&rgb).unwrap();
+                        assert_eq!(to_rgb.convert(&ycbcr).unwrap(), rgb);
+                    }
                 }
             }
         }
     }
 
     #[test]
-    fn hsl_to_rgb_converter_error_check_test() {
-        for h in 0..=360 {
-            for s in 0..=100 {
-                for l in 0..=100 {
-                    let hsl = HslColor::new(h, s, l);
-                    let converter = HslToRgbConverter {};
-                    assert!(converter.convert(&hsl).is_ok());
+    fn ycbcr_to_rgb_converter_code_value_test() {
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt709, YCbCrRange::Limited);
+        let converter = YCbCrToRgbConverter::new(format);
+        assert!(converter.convert(&YCbCrColor::new(1023, 128, 128)).is_err());
+        assert!(converter.convert(&YCbCrColor::new(235, 128, 256)).is_err());
+        assert!(YCbCrToRgbConverter::new(format.with_bit_depth(10))
+            .convert(&YCbCrColor::new(1023, 512, 512))
+            .is_ok());
+
+        let colors = [RgbColor::new(255, 0, 0), RgbColor::new(0, 0, 0)];
+        let mut ycbcr = [YCbCrColor::default(); 2];
+        RgbToYCbCrConverter::new(format)
+            .convert_slice(&colors, &mut ycbcr)
+            .unwrap();
+        let mut rgb = [RgbColor::default(); 2];
+        converter.convert_slice(&ycbcr, &mut rgb).unwrap();
+        assert_eq!(rgb[1], colors[1]);
+        assert!(converter
+            .convert_slice(&[YCbCrColor::new(300, 128, 128)], &mut rgb[..1])
+            .is_err());
+    }
+
+    #[test]
+    fn rgb_to_ycocg_converter_convert_test() {
+        let converter = RgbToYCoCgConverter {};
+        let ycocg = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert_eq!(ycocg, YCoCgColor::new(0.25, 0.5, -0.25));
+
+        let rgb = YCoCgToRgbConverter {}.convert(&ycocg).unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 0, 0));
+    }
+
+    #[test]
+    fn rgb_to_ycocg_r_converter_convert_test() {
+        let converter = RgbToYCoCgRConverter {};
+        let ycocg = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert_eq!(ycocg, YCoCgRColor::new(63, 255, -127));
+
+        let ycocg = converter.convert(&RgbColor::new(0, 255, 0)).unwrap();
+        assert_eq!(ycocg, YCoCgRColor::new(127, 0, 255));
+
+        let ycocg = converter.convert(&RgbColor::new(255, 255, 255)).unwrap();
+        assert_eq!(ycocg, YCoCgRColor::new(255, 0, 0));
+    }
+
+    #[test]
+    fn ycocg_round_trip_test() {
+        let to_ycocg = RgbToYCoCgConverter {};
+        let to_rgb = YCoCgToRgbConverter {};
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let rgb = RgbColor::new(r, g, b);
+                    let ycocg = to_ycocg.convert(&rgb).unwrap();
+                    assert_eq!(to_rgb.convert(&ycocg).unwrap(), rgb);
                 }
             }
         }
     }
 
     #[test]
-    fn hsl_to_hsv_converter_error_check_test() {
-        for h in 0..=360 {
-            for s in 0..=100 {
-                for l in 0..=100 {
-                    let hsl = HslColor::new(h, s, l);
-                    let converter = HslToHsvConverter {};
-                    assert!(converter.convert(&hsl).is_ok());
+    fn ycocg_r_round_trip_test() {
+        let to_ycocg = RgbToYCoCgRConverter {};
+        let to_rgb = YCoCgRToRgbConverter {};
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let rgb = RgbColor::new(r, g, b);
+                    let ycocg = to_ycocg.convert(&rgb).unwrap();
+                    assert_eq!(to_rgb.convert(&ycocg).unwrap(), rgb);
                 }
             }
         }
     }
 
     #[test]
-    fn rgb_to_hsv_converter_convert_test() {
-        let converter = RgbToHsvConverter {};
-        let hsv = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
-        assert_eq!(hsv, HsvColor::new(0, 100, 100));
+    fn ycocg_r_convert_slice_test() {
+        let colors: Vec<RgbColor> = (0..=255)
+            .map(|v| RgbColor::from_rgba(v, 255 - v, v / 2, v))
+            .collect();
+        let mut ycocg = vec![YCoCgRColor::default(); colors.len()];
+        RgbToYCoCgRConverter {}
+            .convert_slice(&colors, &mut ycocg)
+            .unwrap();
+
+        let mut output = vec![RgbColor::default(); colors.len()];
+        YCoCgRToRgbConverter {}
+            .convert_slice(&ycocg, &mut output)
+            .unwrap();
+        assert_eq!(output, colors);
+
+        let mut short = vec![YCoCgRColor::default(); 2];
+        assert!(RgbToYCoCgRConverter {}
+            .convert_slice(&colors, &mut short)
+            .is_err());
     }
 
     #[test]
-    fn rgb_to_hsv_converter_convert_test2() {
-        let converter = RgbToHsvConverter {};
-        let hsv = converter.convert(&RgbColor::new(0, 255, 0)).unwrap();
-        assert_eq!(hsv, HsvColor::new(120, 100, 100));
+    fn ycocg_convert_slice_test() {
+        let colors = [RgbColor::new(1, 2, 3), RgbColor::new(250, 128, 0)];
+        let mut ycocg = [YCoCgColor::default(); 2];
+        RgbToYCoCgConverter {}
+            .convert_slice(&colors, &mut ycocg)
+            .unwrap();
+
+        let mut output = [RgbColor::default(); 2];
+        YCoCgToRgbConverter {}
+            .convert_slice(&ycocg, &mut output)
+            .unwrap();
+        assert_eq!(output, colors);
     }
 
     #[test]
-    fn rgb_to_hsv_converter_convert_test3() {
-        let converter = RgbToHsvConverter {};
-        let hsv = converter.convert(&RgbColor::new(0, 0, 255)).unwrap();
-        assert_eq!(hsv, HsvColor::new(240, 100, 100));
+    fn rgb_to_luv_converter_convert_test() {
+        let luv = RgbToLuvConverter {}
+            .convert(&RgbColor::new(255, 0, 0))
+            .unwrap();
+        assert!((luv.l() - 53.2408).abs() < 1e-3);
+        assert!((luv.u() - 175.0150).abs() < 1e-2);
+        assert!((luv.v() - 37.7564).abs() < 1e-2);
+
+        let lch = RgbToLchuvConverter {}
+            .convert(&RgbColor::new(255, 0, 0))
+            .unwrap();
+        assert!((lch.c() - 179.0414).abs() < 1e-2);
     }
 
     #[test]
-    fn rgb_to_hsl_converter_convert_test() {
-        let converter = RgbToHslConverter {};
-        let hsl = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
-        assert_eq!(hsl, HslColor::new(0, 100, 50));
+    fn rgb_to_hsluv_converter_convert_test() {
+        // hsluv.org derives its values from a slightly different sRGB matrix
+        let hsluv = RgbToHsluvConverter {}
+            .convert(&RgbColor::new(0, 0, 255))
+            .unwrap();
+        assert!((hsluv.h() - 265.8743).abs() < 1e-1);
+        assert!((hsluv.s() - 100_f64).abs() < 1e-1);
+        assert!((hsluv.l() - 32.3030).abs() < 1e-1);
+
+        let gray = RgbToHsluvConverter {}
+            .convert(&RgbColor::new(128, 128, 128))
+            .unwrap();
+        assert!(gray.s() < 1e-3);
     }
 
     #[test]
-    fn rgb_to_hsl_converter_convert_test2() {
-        let converter = RgbToHslConverter {};
-        let hsl = converter.convert(&RgbColor::new(0, 255, 0)).unwrap();
-        assert_eq!(hsl, HslColor::new(120, 100, 50));
+    fn luv_round_trip_test() {
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let rgb = RgbColor::new(r, g, b);
+                    let luv = RgbToLuvConverter {}.convert(&rgb).unwrap();
+                    assert_eq!(LuvToRgbConverter {}.convert(&luv).unwrap(), rgb);
+
+                    let lch = RgbToLchuvConverter {}.convert(&rgb).unwrap();
+                    assert_eq!(LchuvToRgbConverter {}.convert(&lch).unwrap(), rgb);
+
+                    let hsluv = RgbToHsluvConverter {}.convert(&rgb).unwrap();
+                    assert_eq!(HsluvToRgbConverter {}.convert(&hsluv).unwrap(), rgb);
+
+                    let hpluv = RgbToHpluvConverter {}.convert(&rgb).unwrap();
+                    assert_eq!(HpluvToRgbConverter {}.convert(&hpluv).unwrap(), rgb);
+                }
+            }
+        }
     }
 
     #[test]
-    fn rgb_to_hsl_converter_convert_test3() {
-        let converter = RgbToHslConverter {};
-        let hsl = converter.convert(&RgbColor::new(0, 0, 255)).unwrap();
-        assert_eq!(hsl, HslColor::new(240, 100, 50));
+    fn hsluv_to_rgb_converter_convert_test() {
+        // fully saturated HSLuv colors stay inside sRGB
+        for h in (0..360).step_by(10) {
+            let hsluv = HsluvColor::new(h as f64, 100_f64, 60_f64);
+            let rgb = HsluvToRgbConverter {}.convert(&hsluv).unwrap();
+            let actual = RgbToHsluvConverter {}.convert(&rgb).unwrap();
+            assert!((actual.l() - 60_f64).abs() < 0.5);
+        }
     }
 
     #[test]
-    fn hsv_to_rgb_converter_convert_test() {
-        let converter = HsvToRgbConverter {};
-        let rgb = converter.convert(&HsvColor::new(0, 100, 100)).unwrap();
-        assert_eq!(rgb, RgbColor::new(255, 0, 0));
+    fn rgb_to_xyy_converter_convert_test() {
+        let xyy = RgbToXyYConverter {}
+            .convert(&RgbColor::new(0, 255, 0))
+            .unwrap();
+        assert!((xyy.x() - 0.30).abs() < 1e-3);
+        assert!((xyy.y() - 0.60).abs() < 1e-3);
+        assert!((xyy.luminance() - 0.7151522).abs() < 1e-6);
+        assert!(
+            xyy.uv_prime()
+                .distance(&Chromaticity::new(0.30, 0.60).to_uv_prime())
+                < 1e-3
+        );
+
+        let white = RgbToXyYConverter {}
+            .convert(&RgbColor::new(255, 255, 255))
+            .unwrap();
+        assert!(
+            white
+                .chromaticity()
+                .distance(&Chromaticity::new(0.3127, 0.3290))
+                < 1e-4
+        );
+
+        let rgb = XyYToRgbConverter::new().convert(&xyy).unwrap();
+        assert_eq!(rgb, RgbColor::new(0, 255, 0));
     }
 
     #[test]
-    fn hsv_to_rgb_converter_convert_test2() {
-        let converter = HsvToRgbConverter {};
-        let rgb = converter.convert(&HsvColor::new(120, 100, 100)).unwrap();
-        assert_eq!(rgb, RgbColor::new(0, 255, 0));
+    fn color_temperature_to_rgb_converter_convert_test() {
+        let converter = ColorTemperatureToRgbConverter::new();
+        let rgb = converter
+            .convert(&ColorTemperature::new(6504_f64).with_duv(0.0032))
+            .unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 255, 255));
+
+        let warm = converter.convert(&ColorTemperature::new(2700_f64)).unwrap();
+        assert_eq!(warm.r(), 255);
+        assert!(warm.g() < 255 && warm.b() < warm.g());
+
+        let cool = converter
+            .convert(&ColorTemperature::new(12000_f64))
+            .unwrap();
+        assert_eq!(cool.b(), 255);
+        assert!(cool.r() < cool.g());
     }
 
     #[test]
-    fn hsv_to_rgb_converter_convert_test3() {
-        let converter = HsvToRgbConverter {};
-        let rgb = converter.convert(&HsvColor::new(240, 100, 100)).unwrap();
-        assert_eq!(rgb, RgbColor::new(0, 0, 255));
+    fn rgb_to_color_temperature_converter_convert_test() {
+        for method in [CctMethod::Robertson, CctMethod::Ohno] {
+            let converter = RgbToColorTemperatureConverter::new().with_method(method);
+            assert_eq!(converter.method(), method);
+
+            let cct = converter.convert(&RgbColor::new(255, 255, 255)).unwrap();
+            assert!((cct.kelvin() - 6504_f64).abs() < 5_f64);
+            assert!((cct.duv() - 0.0032).abs() < 2e-4);
+
+            assert!(converter.convert(&RgbColor::new(0, 0, 0)).is_err());
+        }
     }
 
     #[test]
-    fn hsv_to_hsl_converter_convert_test() {
-        let converter = HsvToHslConverter {};
-        let hsl = converter.convert(&HsvColor::new(0, 100, 100)).unwrap();
-        assert_eq!(hsl, HslColor::new(0, 100, 50));
+    fn reflectance_to_rgb_converter_convert_test() {
+        let white = SampledSpectrum::new(380_f64, 10_f64, vec![1_f64; 36]).unwrap();
+        let converter = ReflectanceToRgbConverter::new();
+        assert_eq!(
+            converter.convert(&white).unwrap(),
+            RgbColor::new(255, 255, 255)
+        );
+
+        // the perfect reflector stays white under any illuminant
+        let converter =
+            ReflectanceToRgbConverter::with_illuminant(SampledSpectrum::blackbody(2856_f64))
+                .unwrap();
+        assert_eq!(
+            converter.convert(&white).unwrap(),
+            RgbColor::new(255, 255, 255)
+        );
+
+        // long pass filter
+        let values = (0..36).map(|i| if i >= 22 { 0.9 } else { 0.02 }).collect();
+        let red = SampledSpectrum::new(380_f64, 10_f64, values).unwrap();
+        let rgb = ReflectanceToRgbConverter::new().convert(&red).unwrap();
+        assert!(rgb.r() > 200 && rgb.g() < 100 && rgb.b() < 100, "{}", rgb);
     }
 
     #[test]
-    fn hsv_to_hsl_converter_convert_test2() {
-        let converter = HsvToHslConverter {};
-        let hsl = converter.convert(&HsvColor::new(120, 100, 100)).unwrap();
-        assert_eq!(hsl, HslColor::new(120, 100, 50));
+    fn rgb_to_reflectance_converter_convert_test() {
+        let to_rgb = ReflectanceToRgbConverter::new();
+        let converter = RgbToReflectanceConverter::new();
+        for color in [
+            RgbColor::new(0, 0, 255),
+            RgbColor::new(255, 255, 0),
+            RgbColor::new(200, 30, 40),
+            RgbColor::new(128, 128, 128),
+            RgbColor::new(0, 0, 0),
+            RgbColor::new(255, 255, 255),
+            RgbColor::new(10, 250, 30),
+        ] {
+            let reflectance = converter.convert(&color).unwrap();
+            assert_eq!(reflectance.values().len(), upsampling::SAMPLES);
+            assert!(reflectance
+                .values()
+                .iter()
+                .all(|value| (0_f64..=1_f64).contains(value)));
+            assert_eq!(to_rgb.convert(&reflectance).unwrap(), color);
+        }
+
+        // Smits is an approximation
+        let converter = RgbToReflectanceConverter::new().with_upsampling(SpectralUpsampling::Smits);
+        let reflectance = converter.convert(&RgbColor::new(200, 30, 40)).unwrap();
+        let rgb = to_rgb.convert(&reflectance).unwrap();
+        assert!(rgb.r() > 150 && rgb.g() < 80 && rgb.b() < 80, "{}", rgb);
     }
 
     #[test]
-    fn hsv_to_hsl_converter_convert_test3() {
-        let converter = HsvToHslConverter {};
-        let hsl = converter.convert(&HsvColor::new(240, 100, 100)).unwrap();
-        assert_eq!(hsl, HslColor::new(240, 100, 50));
+    fn rgb_to_ictcp_converter_convert_test() {
+        for transfer in [IctcpTransfer::Pq, IctcpTransfer::Hlg] {
+            let to_ictcp = RgbToIctcpConverter::new().with_transfer(transfer);
+            let to_rgb = IctcpToRgbConverter::new().with_transfer(transfer);
+            for r in (0..=255).step_by(51) {
+                for g in (0..=255).step_by(51) {
+                    for b in (0..=255).step_by(51) {
+                        let rgb = RgbColor::new(r, g, b);
+                        let ictcp = to_ictcp.convert(&rgb).unwrap();
+                        assert_eq!(to_rgb.convert(&ictcp).unwrap(), rgb);
+                    }
+                }
+            }
+        }
+
+        // sRGB white is 203 cd/m2 (BT.2408)
+        let white = RgbToIctcpConverter::new()
+            .convert(&RgbColor::new(255, 255, 255))
+            .unwrap();
+        assert!((white.i() - 0.58).abs() < 1e-2, "{}", white);
     }
 
     #[test]
-    fn hsl_to_rgb_converter_convert_test() {
-        let converter = HslToRgbConverter {};
-        let rgb = converter.convert(&HslColor::new(0, 100, 50)).unwrap();
+    fn rgb_to_jzazbz_converter_convert_test() {
+        let to_jzczhz = RgbToJzczhzConverter {};
+        let to_rgb = JzczhzToRgbConverter::new();
+        for r in (0..=255).step_by(51) {
+            for g in (0..=255).step_by(51) {
+                for b in (0..=255).step_by(51) {
+                    let rgb = RgbColor::new(r, g, b);
+                    let jzczhz = to_jzczhz.convert(&rgb).unwrap();
+                    assert_eq!(to_rgb.convert(&jzczhz).unwrap(), rgb);
+                }
+            }
+        }
+
+        let white = RgbToJzazbzConverter {}
+            .convert(&RgbColor::new(255, 255, 255))
+            .unwrap();
+        assert!(
+            white.az().abs() < 1e-3 && white.bz().abs() < 1e-3,
+            "{}",
+            white
+        );
+    }
+
+    #[test]
+    fn linear_rgb_to_rgb_converter_convert_test() {
+        let converter = LinearRgbToRgbConverter::new();
+        assert_eq!(
+            converter
+                .convert(&FloatRgbColor::new(0.2158605, 2_f64, -1_f64))
+                .unwrap(),
+            RgbColor::new(128, 255, 0)
+        );
+
+        // one stop down
+        let converter = converter.with_exposure(-1_f64);
+        assert_eq!(
+            converter
+                .convert(&FloatRgbColor::new(0.431721, 0.431721, 0.431721))
+                .unwrap(),
+            RgbColor::new(128, 128, 128)
+        );
+
+        let converter = LinearRgbToRgbConverter::new().with_tone_mapping(ToneMapping::Reinhard);
+        let colors = [
+            FloatRgbColor::new(1_f64, 1_f64, 1_f64),
+            FloatRgbColor::from_rgba(1000_f64, 0_f64, 0.5, 0.5),
+        ];
+        let mut output = [RgbColor::default(); 2];
+        converter.convert_slice(&colors, &mut output).unwrap();
+        assert_eq!(output[0], RgbColor::new(188, 188, 188));
+        assert_eq!(output[1].r(), 255);
+        assert_eq!(output[1].a(), 128);
+        assert!(converter
+            .convert_slice(&colors, &mut [RgbColor::default(); 1])
+            .is_err());
+    }
+
+    #[test]
+    fn rgb_to_xyz_converter_convert_test() {
+        let converter = RgbToXyzConverter {};
+        let xyz = converter.convert(&RgbColor::new(255, 255, 255)).unwrap();
+        assert!((xyz.x() - 0.95047).abs() < 1e-4);
+        assert!((xyz.y() - 1_f64).abs() < 1e-4);
+        assert!((xyz.z() - 1.08883).abs() < 1e-4);
+        assert_eq!(xyz.a(), 1_f64);
+    }
+
+    #[test]
+    fn xyz_to_rgb_converter_convert_test() {
+        let converter = XyzToRgbConverter::new();
+        let rgb = converter
+            .convert(&XyzColor::new(0.4124564, 0.2126729, 0.0193339))
+            .unwrap();
         assert_eq!(rgb, RgbColor::new(255, 0, 0));
     }
 
     #[test]
-    fn hsl_to_rgb_converter_convert_test2() {
-        let converter = HslToRgbConverter {};
-        let rgb = converter.convert(&HslColor::new(120, 100, 50)).unwrap();
-        assert_eq!(rgb, RgbColor::new(0, 255, 0));
+    fn rgb_to_xyz_to_rgb_round_trip_test() {
+        let to_xyz = RgbToXyzConverter {};
+        let to_rgb = XyzToRgbConverter::new();
+        for r in 0..=255 {
+            for g in 0..=255 {
+                for b in 0..=255 {
+                    let rgb = RgbColor::from_rgba(r, g, b, 128);
+                    let xyz = to_xyz.convert(&rgb).unwrap();
+                    assert_eq!(to_rgb.convert(&xyz).unwrap(), rgb);
+                }
+            }
+        }
     }
 
     #[test]
-    fn hsl_to_rgb_converter_convert_test3() {
-        let converter = HslToRgbConverter {};
-        let rgb = converter.convert(&HslColor::new(240, 100, 50)).unwrap();
-        assert_eq!(rgb, RgbColor::new(0, 0, 255));
+    fn rgb_to_rgb_space_converter_convert_test() {
+        let converter = RgbToRgbSpaceConverter::new(RgbSpace::display_p3());
+        let p3 = converter.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert!((p3.r() - 0.9175).abs() < 1e-4);
+        assert!((p3.g() - 0.2003).abs() < 1e-4);
+        assert!((p3.b() - 0.1386).abs() < 1e-4);
     }
 
     #[test]
-    fn hsl_to_hsv_converter_convert_test() {
-        let converter = HslToHsvConverter {};
-        let hsv = converter.convert(&HslColor::new(0, 100, 50)).unwrap();
-        assert_eq!(hsv, HsvColor::new(0, 100, 100));
+    fn rgb_space_to_rgb_converter_convert_test() {
+        let converter = RgbSpaceToRgbConverter::new(RgbSpace::display_p3());
+        let rgb = converter
+            .convert(&FloatRgbColor::new(1_f64, 1_f64, 1_f64))
+            .unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 255, 255));
     }
 
     #[test]
-    fn hsl_to_hsv_converter_convert_test2() {
-        let converter = HslToHsvConverter {};
-        let hsv = converter.convert(&HslColor::new(120, 100, 50)).unwrap();
-        assert_eq!(hsv, HsvColor::new(120, 100, 100));
+    fn rgb_space_round_trip_test() {
+        let spaces = [
+            RgbSpace::srgb(),
+            RgbSpace::display_p3(),
+            RgbSpace::adobe_rgb(),
+            RgbSpace::rec709(),
+            RgbSpace::rec2020(),
+            RgbSpace::prophoto_rgb(),
+        ];
+        for space in spaces.iter() {
+            let to_space = RgbToRgbSpaceConverter::new(space.clone());
+            let to_rgb = RgbSpaceToRgbConverter::new(space.clone());
+            for r in (0..=255).step_by(15) {
+                for g in (0..=255).step_by(15) {
+                    for b in (0..=255).step_by(15) {
+                        let rgb = RgbColor::new(r, g, b);
+                        let color = to_space.convert(&rgb).unwrap();
+                        assert_eq!(to_rgb.convert(&color).unwrap(), rgb);
+                    }
+                }
+            }
+        }
     }
 
     #[test]
-    fn hsl_to_hsv_converter_convert_test3() {
-        let converter = HslToHsvConverter {};
-        let hsv = converter.convert(&HslColor::new(240, 100, 50)).unwrap();
-        assert_eq!(hsv, HsvColor::new(240, 100, 100));
+    fn rgb_space_to_rgb_space_converter_convert_test() {
+        let converter = RgbSpaceToRgbSpaceConverter::new(RgbSpace::rec2020(), RgbSpace::srgb());
+        let srgb = converter
+            .convert(&FloatRgbColor::new(0_f64, 1_f64, 0_f64))
+            .unwrap();
+        // Rec.2020 green is out of sRGB gamut
+        assert!(srgb.r() < 0_f64);
+        assert!(srgb.g() > 1_f64);
+        assert!(srgb.b() < 0_f64);
+    }
+
+    #[test]
+    fn rgb_space_to_xyz_converter_convert_test() {
+        let converter = RgbSpaceToXyzConverter::new(RgbSpace::prophoto_rgb());
+        let xyz = converter
+            .convert(&FloatRgbColor::new(1_f64, 1_f64, 1_f64))
+            .unwrap();
+        assert!((xyz.x() - 0.964295).abs() < 1e-6);
+        assert!((xyz.z() - 0.825105).abs() < 1e-6);
+
+        let converter = XyzToRgbSpaceConverter::new(RgbSpace::prophoto_rgb());
+        let prophoto = converter.convert(&xyz).unwrap();
+        assert!((prophoto.r() - 1_f64).abs() < 1e-9);
+        assert!((prophoto.g() - 1_f64).abs() < 1e-9);
+        assert!((prophoto.b() - 1_f64).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rgb_to_oklab_converter_convert_test() {
+        let oklab = RgbToOklabConverter {}
+            .convert(&RgbColor::new(255, 255, 255))
+            .unwrap();
+        assert!((oklab.l() - 1_f64).abs() < 1e-3);
+        assert!(oklab.a().abs() < 1e-3);
+        assert!(oklab.b().abs() < 1e-3);
+
+        let rgb = OklabToRgbConverter::new().convert(&oklab).unwrap();
+        assert_eq!(rgb, RgbColor::new(255, 255, 255));
+    }
+
+    #[test]
+    fn rgb_to_oklch_converter_round_trip_test() {
+        let to_oklch = RgbToOklchConverter {};
+        let to_rgb = OklchToRgbConverter::new().with_gamut_mapping(GamutMapping::Css4);
+        for r in (0..=255).step_by(15) {
+            for g in (0..=255).step_by(15) {
+                for b in (0..=255).step_by(15) {
+                    let rgb = RgbColor::new(r, g, b);
+                    let oklch = to_oklch.convert(&rgb).unwrap();
+                    assert_eq!(to_rgb.convert(&oklch).unwrap(), rgb);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn oklch_to_rgb_converter_gamut_mapping_test() {
+        // oklch(0.7 0.4 150) is far outside of sRGB
+        let oklch = OklchColor::new(0.7, 0.4, 150_f64);
+        let clip = OklchToRgbConverter::new().convert(&oklch).unwrap();
+        let css4 = OklchToRgbConverter::new()
+            .with_gamut_mapping(GamutMapping::Css4)
+            .convert(&oklch)
+            .unwrap();
+
+        let lightness = |rgb: &RgbColor| RgbToOklchConverter {}.convert(rgb).unwrap().l();
+        assert!((lightness(&css4) - 0.7).abs() < (lightness(&clip) - 0.7).abs());
+    }
+
+    #[test]
+    fn rgb_space_to_rgb_converter_gamut_mapping_test() {
+        let converter = RgbSpaceToRgbConverter::new(RgbSpace::display_p3())
+            .with_gamut_mapping(GamutMapping::Perceptual);
+        assert_eq!(converter.gamut_mapping(), GamutMapping::Perceptual);
+
+        let rgb = converter
+            .convert(&FloatRgbColor::new(0_f64, 1_f64, 0_f64))
+            .unwrap();
+        assert!(rgb.g() > rgb.r() && rgb.g() > rgb.b());
     }
 
     #[test]
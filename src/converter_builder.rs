@@ -1,9 +1,15 @@
 use std::marker::PhantomData;
 
+use crate::cmyk::CmykColor;
 use crate::converter::*;
+use crate::float_rgb::FloatRgbColor;
 use crate::hsl::HslColor;
 use crate::hsv::HsvColor;
+use crate::hwb::HwbColor;
 use crate::rgb::RgbColor;
+use crate::rgb_space::RgbSpace;
+use crate::xyz::XyzColor;
+use crate::ycbcr::{YCbCrColor, YCbCrFormat};
 
 pub struct ColorConverterBuilder {}
 pub struct ColorConverterFromBuilder<TFrom>(PhantomData<TFrom>);
@@ -13,6 +19,31 @@ pub struct HtmlConverterFromBuilder {}
 
 pub struct HtmlConverterFromToBuilder<TTo>(PhantomData<TTo>);
 
+pub struct RgbSpaceConverterFromBuilder {
+    space: RgbSpace,
+}
+
+pub struct YCbCrConverterFromBuilder {
+    format: YCbCrFormat,
+}
+
+pub struct YCbCrConverterFromToBuilder<TFrom, TTo> {
+    format: YCbCrFormat,
+    phantom: PhantomData<(TFrom, TTo)>,
+}
+
+pub struct RgbSpaceConverterFromToBuilder<TFrom, TTo> {
+    source: RgbSpace,
+    destination: RgbSpace,
+    phantom: PhantomData<(TFrom, TTo)>,
+}
+
+impl Default for ColorConverterBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ColorConverterBuilder {
     pub fn new() -> Self {
         ColorConverterBuilder {}
@@ -30,9 +61,25 @@ impl ColorConverterBuilder {
         ColorConverterFromBuilder(PhantomData)
     }
 
+    pub fn from_hwb(&self) -> ColorConverterFromBuilder<HwbColor> {
+        ColorConverterFromBuilder(PhantomData)
+    }
+
+    pub fn from_cmyk(&self) -> ColorConverterFromBuilder<CmykColor> {
+        ColorConverterFromBuilder(PhantomData)
+    }
+
     pub fn from_html(&self) -> HtmlConverterFromBuilder {
         HtmlConverterFromBuilder {}
     }
+
+    pub fn from_rgb_space(&self, space: RgbSpace) -> RgbSpaceConverterFromBuilder {
+        RgbSpaceConverterFromBuilder { space }
+    }
+
+    pub fn from_ycbcr(&self, format: YCbCrFormat) -> YCbCrConverterFromBuilder {
+        YCbCrConverterFromBuilder { format }
+    }
 }
 
 impl HtmlConverterFromBuilder {
@@ -47,6 +94,108 @@ impl HtmlConverterFromBuilder {
     pub fn to_hsl(&self) -> ColorConverterFromToBuilder<HtmlColorCode, HslColor> {
         ColorConverterFromToBuilder(PhantomData, PhantomData)
     }
+
+    pub fn to_hwb(&self) -> ColorConverterFromToBuilder<HtmlColorCode, HwbColor> {
+        ColorConverterFromToBuilder(PhantomData, PhantomData)
+    }
+
+    pub fn to_cmyk(&self) -> ColorConverterFromToBuilder<HtmlColorCode, CmykColor> {
+        ColorConverterFromToBuilder(PhantomData, PhantomData)
+    }
+}
+
+impl RgbSpaceConverterFromBuilder {
+    pub fn to_rgb(&self) -> RgbSpaceConverterFromToBuilder<FloatRgbColor, RgbColor> {
+        RgbSpaceConverterFromToBuilder::new(self.space.clone(), RgbSpace::srgb())
+    }
+
+    pub fn to_rgb_space(
+        &self,
+        space: RgbSpace,
+    ) -> RgbSpaceConverterFromToBuilder<FloatRgbColor, FloatRgbColor> {
+        RgbSpaceConverterFromToBuilder::new(self.space.clone(), space)
+    }
+
+    pub fn to_xyz(&self) -> RgbSpaceConverterFromToBuilder<FloatRgbColor, XyzColor> {
+        RgbSpaceConverterFromToBuilder::new(self.space.clone(), self.space.clone())
+    }
+}
+
+impl ColorConverterFromBuilder<RgbColor> {
+    pub fn to_rgb_space(
+        &self,
+        space: RgbSpace,
+    ) -> RgbSpaceConverterFromToBuilder<RgbColor, FloatRgbColor> {
+        RgbSpaceConverterFromToBuilder::new(RgbSpace::srgb(), space)
+    }
+
+    pub fn to_ycbcr(
+        &self,
+        format: YCbCrFormat,
+    ) -> YCbCrConverterFromToBuilder<RgbColor, YCbCrColor> {
+        YCbCrConverterFromToBuilder::new(format)
+    }
+}
+
+impl YCbCrConverterFromBuilder {
+    pub fn to_rgb(&self) -> YCbCrConverterFromToBuilder<YCbCrColor, RgbColor> {
+        YCbCrConverterFromToBuilder::new(self.format)
+    }
+}
+
+impl<TFrom, TTo> YCbCrConverterFromToBuilder<TFrom, TTo> {
+    fn new(format: YCbCrFormat) -> Self {
+        Self {
+            format,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl YCbCrConverterFromToBuilder<RgbColor, YCbCrColor> {
+    pub fn build(&self) -> RgbToYCbCrConverter {
+        RgbToYCbCrConverter::new(self.format)
+    }
+}
+
+impl YCbCrConverterFromToBuilder<YCbCrColor, RgbColor> {
+    pub fn build(&self) -> YCbCrToRgbConverter {
+        YCbCrToRgbConverter::new(self.format)
+    }
+}
+
+impl<TFrom, TTo> RgbSpaceConverterFromToBuilder<TFrom, TTo> {
+    fn new(source: RgbSpace, destination: RgbSpace) -> Self {
+        Self {
+            source,
+            destination,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl RgbSpaceConverterFromToBuilder<RgbColor, FloatRgbColor> {
+    pub fn build(&self) -> RgbToRgbSpaceConverter {
+        RgbToRgbSpaceConverter::new(self.destination.clone())
+    }
+}
+
+impl RgbSpaceConverterFromToBuilder<FloatRgbColor, RgbColor> {
+    pub fn build(&self) -> RgbSpaceToRgbConverter {
+        RgbSpaceToRgbConverter::new(self.source.clone())
+    }
+}
+
+impl RgbSpaceConverterFromToBuilder<FloatRgbColor, FloatRgbColor> {
+    pub fn build(&self) -> RgbSpaceToRgbSpaceConverter {
+        RgbSpaceToRgbSpaceConverter::new(self.source.clone(), self.destination.clone())
+    }
+}
+
+impl RgbSpaceConverterFromToBuilder<FloatRgbColor, XyzColor> {
+    pub fn build(&self) -> RgbSpaceToXyzConverter {
+        RgbSpaceToXyzConverter::new(self.source.clone())
+    }
 }
 
 #[allow(unused_macros)]
@@ -65,6 +214,14 @@ macro_rules! color_from_builder {
                 ColorConverterFromToBuilder(PhantomData, PhantomData)
             }
 
+            pub fn to_hwb(&self) -> ColorConverterFromToBuilder<$from_name, HwbColor> {
+                ColorConverterFromToBuilder(PhantomData, PhantomData)
+            }
+
+            pub fn to_cmyk(&self) -> ColorConverterFromToBuilder<$from_name, CmykColor> {
+                ColorConverterFromToBuilder(PhantomData, PhantomData)
+            }
+
             pub fn to_html(&self) -> ColorConverterFromToBuilder<$from_name, HtmlColorCode> {
                 ColorConverterFromToBuilder(PhantomData, PhantomData)
             }
@@ -82,31 +239,63 @@ macro_rules! color_from_to_builder {
             }
         }
     };
+    ($converter_name: ident, $from_name: ident, $to_name: ident, $constructor: ident) => {
+        impl ColorConverterFromToBuilder<$from_name, $to_name> {
+            #[allow(dead_code)]
+            pub fn build(&self) -> $converter_name {
+                $converter_name::$constructor()
+            }
+        }
+    };
 }
 
 color_from_builder!(RgbColor);
 color_from_builder!(HsvColor);
 color_from_builder!(HslColor);
+color_from_builder!(HwbColor);
+color_from_builder!(CmykColor);
 
 color_from_to_builder!(RgbToRgbConverter, RgbColor, RgbColor);
 color_from_to_builder!(RgbToHsvConverter, RgbColor, HsvColor);
 color_from_to_builder!(RgbToHslConverter, RgbColor, HslColor);
+color_from_to_builder!(RgbToHwbConverter, RgbColor, HwbColor);
+color_from_to_builder!(RgbToCmykConverter, RgbColor, CmykColor, new);
 
 color_from_to_builder!(HsvToRgbConverter, HsvColor, RgbColor);
 color_from_to_builder!(HsvToHsvConverter, HsvColor, HsvColor);
 color_from_to_builder!(HsvToHslConverter, HsvColor, HslColor);
+color_from_to_builder!(HsvToHwbConverter, HsvColor, HwbColor);
+color_from_to_builder!(HsvToCmykConverter, HsvColor, CmykColor, new);
 
 color_from_to_builder!(HslToRgbConverter, HslColor, RgbColor);
 color_from_to_builder!(HslToHsvConverter, HslColor, HsvColor);
 color_from_to_builder!(HslToHslConverter, HslColor, HslColor);
+color_from_to_builder!(HslToHwbConverter, HslColor, HwbColor);
+color_from_to_builder!(HslToCmykConverter, HslColor, CmykColor, new);
+
+color_from_to_builder!(HwbToRgbConverter, HwbColor, RgbColor);
+color_from_to_builder!(HwbToHsvConverter, HwbColor, HsvColor);
+color_from_to_builder!(HwbToHslConverter, HwbColor, HslColor);
+color_from_to_builder!(HwbToHwbConverter, HwbColor, HwbColor);
+color_from_to_builder!(HwbToCmykConverter, HwbColor, CmykColor, new);
+
+color_from_to_builder!(CmykToRgbConverter, CmykColor, RgbColor);
+color_from_to_builder!(CmykToHsvConverter, CmykColor, HsvColor);
+color_from_to_builder!(CmykToHslConverter, CmykColor, HslColor);
+color_from_to_builder!(CmykToHwbConverter, CmykColor, HwbColor);
+color_from_to_builder!(CmykToCmykConverter, CmykColor, CmykColor);
 
 color_from_to_builder!(HtmlToRgbConverter, HtmlColorCode, RgbColor);
 color_from_to_builder!(HtmlToHsvConverter, HtmlColorCode, HsvColor);
 color_from_to_builder!(HtmlToHslConverter, HtmlColorCode, HslColor);
+color_from_to_builder!(HtmlToHwbConverter, HtmlColorCode, HwbColor);
+color_from_to_builder!(HtmlToCmykConverter, HtmlColorCode, CmykColor, new);
 
 color_from_to_builder!(RgbToHtmlConverter, RgbColor, HtmlColorCode);
 color_from_to_builder!(HslToHtmlConverter, HsvColor, HtmlColorCode);
 color_from_to_builder!(HsvToHtmlConverter, HslColor, HtmlColorCode);
+color_from_to_builder!(HwbToHtmlConverter, HwbColor, HtmlColorCode);
+color_from_to_builder!(CmykToHtmlConverter, CmykColor, HtmlColorCode);
 
 #[cfg(test)]
 mod tests {
@@ -130,6 +319,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn hwb_build_new_test() {
+        let converter = ColorConverterBuilder::new().from_hwb().to_rgb().build();
+        assert_eq!(
+            converter.convert(&HwbColor::new(30, 20, 40)).unwrap(),
+            RgbColor::new(153, 102, 51)
+        );
+
+        let converter = ColorConverterBuilder::new().from_html().to_hwb().build();
+        assert_eq!(
+            converter.convert(&HtmlColorCode::new("#996633")).unwrap(),
+            HwbColor::new(30, 20, 40)
+        );
+    }
+
+    #[test]
+    fn cmyk_build_new_test() {
+        let converter = ColorConverterBuilder::new().from_rgb().to_cmyk().build();
+        assert_eq!(
+            converter.convert(&RgbColor::new(255, 0, 0)).unwrap(),
+            CmykColor::new(0, 100, 100, 0)
+        );
+
+        let converter = ColorConverterBuilder::new().from_cmyk().to_html().build();
+        assert_eq!(
+            converter.convert(&CmykColor::new(0, 100, 100, 0)).unwrap(),
+            HtmlColorCode::new("#ff0000")
+        );
+    }
+
+    #[test]
+    fn ycbcr_build_new_test() {
+        let format = YCbCrFormat::default().with_bit_depth(10);
+        let converter = ColorConverterBuilder::new()
+            .from_rgb()
+            .to_ycbcr(format)
+            .build();
+        let ycbcr = converter.convert(&RgbColor::new(0, 0, 0)).unwrap();
+        assert_eq!(ycbcr, YCbCrColor::new(64, 512, 512));
+
+        let converter = ColorConverterBuilder::new()
+            .from_ycbcr(format)
+            .to_rgb()
+            .build();
+        assert_eq!(converter.convert(&ycbcr).unwrap(), RgbColor::new(0, 0, 0));
+    }
+
+    #[test]
+    fn rgb_space_build_new_test() {
+        let converter = ColorConverterBuilder::new()
+            .from_rgb()
+            .to_rgb_space(RgbSpace::display_p3())
+            .build();
+        let p3 = converter.convert(&RgbColor::new(255, 255, 255)).unwrap();
+        assert!((p3.r() - 1_f64).abs() < 1e-9);
+        assert!((p3.g() - 1_f64).abs() < 1e-9);
+        assert!((p3.b() - 1_f64).abs() < 1e-9);
+
+        let converter = ColorConverterBuilder::new()
+            .from_rgb_space(RgbSpace::display_p3())
+            .to_rgb()
+            .build();
+        assert_eq!(
+            converter.convert(&p3).unwrap(),
+            RgbColor::new(255, 255, 255)
+        );
+    }
+
+    #[test]
+    fn rgb_space_build_new_test2() {
+        let converter = ColorConverterBuilder::new()
+            .from_rgb_space(RgbSpace::adobe_rgb())
+            .to_rgb_space(RgbSpace::prophoto_rgb())
+            .build();
+        let prophoto = converter
+            .convert(&FloatRgbColor::new(0.5, 0.5, 0.5))
+            .unwrap();
+        assert!((prophoto.r() - prophoto.g()).abs() < 1e-6);
+        assert!((prophoto.g() - prophoto.b()).abs() < 1e-6);
+
+        let converter = ColorConverterBuilder::new()
+            .from_rgb_space(RgbSpace::prophoto_rgb())
+            .to_xyz()
+            .build();
+        let xyz = converter
+            .convert(&FloatRgbColor::new(1_f64, 1_f64, 1_f64))
+            .unwrap();
+        assert!((xyz.y() - 1_f64).abs() < 1e-9);
+    }
+
     #[test]
     fn color_build_new_test3() {
         let converter = ColorConverterBuilder::new().from_html().to_rgb().build();
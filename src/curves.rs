@@ -0,0 +1,350 @@
+use crate::converter::{slice_conversion, Conversion};
+use crate::errors;
+use crate::errors::Result;
+use crate::float_rgb::FloatRgbColor;
+use crate::lut;
+use crate::rgb::RgbColor;
+
+// tone curve through control points (natural cubic spline, like Photoshop Curves)
+// input before the first point and after the last point is flat, output is clamped to [0, 1]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Curve {
+    points: Vec<(f64, f64)>,
+    // second derivatives at the points
+    derivatives: Vec<f64>,
+}
+
+impl Default for Curve {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl Curve {
+    // points are (input, output) in [0, 1], the order does not matter
+    pub fn new(points: &[(f64, f64)]) -> Result<Self> {
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let valid = |v: f64| (0_f64..=1_f64).contains(&v);
+        if points.len() < 2
+            || points.iter().any(|&(x, y)| !valid(x) || !valid(y))
+            || points.windows(2).any(|pair| pair[0].0 == pair[1].0)
+        {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("{:?}", points),
+            ));
+        }
+        let derivatives = spline_derivatives(&points);
+        Ok(Self {
+            points,
+            derivatives,
+        })
+    }
+
+    pub fn identity() -> Self {
+        Self {
+            points: vec![(0_f64, 0_f64), (1_f64, 1_f64)],
+            derivatives: vec![0_f64; 2],
+        }
+    }
+
+    pub fn points(&self) -> &[(f64, f64)] {
+        &self.points
+    }
+
+    pub fn evaluate(&self, value: f64) -> f64 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if value.is_nan() || value <= first.0 {
+            return first.1;
+        }
+        if value >= last.0 {
+            return last.1;
+        }
+        let i = self.points.partition_point(|&(x, _)| x <= value) - 1;
+        let ((x0, y0), (x1, y1)) = (self.points[i], self.points[i + 1]);
+        let h = x1 - x0;
+        let a = (x1 - value) / h;
+        let b = (value - x0) / h;
+        let y = a * y0
+            + b * y1
+            + ((a.powi(3) - a) * self.derivatives[i] + (b.powi(3) - b) * self.derivatives[i + 1])
+                * h
+                * h
+                / 6_f64;
+        y.clamp(0_f64, 1_f64)
+    }
+}
+
+// the second derivatives of a natural spline (0 at both ends), tridiagonal system
+fn spline_derivatives(points: &[(f64, f64)]) -> Vec<f64> {
+    let n = points.len();
+    let mut derivatives = vec![0_f64; n];
+    let mut u = vec![0_f64; n];
+    for i in 1..n - 1 {
+        let (x_prev, y_prev) = points[i - 1];
+        let (x, y) = points[i];
+        let (x_next, y_next) = points[i + 1];
+        let sig = (x - x_prev) / (x_next - x_prev);
+        let p = sig * derivatives[i - 1] + 2_f64;
+        derivatives[i] = (sig - 1_f64) / p;
+        let slope = (y_next - y) / (x_next - x) - (y - y_prev) / (x - x_prev);
+        u[i] = (6_f64 * slope / (x_next - x_prev) - sig * u[i - 1]) / p;
+    }
+    derivatives[n - 1] = 0_f64;
+    for i in (0..n - 1).rev() {
+        derivatives[i] = derivatives[i] * derivatives[i + 1] + u[i];
+    }
+    derivatives
+}
+
+// the channel curves are applied first, then the composite (rgb) curve
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Curves {
+    rgb: Curve,
+    red: Curve,
+    green: Curve,
+    blue: Curve,
+}
+
+impl Curves {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rgb(self, rgb: Curve) -> Self {
+        Self { rgb, ..self }
+    }
+
+    pub fn with_red(self, red: Curve) -> Self {
+        Self { red, ..self }
+    }
+
+    pub fn with_green(self, green: Curve) -> Self {
+        Self { green, ..self }
+    }
+
+    pub fn with_blue(self, blue: Curve) -> Self {
+        Self { blue, ..self }
+    }
+
+    pub fn rgb(&self) -> &Curve {
+        &self.rgb
+    }
+
+    pub fn red(&self) -> &Curve {
+        &self.red
+    }
+
+    pub fn green(&self) -> &Curve {
+        &self.green
+    }
+
+    pub fn blue(&self) -> &Curve {
+        &self.blue
+    }
+
+    pub fn apply(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        FloatRgbColor::from_rgba(
+            self.rgb.evaluate(self.red.evaluate(color.r())),
+            self.rgb.evaluate(self.green.evaluate(color.g())),
+            self.rgb.evaluate(self.blue.evaluate(color.b())),
+            color.a(),
+        )
+    }
+}
+
+slice_conversion!(Curves, RgbColor, RgbColor);
+slice_conversion!(Curves, convert_float_slice, FloatRgbColor, FloatRgbColor);
+
+impl Conversion<&FloatRgbColor, FloatRgbColor> for Curves {
+    fn convert(&self, color: &FloatRgbColor) -> Result<FloatRgbColor> {
+        Ok(self.apply(color))
+    }
+}
+
+impl Conversion<&RgbColor, RgbColor> for Curves {
+    fn convert(&self, color: &RgbColor) -> Result<RgbColor> {
+        Ok(lut::convert_rgb(color, |color| self.apply(color)))
+    }
+}
+
+// Photoshop Levels, input / output black and white points in [0, 1]
+// gamma above 1 brightens the midtones
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Levels {
+    input_black: f64,
+    input_white: f64,
+    gamma: f64,
+    output_black: f64,
+    output_white: f64,
+}
+
+impl Default for Levels {
+    fn default() -> Self {
+        Self {
+            input_black: 0_f64,
+            input_white: 1_f64,
+            gamma: 1_f64,
+            output_black: 0_f64,
+            output_white: 1_f64,
+        }
+    }
+}
+
+impl Levels {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_input(self, black: f64, white: f64) -> Self {
+        Self {
+            input_black: black,
+            input_white: white,
+            ..self
+        }
+    }
+
+    pub fn with_gamma(self, gamma: f64) -> Self {
+        Self { gamma, ..self }
+    }
+
+    pub fn with_output(self, black: f64, white: f64) -> Self {
+        Self {
+            output_black: black,
+            output_white: white,
+            ..self
+        }
+    }
+
+    pub fn input_black(&self) -> f64 {
+        self.input_black
+    }
+
+    pub fn input_white(&self) -> f64 {
+        self.input_white
+    }
+
+    pub fn gamma(&self) -> f64 {
+        self.gamma
+    }
+
+    pub fn output_black(&self) -> f64 {
+        self.output_black
+    }
+
+    pub fn output_white(&self) -> f64 {
+        self.output_white
+    }
+
+    pub fn evaluate(&self, value: f64) -> f64 {
+        let span = (self.input_white - self.input_black).max(f64::EPSILON);
+        let t = ((value - self.input_black) / span).clamp(0_f64, 1_f64);
+        let t = t.powf(1_f64 / self.gamma.max(f64::EPSILON));
+        self.output_black + (self.output_white - self.output_black) * t
+    }
+
+    pub fn apply(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        FloatRgbColor::from_rgba(
+            self.evaluate(color.r()),
+            self.evaluate(color.g()),
+            self.evaluate(color.b()),
+            color.a(),
+        )
+    }
+}
+
+slice_conversion!(Levels, RgbColor, RgbColor);
+slice_conversion!(Levels, convert_float_slice, FloatRgbColor, FloatRgbColor);
+
+impl Conversion<&FloatRgbColor, FloatRgbColor> for Levels {
+    fn convert(&self, color: &FloatRgbColor) -> Result<FloatRgbColor> {
+        Ok(self.apply(color))
+    }
+}
+
+impl Conversion<&RgbColor, RgbColor> for Levels {
+    fn convert(&self, color: &RgbColor) -> Result<RgbColor> {
+        Ok(lut::convert_rgb(color, |color| self.apply(color)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curve_test() {
+        let identity = Curve::identity();
+        for i in 0..=10 {
+            let value = i as f64 / 10_f64;
+            assert!((identity.evaluate(value) - value).abs() < 1e-12);
+        }
+
+        // passes through the control points
+        let points = [(0_f64, 0_f64), (0.25, 0.15), (0.75, 0.85), (1_f64, 1_f64)];
+        let s_curve = Curve::new(&points).unwrap();
+        for (x, y) in points {
+            assert!((s_curve.evaluate(x) - y).abs() < 1e-12);
+        }
+        assert!((s_curve.evaluate(0.5) - 0.5).abs() < 1e-12);
+        let mut previous = 0_f64;
+        for i in 0..=100 {
+            let value = s_curve.evaluate(i as f64 / 100_f64);
+            assert!(value >= previous);
+            previous = value;
+        }
+
+        // flat outside the end points
+        let curve = Curve::new(&[(0.8, 0.9), (0.2, 0.1)]).unwrap();
+        assert_eq!(curve.points()[0], (0.2, 0.1));
+        assert_eq!(curve.evaluate(0_f64), 0.1);
+        assert_eq!(curve.evaluate(1_f64), 0.9);
+
+        assert!(Curve::new(&[(0.5, 0.5)]).is_err());
+        assert!(Curve::new(&[(0.5, 0.5), (0.5, 0.7)]).is_err());
+        assert!(Curve::new(&[(0_f64, 0_f64), (1.5, 1_f64)]).is_err());
+    }
+
+    #[test]
+    fn curves_test() {
+        let invert = Curve::new(&[(0_f64, 1_f64), (1_f64, 0_f64)]).unwrap();
+        let curves = Curves::new().with_red(invert.clone());
+        let actual = curves
+            .convert(&RgbColor::from_rgba(255, 100, 0, 10))
+            .unwrap();
+        assert_eq!(actual, RgbColor::from_rgba(0, 100, 0, 10));
+
+        // the composite curve is applied after the channel curve
+        let half = Curve::new(&[(0_f64, 0_f64), (1_f64, 0.5)]).unwrap();
+        let curves = Curves::new().with_red(invert).with_rgb(half);
+        let actual = curves.apply(&FloatRgbColor::new(0_f64, 1_f64, 0.5));
+        assert_eq!(actual, FloatRgbColor::new(0.5, 0.5, 0.25));
+    }
+
+    #[test]
+    fn levels_test() {
+        let levels = Levels::new();
+        assert_eq!(levels.evaluate(0.3), 0.3);
+
+        let levels = Levels::new().with_input(0.1, 0.9);
+        assert_eq!(levels.evaluate(0.05), 0_f64);
+        assert!((levels.evaluate(0.5) - 0.5).abs() < 1e-12);
+        assert_eq!(levels.evaluate(0.95), 1_f64);
+
+        let levels = Levels::new().with_gamma(2_f64);
+        assert!((levels.evaluate(0.25) - 0.5).abs() < 1e-12);
+
+        let levels = Levels::new().with_output(0.2, 0.8);
+        assert!((levels.evaluate(0_f64) - 0.2).abs() < 1e-12);
+        assert!((levels.evaluate(1_f64) - 0.8).abs() < 1e-12);
+
+        // 8 bit: input 16 - 235 is expanded to full range
+        let levels = Levels::new().with_input(16_f64 / 255_f64, 235_f64 / 255_f64);
+        let colors = [RgbColor::new(16, 235, 126), RgbColor::new(0, 255, 10)];
+        let mut output = [RgbColor::default(); 2];
+        levels.convert_slice(&colors, &mut output).unwrap();
+        assert_eq!(output[0], RgbColor::new(0, 255, 128));
+        assert_eq!(output[1], RgbColor::new(0, 255, 0));
+    }
+}
@@ -46,7 +46,7 @@ impl Error {
     pub(crate) fn new(code: ErrorCode, target: impl Into<String>) -> Self {
         Self {
             inner_error: Box::new(InnerError {
-                code: code,
+                code,
                 target: target.into(),
             }),
         }
@@ -61,7 +61,7 @@ impl error::Error for Error {
 
 impl fmt::Debug for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&*self, f)
+        fmt::Display::fmt(self, f)
     }
 }
 
@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct FloatRgbColor {
+    r: f64,
+    g: f64,
+    b: f64,
+    a: f64,
+}
+
+impl Display for FloatRgbColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "FloatRgbColor R = {} G = {} B = {} A = {}",
+            self.r, self.g, self.b, self.a
+        )
+    }
+}
+
+impl FloatRgbColor {
+    pub fn new(r: f64, g: f64, b: f64) -> Self {
+        Self::from_rgba(r, g, b, 1f64)
+    }
+
+    pub fn from_rgba(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self { r, g, b, a }
+    }
+
+    pub fn r(&self) -> f64 {
+        self.r
+    }
+
+    pub fn g(&self) -> f64 {
+        self.g
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    pub(crate) fn to_array(self) -> [f64; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    pub(crate) fn from_array(rgb: [f64; 3], a: f64) -> Self {
+        Self::from_rgba(rgb[0], rgb[1], rgb[2], a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn float_rgb_new_test() {
+        let red = FloatRgbColor::new(1.25, -0.5, 0f64);
+
+        assert_eq!(red.r(), 1.25);
+        assert_eq!(red.g(), -0.5);
+        assert_eq!(red.b(), 0f64);
+        assert_eq!(red.a(), 1f64);
+    }
+}
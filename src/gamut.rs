@@ -0,0 +1,257 @@
+use crate::adaptation::ChromaticAdaptation;
+use crate::float_rgb::FloatRgbColor;
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::oklab::{OklabColor, OklchColor};
+use crate::rgb_space::RgbSpace;
+use crate::white_point::WhitePoint;
+use crate::xyz::XyzColor;
+
+const GAMUT_EPSILON: f64 = 1e-6;
+
+// https://www.w3.org/TR/css-color-4/#gamut-mapping
+const CSS4_JND: f64 = 0.02;
+const CSS4_EPSILON: f64 = 0.0001;
+
+// chroma below KNEE * (gamut boundary) is left untouched by Perceptual
+const PERCEPTUAL_KNEE: f64 = 0.8;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum GamutMapping {
+    // per channel clamp to [0, 1]
+    #[default]
+    Clip,
+    // CSS Color 4: Oklch chroma reduction with ΔEOK JND
+    Css4,
+    // smooth Oklch chroma compression toward the gamut boundary
+    Perceptual,
+}
+
+// color: encoded values of the RGB space
+pub fn is_in_gamut(color: &FloatRgbColor) -> bool {
+    color
+        .to_array()
+        .iter()
+        .all(|&v| (-GAMUT_EPSILON..=1_f64 + GAMUT_EPSILON).contains(&v))
+}
+
+impl GamutMapping {
+    // color: encoded values of the RGB space, returns encoded values in [0, 1]
+    pub fn map(&self, color: &FloatRgbColor, space: &RgbSpace) -> FloatRgbColor {
+        match self {
+            Self::Clip => clip(color),
+            Self::Css4 => {
+                if is_in_gamut(color) {
+                    clip(color)
+                } else {
+                    OklchGamut::new(space).css4(color)
+                }
+            }
+            Self::Perceptual => OklchGamut::new(space).compress(color),
+        }
+    }
+}
+
+fn clip(color: &FloatRgbColor) -> FloatRgbColor {
+    FloatRgbColor::from_rgba(
+        color.r().clamp(0_f64, 1_f64),
+        color.g().clamp(0_f64, 1_f64),
+        color.b().clamp(0_f64, 1_f64),
+        color.a(),
+    )
+}
+
+struct OklchGamut<'a> {
+    space: &'a RgbSpace,
+    to_xyz: Matrix3,
+    from_xyz: Matrix3,
+}
+
+impl<'a> OklchGamut<'a> {
+    fn new(space: &'a RgbSpace) -> Self {
+        // Oklab is defined relative to D65
+        // the white point is validated in RgbSpace::new
+        let adaptation = ChromaticAdaptation::new(space.white_point(), WhitePoint::D65).unwrap();
+        let to_xyz = matrix::multiply(&adaptation.matrix(), &space.to_xyz_matrix());
+        let from_xyz = matrix::inverse(&to_xyz).unwrap_or(space.from_xyz_matrix());
+        Self {
+            space,
+            to_xyz,
+            from_xyz,
+        }
+    }
+
+    fn oklch(&self, color: &FloatRgbColor) -> OklchColor {
+        let linear = self.space.decode(color);
+        let xyz = XyzColor::from_array(
+            matrix::multiply_vector(&self.to_xyz, linear.to_array()),
+            color.a(),
+        );
+        OklchColor::from_oklab(&OklabColor::from_xyz(&xyz))
+    }
+
+    fn encoded(&self, color: &OklchColor) -> FloatRgbColor {
+        let xyz = color.to_oklab().to_xyz();
+        let linear = FloatRgbColor::from_array(
+            matrix::multiply_vector(&self.from_xyz, xyz.to_array()),
+            xyz.a(),
+        );
+        self.space.encode(&linear)
+    }
+
+    fn with_chroma(color: &OklchColor, chroma: f64) -> OklchColor {
+        OklchColor::from_lcha(color.l(), chroma, color.h(), color.a())
+    }
+
+    fn white_or_black(&self, origin: &OklchColor, color: &FloatRgbColor) -> Option<FloatRgbColor> {
+        if origin.l() >= 1_f64 {
+            Some(FloatRgbColor::from_rgba(1_f64, 1_f64, 1_f64, color.a()))
+        } else if origin.l() <= 0_f64 {
+            Some(FloatRgbColor::from_rgba(0_f64, 0_f64, 0_f64, color.a()))
+        } else {
+            None
+        }
+    }
+
+    fn css4(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        let origin = self.oklch(color);
+        if let Some(result) = self.white_or_black(&origin, color) {
+            return result;
+        }
+
+        let delta = |current: &OklchColor, clipped: &FloatRgbColor| {
+            current.to_oklab().distance(&self.oklch(clipped).to_oklab())
+        };
+
+        let mut clipped = clip(color);
+        if delta(&origin, &clipped) < CSS4_JND {
+            return clipped;
+        }
+
+        let mut min = 0_f64;
+        let mut max = origin.c();
+        let mut min_in_gamut = true;
+        while max - min > CSS4_EPSILON {
+            let chroma = (min + max) / 2_f64;
+            let current = Self::with_chroma(&origin, chroma);
+            let candidate = self.encoded(&current);
+
+            if min_in_gamut && is_in_gamut(&candidate) {
+                min = chroma;
+                continue;
+            }
+
+            clipped = clip(&candidate);
+            let e = delta(&current, &clipped);
+            if e < CSS4_JND {
+                if CSS4_JND - e < CSS4_EPSILON {
+                    break;
+                }
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        clipped
+    }
+
+    // largest in gamut chroma for the lightness and hue of color
+    fn max_chroma(&self, color: &OklchColor) -> f64 {
+        let mut min = 0_f64;
+        let mut max = 1_f64;
+        while max - min > CSS4_EPSILON {
+            let chroma = (min + max) / 2_f64;
+            if is_in_gamut(&self.encoded(&Self::with_chroma(color, chroma))) {
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+        min
+    }
+
+    fn compress(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        let origin = self.oklch(color);
+        if let Some(result) = self.white_or_black(&origin, color) {
+            return result;
+        }
+
+        let boundary = self.max_chroma(&origin);
+        let knee = boundary * PERCEPTUAL_KNEE;
+        if origin.c() <= knee {
+            return clip(color);
+        }
+
+        let range = boundary - knee;
+        let chroma = knee + range * ((origin.c() - knee) / range).tanh();
+        clip(&self.encoded(&Self::with_chroma(&origin, chroma)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p3_red_in_srgb() -> FloatRgbColor {
+        let linear = matrix::multiply_vector(
+            &RgbSpace::display_p3().conversion_matrix(&RgbSpace::srgb()),
+            [1_f64, 0_f64, 0_f64],
+        );
+        RgbSpace::srgb().encode(&FloatRgbColor::from_array(linear, 1_f64))
+    }
+
+    #[test]
+    fn is_in_gamut_test() {
+        assert!(is_in_gamut(&FloatRgbColor::new(0_f64, 0.5, 1_f64)));
+        assert!(!is_in_gamut(&FloatRgbColor::new(1.01, 0.5, 0.5)));
+        assert!(!is_in_gamut(&p3_red_in_srgb()));
+    }
+
+    #[test]
+    fn gamut_mapping_in_gamut_test() {
+        let color = FloatRgbColor::new(0.2, 0.4, 0.6);
+        let space = RgbSpace::srgb();
+        assert_eq!(GamutMapping::Clip.map(&color, &space), color);
+        assert_eq!(GamutMapping::Css4.map(&color, &space), color);
+        assert_eq!(GamutMapping::Perceptual.map(&color, &space), color);
+    }
+
+    #[test]
+    fn gamut_mapping_css4_test() {
+        let space = RgbSpace::srgb();
+        let color = p3_red_in_srgb();
+        let mapped = GamutMapping::Css4.map(&color, &space);
+        assert!(is_in_gamut(&mapped));
+
+        let gamut = OklchGamut::new(&space);
+        let origin = gamut.oklch(&color);
+        let result = gamut.oklch(&mapped);
+        assert!(result.c() < origin.c());
+        assert!((result.l() - origin.l()).abs() < 0.02);
+        assert!((result.h() - origin.h()).abs() < 2_f64);
+    }
+
+    #[test]
+    fn gamut_mapping_perceptual_test() {
+        let space = RgbSpace::srgb();
+        let mapped = GamutMapping::Perceptual.map(&p3_red_in_srgb(), &space);
+        assert!(is_in_gamut(&mapped));
+
+        // saturated in gamut colors are compressed toward the boundary as well
+        let red = FloatRgbColor::new(1_f64, 0_f64, 0_f64);
+        let gamut = OklchGamut::new(&space);
+        let compressed = GamutMapping::Perceptual.map(&red, &space);
+        assert!(gamut.oklch(&compressed).c() < gamut.oklch(&red).c());
+    }
+
+    #[test]
+    fn gamut_mapping_lightness_test() {
+        let space = RgbSpace::srgb();
+        let bright = FloatRgbColor::new(1.5, 1.4, 1.3);
+        assert_eq!(
+            GamutMapping::Css4.map(&bright, &space),
+            FloatRgbColor::new(1_f64, 1_f64, 1_f64)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use std::fmt::Display;
+
+use crate::luv::LchuvColor;
+use crate::rgb_space;
+
+// https://www.hsluv.org/math/
+
+const EPSILON: f64 = 216_f64 / 24389_f64;
+const KAPPA: f64 = 24389_f64 / 27_f64;
+
+const MAX_LIGHTNESS: f64 = 99.9999999;
+const MIN_LIGHTNESS: f64 = 1e-8;
+
+// y = slope * x + intercept
+struct Line {
+    slope: f64,
+    intercept: f64,
+}
+
+impl Line {
+    fn distance_from_origin(&self) -> f64 {
+        self.intercept.abs() / (self.slope.powi(2) + 1_f64).sqrt()
+    }
+
+    fn ray_length(&self, theta: f64) -> Option<f64> {
+        let length = self.intercept / (theta.sin() - self.slope * theta.cos());
+        if length >= 0_f64 {
+            Some(length)
+        } else {
+            None
+        }
+    }
+}
+
+fn bounds(l: f64) -> Vec<Line> {
+    let sub1 = (l + 16_f64).powi(3) / 1560896_f64;
+    let sub2 = if sub1 > EPSILON { sub1 } else { l / KAPPA };
+
+    let mut lines = Vec::with_capacity(6);
+    // the gamut boundary uses the same XYZ to linear sRGB matrix as XyzToRgbConverter
+    for [m1, m2, m3] in rgb_space::srgb().from_xyz_matrix() {
+        for t in [0_f64, 1_f64] {
+            let top1 = (284517_f64 * m1 - 94839_f64 * m3) * sub2;
+            let top2 = (838422_f64 * m3 + 769860_f64 * m2 + 731718_f64 * m1) * l * sub2
+                - 769860_f64 * t * l;
+            let bottom = (632260_f64 * m3 - 126452_f64 * m2) * sub2 + 126452_f64 * t;
+            lines.push(Line {
+                slope: top1 / bottom,
+                intercept: top2 / bottom,
+            });
+        }
+    }
+    lines
+}
+
+fn max_chroma_for_lh(l: f64, h: f64) -> f64 {
+    let theta = h.to_radians();
+    bounds(l)
+        .iter()
+        .filter_map(|line| line.ray_length(theta))
+        .fold(f64::MAX, f64::min)
+}
+
+fn max_safe_chroma_for_l(l: f64) -> f64 {
+    bounds(l)
+        .iter()
+        .map(|line| line.distance_from_origin())
+        .fold(f64::MAX, f64::min)
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HsluvColor {
+    h: f64,
+    s: f64,
+    l: f64,
+    a: f64,
+}
+
+impl Display for HsluvColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HsluvColor H = {} S = {} L = {} A = {}",
+            self.h, self.s, self.l, self.a
+        )
+    }
+}
+
+impl HsluvColor {
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        Self::from_hsla(h, s, l, 1f64)
+    }
+
+    pub fn from_hsla(h: f64, s: f64, l: f64, a: f64) -> Self {
+        Self {
+            h: h.rem_euclid(360f64),
+            s: s.clamp(0f64, 100f64),
+            l: l.clamp(0f64, 100f64),
+            a,
+        }
+    }
+
+    pub fn from_lchuv(color: &LchuvColor) -> Self {
+        let s = if color.l() > MAX_LIGHTNESS || color.l() < MIN_LIGHTNESS {
+            0_f64
+        } else {
+            color.c() / max_chroma_for_lh(color.l(), color.h()) * 100_f64
+        };
+        // colors on the gamut boundary may slightly exceed 100, keep them to round trip
+        Self {
+            h: color.h(),
+            s: s.max(0_f64),
+            l: color.l().clamp(0f64, 100f64),
+            a: color.a(),
+        }
+    }
+
+    pub fn to_lchuv(&self) -> LchuvColor {
+        let c = if self.l > MAX_LIGHTNESS || self.l < MIN_LIGHTNESS {
+            0_f64
+        } else {
+            max_chroma_for_lh(self.l, self.h) / 100_f64 * self.s
+        };
+        LchuvColor::from_lcha(self.l, c, self.h, self.a)
+    }
+
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    pub fn s(&self) -> f64 {
+        self.s
+    }
+
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+// HPLuv trades the full saturation range of HSLuv for uniform chroma, so it only covers pastel colors.
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct HpluvColor {
+    h: f64,
+    p: f64,
+    l: f64,
+    a: f64,
+}
+
+impl Display for HpluvColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HpluvColor H = {} P = {} L = {} A = {}",
+            self.h, self.p, self.l, self.a
+        )
+    }
+}
+
+impl HpluvColor {
+    pub fn new(h: f64, p: f64, l: f64) -> Self {
+        Self::from_hpla(h, p, l, 1f64)
+    }
+
+    pub fn from_hpla(h: f64, p: f64, l: f64, a: f64) -> Self {
+        Self {
+            h: h.rem_euclid(360f64),
+            p: p.max(0f64),
+            l: l.clamp(0f64, 100f64),
+            a,
+        }
+    }
+
+    pub fn from_lchuv(color: &LchuvColor) -> Self {
+        let p = if color.l() > MAX_LIGHTNESS || color.l() < MIN_LIGHTNESS {
+            0_f64
+        } else {
+            color.c() / max_safe_chroma_for_l(color.l()) * 100_f64
+        };
+        Self::from_hpla(color.h(), p, color.l(), color.a())
+    }
+
+    pub fn to_lchuv(&self) -> LchuvColor {
+        let c = if self.l > MAX_LIGHTNESS || self.l < MIN_LIGHTNESS {
+            0_f64
+        } else {
+            max_safe_chroma_for_l(self.l) / 100_f64 * self.p
+        };
+        LchuvColor::from_lcha(self.l, c, self.h, self.a)
+    }
+
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    // saturation relative to the most saturated pastel color of the lightness, may exceed 100
+    pub fn p(&self) -> f64 {
+        self.p
+    }
+
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn hsluv_from_lchuv_test() {
+        // sRGB red (https://www.hsluv.org/)
+        let lch = LchuvColor::new(53.237115595429344, 179.04142708939614, 12.177050630061776);
+        let hsluv = HsluvColor::from_lchuv(&lch);
+        assert_near(hsluv.h(), 12.177050630061776);
+        assert!((hsluv.s() - 100_f64).abs() < 1e-2);
+        assert_near(hsluv.l(), 53.237115595429344);
+
+        let lch = LchuvColor::new(60_f64, 30_f64, 200_f64);
+        let actual = HsluvColor::from_lchuv(&lch).to_lchuv();
+        assert_near(actual.l(), lch.l());
+        assert_near(actual.c(), lch.c());
+        assert_near(actual.h(), lch.h());
+    }
+
+    #[test]
+    fn hpluv_from_lchuv_test() {
+        // sRGB red (https://www.hsluv.org/)
+        let lch = LchuvColor::new(53.237115595429344, 179.04142708939614, 12.177050630061776);
+        let hpluv = HpluvColor::from_lchuv(&lch);
+        assert_near(hpluv.h(), 12.177050630061776);
+        assert!((hpluv.p() - 426.7467962889138).abs() < 1e-1);
+        assert_near(hpluv.l(), 53.237115595429344);
+
+        let actual = hpluv.to_lchuv();
+        assert_near(actual.c(), lch.c());
+    }
+
+    #[test]
+    fn hsluv_extreme_lightness_test() {
+        let white = HsluvColor::from_lchuv(&LchuvColor::new(100_f64, 1_f64, 40_f64));
+        assert_eq!(white.s(), 0_f64);
+        assert_eq!(HsluvColor::new(0_f64, 100_f64, 0_f64).to_lchuv().c(), 0_f64);
+        assert_eq!(HsluvColor::new(-30_f64, 120_f64, 50_f64).h(), 330_f64);
+        assert_eq!(HsluvColor::new(-30_f64, 120_f64, 50_f64).s(), 100_f64);
+    }
+}
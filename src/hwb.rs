@@ -0,0 +1,77 @@
+use std::fmt::Display;
+
+use crate::utils;
+
+// https://www.w3.org/TR/css-color-4/#the-hwb-notation
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct HwbColor {
+    h: u16,
+    w: u8,
+    b: u8,
+    a: u8,
+}
+
+impl Display for HwbColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "HwbColor H = {} W = {} B = {} A = {}",
+            self.h, self.w, self.b, self.a
+        )
+    }
+}
+
+impl HwbColor {
+    pub fn new(h: u16, w: u8, b: u8) -> Self {
+        Self::from_hwba(h, w, b, 255)
+    }
+
+    pub fn from_hwba(h: u16, w: u8, b: u8, a: u8) -> Self {
+        Self {
+            h: utils::crop_range(h, 0, 360),
+            w: utils::crop_range(w, 0, 100),
+            b: utils::crop_range(b, 0, 100),
+            a,
+        }
+    }
+
+    pub fn h(&self) -> u16 {
+        self.h
+    }
+
+    pub fn w(&self) -> u8 {
+        self.w
+    }
+
+    pub fn b(&self) -> u8 {
+        self.b
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hwb_new_test() {
+        let red = HwbColor::new(0, 0, 0);
+
+        assert_eq!(red.h(), 0);
+        assert_eq!(red.w(), 0);
+        assert_eq!(red.b(), 0);
+        assert_eq!(red.a(), 255);
+    }
+
+    #[test]
+    fn hwb_crop_range_test() {
+        let color = HwbColor::new(400, 120, 101);
+
+        assert_eq!(color.h(), 360);
+        assert_eq!(color.w(), 100);
+        assert_eq!(color.b(), 100);
+    }
+}
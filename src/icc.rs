@@ -0,0 +1,2370 @@
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::adaptation::ChromaticAdaptation;
+use crate::chromaticity::Chromaticity;
+use crate::cmyk::CmykColor;
+use crate::converter::Conversion;
+use crate::errors;
+use crate::errors::Result;
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::rgb::RgbColor;
+use crate::rgb_space::RgbSpace;
+use crate::transfer;
+use crate::transfer::{ParametricCurve, SampledCurve, TransferFunction};
+use crate::utils;
+use crate::white_point::WhitePoint;
+use crate::xyz::XyzColor;
+
+// ICC.1:2010 (v4) and ICC.1:2001-04 (v2)
+const HEADER_SIZE: usize = 128;
+const TAG_ENTRY_SIZE: usize = 12;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IccProfileClass {
+    Input,
+    Display,
+    Output,
+    DeviceLink,
+    ColorSpace,
+    Abstract,
+    NamedColor,
+}
+
+impl IccProfileClass {
+    fn from_signature(signature: [u8; 4]) -> Option<Self> {
+        match &signature {
+            b"scnr" => Some(Self::Input),
+            b"mntr" => Some(Self::Display),
+            b"prtr" => Some(Self::Output),
+            b"link" => Some(Self::DeviceLink),
+            b"spac" => Some(Self::ColorSpace),
+            b"abst" => Some(Self::Abstract),
+            b"nmcl" => Some(Self::NamedColor),
+            _ => None,
+        }
+    }
+}
+
+// data color spaces other than these are kept as the signature
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum IccColorSpace {
+    Xyz,
+    Lab,
+    Rgb,
+    Gray,
+    Cmyk,
+    Other([u8; 4]),
+}
+
+impl IccColorSpace {
+    fn from_signature(signature: [u8; 4]) -> Self {
+        match &signature {
+            b"XYZ " => Self::Xyz,
+            b"Lab " => Self::Lab,
+            b"RGB " => Self::Rgb,
+            b"GRAY" => Self::Gray,
+            b"CMYK" => Self::Cmyk,
+            _ => Self::Other(signature),
+        }
+    }
+
+    // number of device channels ('2CLR' - 'FCLR' for the others), 0 if unknown
+    pub fn channels(&self) -> usize {
+        match self {
+            Self::Gray => 1,
+            Self::Xyz | Self::Lab | Self::Rgb => 3,
+            Self::Cmyk => 4,
+            Self::Other([count, b'C', b'L', b'R']) => {
+                (*count as char).to_digit(16).unwrap_or(0) as usize
+            }
+            Self::Other(_) => 0,
+        }
+    }
+
+    // the darkest device values
+    fn black(&self) -> Vec<f64> {
+        match self {
+            Self::Cmyk => vec![1_f64; 4],
+            color_space => vec![0_f64; color_space.channels()],
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum RenderingIntent {
+    #[default]
+    Perceptual,
+    RelativeColorimetric,
+    Saturation,
+    AbsoluteColorimetric,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct TagEntry {
+    signature: [u8; 4],
+    offset: usize,
+    size: usize,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct IccProfile {
+    data: Vec<u8>,
+    version: (u8, u8),
+    class: IccProfileClass,
+    color_space: IccColorSpace,
+    pcs: IccColorSpace,
+    rendering_intent: RenderingIntent,
+    illuminant: XyzColor,
+    tags: Vec<TagEntry>,
+}
+
+impl IccProfile {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        if data.len() < HEADER_SIZE + 4 || &data[36..40] != b"acsp" {
+            return Err(format_error("header"));
+        }
+        let size = read_u32(data, 0)? as usize;
+        if size > data.len() || size < HEADER_SIZE + 4 {
+            return Err(format_error(format!("profile size {}", size)));
+        }
+        let data = &data[..size];
+
+        let class = IccProfileClass::from_signature(read_signature(data, 12)?)
+            .ok_or_else(|| format_error("profile class"))?;
+        let rendering_intent = match read_u32(data, 64)? & 0xffff {
+            0 => RenderingIntent::Perceptual,
+            1 => RenderingIntent::RelativeColorimetric,
+            2 => RenderingIntent::Saturation,
+            3 => RenderingIntent::AbsoluteColorimetric,
+            intent => return Err(format_error(format!("rendering intent {}", intent))),
+        };
+
+        let count = read_u32(data, HEADER_SIZE)? as usize;
+        if count > (size - HEADER_SIZE - 4) / TAG_ENTRY_SIZE {
+            return Err(format_error(format!("tag count {}", count)));
+        }
+        let tags = (0..count)
+            .map(|i| {
+                let entry = HEADER_SIZE + 4 + i * TAG_ENTRY_SIZE;
+                let tag = TagEntry {
+                    signature: read_signature(data, entry)?,
+                    offset: read_u32(data, entry + 4)? as usize,
+                    size: read_u32(data, entry + 8)? as usize,
+                };
+                if tag.size < 8
+                    || tag
+                        .offset
+                        .checked_add(tag.size)
+                        .is_none_or(|end| end > size)
+                {
+                    return Err(format_error(format!(
+                        "tag {}",
+                        String::from_utf8_lossy(&tag.signature)
+                    )));
+                }
+                Ok(tag)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            data: data.to_vec(),
+            version: (data[8], data[9] >> 4),
+            class,
+            color_space: IccColorSpace::from_signature(read_signature(data, 16)?),
+            pcs: IccColorSpace::from_signature(read_signature(data, 20)?),
+            rendering_intent,
+            illuminant: read_xyz_number(data, 68)?,
+            tags,
+        })
+    }
+
+    pub fn read(path: impl AsRef<Path>) -> Result<Self> {
+        let data = fs::read(path.as_ref()).map_err(|error| {
+            errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("{} {}", path.as_ref().display(), error),
+            )
+        })?;
+        Self::parse(&data)
+    }
+
+    // (major, minor)
+    pub fn version(&self) -> (u8, u8) {
+        self.version
+    }
+
+    pub fn class(&self) -> IccProfileClass {
+        self.class
+    }
+
+    pub fn color_space(&self) -> IccColorSpace {
+        self.color_space
+    }
+
+    pub fn pcs(&self) -> IccColorSpace {
+        self.pcs
+    }
+
+    pub fn rendering_intent(&self) -> RenderingIntent {
+        self.rendering_intent
+    }
+
+    // PCS illuminant of the header (D50)
+    pub fn illuminant(&self) -> XyzColor {
+        self.illuminant
+    }
+
+    pub fn has_tag(&self, signature: &[u8; 4]) -> bool {
+        self.tags.iter().any(|tag| &tag.signature == signature)
+    }
+
+    // profileDescriptionTag (v2 textDescriptionType, v4 multiLocalizedUnicodeType)
+    pub fn description(&self) -> Result<String> {
+        read_text(self.tag(b"desc")?)
+    }
+
+    // mediaWhitePointTag, D50 in v4 profiles
+    pub fn media_white_point(&self) -> Result<XyzColor> {
+        read_xyz_type(self.tag(b"wtpt")?)
+    }
+
+    // chromaticAdaptationTag, actual illuminant => PCS illuminant (identity if missing)
+    pub fn chromatic_adaptation(&self) -> Result<[[f64; 3]; 3]> {
+        if !self.has_tag(b"chad") {
+            return Ok(matrix::diagonal([1_f64; 3]));
+        }
+        let data = self.tag(b"chad")?;
+        expect_type(data, b"sf32")?;
+        read_matrix(data, 8)
+    }
+
+    // matrix / TRC (RGB) and gray TRC profiles
+    // the colorants are adapted back from the PCS with chad, so the space keeps its own white point
+    // a gray profile becomes a space whose neutral axis (R = G = B) follows grayTRC
+    pub fn to_rgb_space(&self) -> Result<RgbSpace> {
+        if self.pcs != IccColorSpace::Xyz {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("PCS {:?}", self.pcs),
+            ));
+        }
+        let adaptation =
+            matrix::inverse(&self.chromatic_adaptation()?).ok_or_else(|| format_error("chad"))?;
+        match self.color_space {
+            IccColorSpace::Rgb => {
+                let colorants = [b"rXYZ", b"gXYZ", b"bXYZ"]
+                    .map(|signature| self.tag(signature).and_then(read_xyz_type));
+                let [red, green, blue] = colorants;
+                let [red, green, blue] = [red?, green?, blue?].map(|xyz| {
+                    XyzColor::from_array(
+                        matrix::multiply_vector(&adaptation, xyz.to_array()),
+                        1_f64,
+                    )
+                });
+                let white = XyzColor::new(
+                    red.x() + green.x() + blue.x(),
+                    red.y() + green.y() + blue.y(),
+                    red.z() + green.z() + blue.z(),
+                );
+                let transfers = [b"rTRC", b"gTRC", b"bTRC"]
+                    .map(|signature| self.tag(signature).and_then(read_curve));
+                let [r, g, b] = transfers;
+                let transfers = [r?, g?, b?];
+                Ok(RgbSpace::new(
+                    Chromaticity::from_xyz(&red),
+                    Chromaticity::from_xyz(&green),
+                    Chromaticity::from_xyz(&blue),
+                    normalized_white_point(&white)?,
+                    TransferFunction::Linear,
+                )?
+                .with_transfers(transfers))
+            }
+            IccColorSpace::Gray => {
+                let transfer = read_curve(self.tag(b"kTRC")?)?;
+                let white = matrix::multiply_vector(&adaptation, self.illuminant.to_array());
+                let srgb = RgbSpace::srgb();
+                RgbSpace::new(
+                    srgb.red(),
+                    srgb.green(),
+                    srgb.blue(),
+                    normalized_white_point(&XyzColor::from_array(white, 1_f64))?,
+                    transfer,
+                )
+            }
+            color_space => Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("color space {:?}", color_space),
+            )),
+        }
+    }
+
+    // device values (0 - 1) => PCS XYZ with the A2B tag of the intent (matrix / TRC if missing)
+    // absolute colorimetric scales the result by the media white point
+    pub fn device_to_pcs(&self, device: &[f64], intent: RenderingIntent) -> Result<XyzColor> {
+        DeviceTransform::input(self, intent)?.to_pcs(device)
+    }
+
+    // PCS XYZ => device values (0 - 1) with the B2A tag of the intent (matrix / TRC if missing)
+    pub fn pcs_to_device(&self, xyz: &XyzColor, intent: RenderingIntent) -> Result<Vec<f64>> {
+        Ok(DeviceTransform::output(self, intent)?.to_device(xyz))
+    }
+
+    // neutral black point on the PCS for black point compensation
+    // output profiles use the round trip of PCS black (ink limits), the others the device black
+    pub fn black_point(&self, intent: RenderingIntent) -> Result<XyzColor> {
+        let intent = match intent {
+            RenderingIntent::AbsoluteColorimetric => RenderingIntent::RelativeColorimetric,
+            intent => intent,
+        };
+        let input = DeviceTransform::input(self, intent)?;
+        let black =
+            if self.class == IccProfileClass::Output && self.lut_tag(b"B2A", intent).is_some() {
+                let output = DeviceTransform::output(self, intent)?;
+                input.to_pcs(&output.to_device(&XyzColor::new(0_f64, 0_f64, 0_f64)))?
+            } else {
+                input.to_pcs(&self.color_space.black())?
+            };
+        let y = black.y().clamp(0_f64, 1_f64);
+        Ok(XyzColor::new(
+            self.illuminant.x() * y,
+            self.illuminant.y() * y,
+            self.illuminant.z() * y,
+        ))
+    }
+
+    // A2B1 for relative / absolute colorimetric, A2B2 for saturation, A2B0 as the fallback
+    fn lut_tag(&self, prefix: &[u8; 3], intent: RenderingIntent) -> Option<[u8; 4]> {
+        let index = match intent {
+            RenderingIntent::Perceptual => b'0',
+            RenderingIntent::RelativeColorimetric | RenderingIntent::AbsoluteColorimetric => b'1',
+            RenderingIntent::Saturation => b'2',
+        };
+        [index, b'0']
+            .map(|index| [prefix[0], prefix[1], prefix[2], index])
+            .into_iter()
+            .find(|signature| self.has_tag(signature))
+    }
+
+    fn tag(&self, signature: &[u8; 4]) -> Result<&[u8]> {
+        let tag = self
+            .tags
+            .iter()
+            .find(|tag| &tag.signature == signature)
+            .ok_or_else(|| {
+                errors::Error::new(
+                    errors::ErrorCode::InvalidArgument,
+                    format!("missing tag {}", String::from_utf8_lossy(signature)),
+                )
+            })?;
+        Ok(&self.data[tag.offset..tag.offset + tag.size])
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum IccVersion {
+    // 2.4
+    V2,
+    // 4.3
+    #[default]
+    V4,
+}
+
+// matrix / TRC display profile of an RgbSpace
+// the colorants are adapted to D50 with Bradford (chad), wtpt is the space white in v2 and D50 in v4
+// v2 has no parametricCurveType, transfers other than gamma are sampled
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct IccProfileWriter {
+    version: IccVersion,
+    description: String,
+    copyright: String,
+}
+
+impl IccProfileWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_version(self, version: IccVersion) -> Self {
+        Self { version, ..self }
+    }
+
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        Self {
+            description: description.into(),
+            ..self
+        }
+    }
+
+    pub fn with_copyright(self, copyright: impl Into<String>) -> Self {
+        Self {
+            copyright: copyright.into(),
+            ..self
+        }
+    }
+
+    pub fn version(&self) -> IccVersion {
+        self.version
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn copyright(&self) -> &str {
+        &self.copyright
+    }
+
+    pub fn to_bytes(&self, space: &RgbSpace) -> Vec<u8> {
+        let adaptation =
+            // the white point is validated in RgbSpace::new
+            ChromaticAdaptation::new(space.white_point(), WhitePoint::Custom(PCS_ILLUMINANT))
+                .unwrap()
+                .matrix();
+        let colorants = matrix::multiply(&adaptation, &space.to_xyz_matrix());
+        let column = |i: usize| xyz_type([colorants[0][i], colorants[1][i], colorants[2][i]]);
+        let white = match self.version {
+            IccVersion::V2 => {
+                let white = space.white_point().xyz();
+                [white.x(), white.y(), white.z()].map(|v| v / white.y())
+            }
+            IccVersion::V4 => PCS_ILLUMINANT.to_array(),
+        };
+        let [red, green, blue] = space
+            .transfers()
+            .clone()
+            .map(|transfer| curve_type(&transfer, self.version));
+        let (description, copyright) = match self.version {
+            IccVersion::V2 => (
+                text_description_type(&self.description),
+                text_type(&self.copyright),
+            ),
+            IccVersion::V4 => (mluc_type(&self.description), mluc_type(&self.copyright)),
+        };
+        let tags = [
+            (b"desc", description),
+            (b"cprt", copyright),
+            (b"wtpt", xyz_type(white)),
+            (b"rXYZ", column(0)),
+            (b"gXYZ", column(1)),
+            (b"bXYZ", column(2)),
+            (b"rTRC", red),
+            (b"gTRC", green),
+            (b"bTRC", blue),
+            (b"chad", sf32_type(&adaptation)),
+        ];
+
+        // identical tag data (e.g. the TRCs) is shared
+        let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+        let mut body: Vec<u8> = Vec::new();
+        let mut written: Vec<(usize, &[u8])> = Vec::new();
+        let start = HEADER_SIZE + 4 + tags.len() * TAG_ENTRY_SIZE;
+        for (signature, data) in &tags {
+            let offset = match written.iter().find(|(_, other)| *other == data.as_slice()) {
+                Some((offset, _)) => *offset,
+                None => {
+                    let offset = start + body.len();
+                    body.extend(data);
+                    body.resize(body.len().next_multiple_of(4), 0);
+                    written.push((offset, data));
+                    offset
+                }
+            };
+            table.extend(*signature);
+            table.extend((offset as u32).to_be_bytes());
+            table.extend((data.len() as u32).to_be_bytes());
+        }
+
+        let mut header = vec![0_u8; HEADER_SIZE];
+        let size = (HEADER_SIZE + table.len() + body.len()) as u32;
+        header[..4].copy_from_slice(&size.to_be_bytes());
+        header[8..10].copy_from_slice(match self.version {
+            IccVersion::V2 => &[2, 0x40],
+            IccVersion::V4 => &[4, 0x30],
+        });
+        header[12..16].copy_from_slice(b"mntr");
+        header[16..20].copy_from_slice(b"RGB ");
+        header[20..24].copy_from_slice(b"XYZ ");
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        for (i, value) in date_time(seconds).into_iter().enumerate() {
+            header[24 + i * 2..26 + i * 2].copy_from_slice(&value.to_be_bytes());
+        }
+        header[36..40].copy_from_slice(b"acsp");
+        for (i, value) in PCS_ILLUMINANT.to_array().into_iter().enumerate() {
+            header[68 + i * 4..72 + i * 4].copy_from_slice(&s15_fixed16(value));
+        }
+
+        let mut data = header;
+        data.extend(table);
+        data.extend(body);
+        data
+    }
+
+    pub fn write(&self, space: &RgbSpace, path: impl AsRef<Path>) -> Result<()> {
+        fs::write(path.as_ref(), self.to_bytes(space)).map_err(|error| {
+            errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("{} {}", path.as_ref().display(), error),
+            )
+        })
+    }
+}
+
+// number of curveType entries for the curves without a closed form
+const SAMPLED_CURVE_SIZE: usize = 1024;
+
+fn curve_type(transfer: &TransferFunction, version: IccVersion) -> Vec<u8> {
+    let curv = |values: &[f64]| {
+        let mut data = b"curv\0\0\0\0".to_vec();
+        data.extend((values.len() as u32).to_be_bytes());
+        for value in values {
+            data.extend(((value.clamp(0_f64, 1_f64) * 65535_f64).round() as u16).to_be_bytes());
+        }
+        data
+    };
+    match (transfer, version) {
+        (TransferFunction::Linear, _) => curv(&[]),
+        // u8Fixed8Number
+        (TransferFunction::Gamma(gamma), IccVersion::V2) if (0_f64..256_f64).contains(gamma) => {
+            let mut data = b"curv\0\0\0\0".to_vec();
+            data.extend(1_u32.to_be_bytes());
+            data.extend(((gamma * 256_f64).round() as u16).to_be_bytes());
+            data
+        }
+        (TransferFunction::Sampled(curve), _) => curv(curve.values()),
+        (transfer, IccVersion::V4) if transfer.parametric().is_some() => {
+            let curve = transfer.parametric().unwrap();
+            let mut data = b"para\0\0\0\0".to_vec();
+            data.extend(curve.function_type().to_be_bytes());
+            data.extend([0, 0]);
+            for value in curve.parameters() {
+                data.extend(s15_fixed16(*value));
+            }
+            data
+        }
+        (transfer, _) => {
+            let values = (0..SAMPLED_CURVE_SIZE)
+                .map(|i| transfer.decode(i as f64 / (SAMPLED_CURVE_SIZE - 1) as f64))
+                .collect::<Vec<_>>();
+            curv(&values)
+        }
+    }
+}
+
+fn xyz_type(xyz: [f64; 3]) -> Vec<u8> {
+    let mut data = b"XYZ \0\0\0\0".to_vec();
+    for value in xyz {
+        data.extend(s15_fixed16(value));
+    }
+    data
+}
+
+fn sf32_type(m: &Matrix3) -> Vec<u8> {
+    let mut data = b"sf32\0\0\0\0".to_vec();
+    for value in m.iter().flatten() {
+        data.extend(s15_fixed16(*value));
+    }
+    data
+}
+
+// one en-US record
+fn mluc_type(text: &str) -> Vec<u8> {
+    let units = text
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect::<Vec<_>>();
+    let mut data = b"mluc\0\0\0\0".to_vec();
+    data.extend(1_u32.to_be_bytes());
+    data.extend(12_u32.to_be_bytes());
+    data.extend(b"enUS");
+    data.extend((units.len() as u32).to_be_bytes());
+    data.extend(28_u32.to_be_bytes());
+    data.extend(units);
+    data
+}
+
+// ASCII (non-ASCII characters are replaced with '?') and Unicode parts, no ScriptCode
+fn text_description_type(text: &str) -> Vec<u8> {
+    let ascii = text
+        .chars()
+        .map(|c| if c.is_ascii() { c as u8 } else { b'?' })
+        .chain([0])
+        .collect::<Vec<_>>();
+    let units = text.encode_utf16().chain([0]).collect::<Vec<_>>();
+    let mut data = b"desc\0\0\0\0".to_vec();
+    data.extend((ascii.len() as u32).to_be_bytes());
+    data.extend(ascii);
+    data.extend(0_u32.to_be_bytes());
+    data.extend((units.len() as u32).to_be_bytes());
+    data.extend(units.into_iter().flat_map(u16::to_be_bytes));
+    data.extend([0_u8; 70]);
+    data
+}
+
+fn text_type(text: &str) -> Vec<u8> {
+    let mut data = b"text\0\0\0\0".to_vec();
+    data.extend(
+        text.chars()
+            .map(|c| if c.is_ascii() { c as u8 } else { b'?' }),
+    );
+    data.push(0);
+    data
+}
+
+fn s15_fixed16(value: f64) -> [u8; 4] {
+    ((value * 65536_f64).round() as i32).to_be_bytes()
+}
+
+// dateTimeNumber (UTC) of seconds since 1970-01-01
+fn date_time(seconds: u64) -> [u16; 6] {
+    let days = (seconds / 86400) as i64;
+    let time = seconds % 86400;
+    // days => civil date (proleptic Gregorian)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    [
+        year as u16,
+        month as u16,
+        day as u16,
+        (time / 3600) as u16,
+        (time % 3600 / 60) as u16,
+        (time % 60) as u16,
+    ]
+}
+
+// profile to profile transform of device values (0 - 1)
+// black points that can not be detected are treated as 0
+#[derive(Debug, PartialEq, Clone)]
+pub struct IccTransform {
+    source: DeviceTransform,
+    destination: DeviceTransform,
+    intent: RenderingIntent,
+    black_point_compensation: bool,
+    source_black: XyzColor,
+    destination_black: XyzColor,
+}
+
+impl IccTransform {
+    pub fn new(
+        source: &IccProfile,
+        destination: &IccProfile,
+        intent: RenderingIntent,
+    ) -> Result<Self> {
+        Ok(Self {
+            source: DeviceTransform::input(source, intent)?,
+            destination: DeviceTransform::output(destination, intent)?,
+            intent,
+            black_point_compensation: false,
+            source_black: source.black_point(intent).unwrap_or(XYZ_BLACK),
+            destination_black: destination.black_point(intent).unwrap_or(XYZ_BLACK),
+        })
+    }
+
+    // e.g. sRGB => printer profile
+    pub fn from_rgb_space(
+        source: &RgbSpace,
+        destination: &IccProfile,
+        intent: RenderingIntent,
+    ) -> Result<Self> {
+        let source = DeviceTransform::from_rgb_space(source)?;
+        Ok(Self {
+            source_black: source.black_point(),
+            source,
+            destination: DeviceTransform::output(destination, intent)?,
+            intent,
+            black_point_compensation: false,
+            destination_black: destination.black_point(intent).unwrap_or(XYZ_BLACK),
+        })
+    }
+
+    // e.g. printer profile => sRGB for soft proofing
+    pub fn to_rgb_space(
+        source: &IccProfile,
+        destination: &RgbSpace,
+        intent: RenderingIntent,
+    ) -> Result<Self> {
+        let destination = DeviceTransform::from_rgb_space(destination)?;
+        Ok(Self {
+            source: DeviceTransform::input(source, intent)?,
+            destination_black: destination.black_point(),
+            destination,
+            intent,
+            black_point_compensation: false,
+            source_black: source.black_point(intent).unwrap_or(XYZ_BLACK),
+        })
+    }
+
+    // ignored for absolute colorimetric
+    pub fn with_black_point_compensation(self, black_point_compensation: bool) -> Self {
+        Self {
+            black_point_compensation,
+            ..self
+        }
+    }
+
+    pub fn intent(&self) -> RenderingIntent {
+        self.intent
+    }
+
+    pub fn black_point_compensation(&self) -> bool {
+        self.black_point_compensation
+    }
+
+    pub fn input_channels(&self) -> usize {
+        self.source.channels
+    }
+
+    pub fn output_channels(&self) -> usize {
+        self.destination.channels
+    }
+
+    pub fn transform(&self, device: &[f64]) -> Result<Vec<f64>> {
+        let xyz = self.source.to_pcs(device)?;
+        if !self.black_point_compensation
+            || self.intent == RenderingIntent::AbsoluteColorimetric
+            || self.source_black.y() >= 1_f64
+        {
+            return Ok(self.destination.to_device(&xyz));
+        }
+        // neutral blacks, the scale keeps the white point
+        let scale = (1_f64 - self.destination_black.y()) / (1_f64 - self.source_black.y());
+        let white = self.destination.illuminant.to_array();
+        let xyz = xyz.to_array();
+        let compensated = [0, 1, 2].map(|i| xyz[i] * scale + white[i] * (1_f64 - scale));
+        Ok(self
+            .destination
+            .to_device(&XyzColor::from_array(compensated, 1_f64)))
+    }
+
+    fn expect_channels(&self, input: usize, output: usize) -> Result<()> {
+        if self.input_channels() != input || self.output_channels() != output {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!(
+                    "channels {} {}",
+                    self.input_channels(),
+                    self.output_channels()
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Conversion<&RgbColor, CmykColor> for IccTransform {
+    fn convert(&self, color: &RgbColor) -> Result<CmykColor> {
+        self.expect_channels(3, 4)?;
+        let cmyk = self.transform(&rgb_device(color))?;
+        let [c, m, y, k] = [0, 1, 2, 3].map(|i| (cmyk[i] * 100_f64).round() as u8);
+        Ok(CmykColor::from_cmyka(c, m, y, k, color.a()))
+    }
+}
+
+impl Conversion<&CmykColor, RgbColor> for IccTransform {
+    fn convert(&self, color: &CmykColor) -> Result<RgbColor> {
+        self.expect_channels(4, 3)?;
+        let cmyk = [color.c(), color.m(), color.y(), color.k()].map(|v| v as f64 / 100_f64);
+        let rgb = self.transform(&cmyk)?;
+        Ok(device_rgb(&rgb, color.a()))
+    }
+}
+
+impl Conversion<&RgbColor, RgbColor> for IccTransform {
+    fn convert(&self, color: &RgbColor) -> Result<RgbColor> {
+        self.expect_channels(3, 3)?;
+        let rgb = self.transform(&rgb_device(color))?;
+        Ok(device_rgb(&rgb, color.a()))
+    }
+}
+
+fn rgb_device(color: &RgbColor) -> [f64; 3] {
+    [color.r(), color.g(), color.b()].map(|v| v as f64 / 255_f64)
+}
+
+fn device_rgb(device: &[f64], a: u8) -> RgbColor {
+    RgbColor::from_rgba(
+        utils::float_to_u8(device[0]),
+        utils::float_to_u8(device[1]),
+        utils::float_to_u8(device[2]),
+        a,
+    )
+}
+
+const XYZ_BLACK: XyzColor = XyzColor::new(0_f64, 0_f64, 0_f64);
+
+// D50 of the profile header (s15Fixed16)
+const PCS_ILLUMINANT: XyzColor = XyzColor::new(0.9642, 1_f64, 0.8249);
+
+#[derive(Debug, PartialEq, Clone)]
+enum DeviceModel {
+    // device linear => PCS XYZ
+    Matrix {
+        colorants: Matrix3,
+        inverse: Matrix3,
+        transfers: Box<[TransferFunction; 3]>,
+    },
+    Gray(TransferFunction),
+    Lut {
+        pipeline: Pipeline,
+        pcs: IccColorSpace,
+    },
+}
+
+// one direction of a profile, A2B (to_pcs) or B2A (to_device)
+#[derive(Debug, PartialEq, Clone)]
+struct DeviceTransform {
+    model: DeviceModel,
+    channels: usize,
+    illuminant: XyzColor,
+    // media white / PCS illuminant for absolute colorimetric, otherwise 1
+    scale: [f64; 3],
+}
+
+impl DeviceTransform {
+    fn input(profile: &IccProfile, intent: RenderingIntent) -> Result<Self> {
+        Self::with_tag(profile, intent, b"A2B")
+    }
+
+    fn output(profile: &IccProfile, intent: RenderingIntent) -> Result<Self> {
+        Self::with_tag(profile, intent, b"B2A")
+    }
+
+    fn with_tag(profile: &IccProfile, intent: RenderingIntent, prefix: &[u8; 3]) -> Result<Self> {
+        let channels = profile.color_space.channels();
+        let model = match profile.lut_tag(prefix, intent) {
+            Some(signature) => {
+                let a_to_b = prefix == b"A2B";
+                let input_space = if a_to_b {
+                    profile.color_space
+                } else {
+                    profile.pcs
+                };
+                let pipeline =
+                    read_lut(profile.tag(&signature)?, input_space == IccColorSpace::Xyz)?;
+                let expected = if a_to_b { (channels, 3) } else { (3, channels) };
+                if !matches!(profile.pcs, IccColorSpace::Xyz | IccColorSpace::Lab)
+                    || (pipeline.inputs, pipeline.outputs) != expected
+                {
+                    return Err(format_error(format!(
+                        "{} {:?} {:?}",
+                        String::from_utf8_lossy(&signature),
+                        profile.color_space,
+                        profile.pcs
+                    )));
+                }
+                DeviceModel::Lut {
+                    pipeline,
+                    pcs: profile.pcs,
+                }
+            }
+            None => Self::matrix_model(profile)?,
+        };
+        let scale = match intent {
+            RenderingIntent::AbsoluteColorimetric if profile.has_tag(b"wtpt") => {
+                let white = profile.media_white_point()?.to_array();
+                let illuminant = profile.illuminant.to_array();
+                [0, 1, 2].map(|i| white[i] / illuminant[i])
+            }
+            _ => [1_f64; 3],
+        };
+        Ok(Self {
+            model,
+            channels,
+            illuminant: profile.illuminant,
+            scale,
+        })
+    }
+
+    // the colorants are used as they are (adapted to the PCS)
+    fn matrix_model(profile: &IccProfile) -> Result<DeviceModel> {
+        if profile.pcs != IccColorSpace::Xyz {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("PCS {:?}", profile.pcs),
+            ));
+        }
+        match profile.color_space {
+            IccColorSpace::Rgb => {
+                let colorants = [b"rXYZ", b"gXYZ", b"bXYZ"]
+                    .map(|signature| profile.tag(signature).and_then(read_xyz_type));
+                let [red, green, blue] = colorants;
+                let [red, green, blue] = [red?, green?, blue?].map(XyzColor::to_array);
+                let colorants = [0, 1, 2].map(|i| [red[i], green[i], blue[i]]);
+                let transfers = [b"rTRC", b"gTRC", b"bTRC"]
+                    .map(|signature| profile.tag(signature).and_then(read_curve));
+                let [r, g, b] = transfers;
+                Ok(DeviceModel::Matrix {
+                    colorants,
+                    inverse: matrix::inverse(&colorants)
+                        .ok_or_else(|| format_error("colorants"))?,
+                    transfers: Box::new([r?, g?, b?]),
+                })
+            }
+            IccColorSpace::Gray => Ok(DeviceModel::Gray(read_curve(profile.tag(b"kTRC")?)?)),
+            color_space => Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("color space {:?}", color_space),
+            )),
+        }
+    }
+
+    // a display profile of the space (Bradford to the PCS illuminant)
+    fn from_rgb_space(space: &RgbSpace) -> Result<Self> {
+        let adaptation =
+            // the white point is validated in RgbSpace::new
+            ChromaticAdaptation::new(space.white_point(), WhitePoint::Custom(PCS_ILLUMINANT))
+                .unwrap();
+        let colorants = matrix::multiply(&adaptation.matrix(), &space.to_xyz_matrix());
+        Ok(Self {
+            model: DeviceModel::Matrix {
+                colorants,
+                inverse: matrix::inverse(&colorants)
+                    .ok_or_else(|| format_error(format!("{:?}", space)))?,
+                transfers: Box::new(space.transfers().clone()),
+            },
+            channels: 3,
+            illuminant: PCS_ILLUMINANT,
+            scale: [1_f64; 3],
+        })
+    }
+
+    fn black_point(&self) -> XyzColor {
+        self.to_pcs(&vec![0_f64; self.channels])
+            .map(|black| {
+                let y = black.y().clamp(0_f64, 1_f64);
+                XyzColor::from_array(self.illuminant.to_array().map(|v| v * y), 1_f64)
+            })
+            .unwrap_or(XYZ_BLACK)
+    }
+
+    fn to_pcs(&self, device: &[f64]) -> Result<XyzColor> {
+        if device.len() != self.channels {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("{:?}", device),
+            ));
+        }
+        let device = device
+            .iter()
+            .map(|v| v.clamp(0_f64, 1_f64))
+            .collect::<Vec<_>>();
+        let xyz = match &self.model {
+            DeviceModel::Matrix {
+                colorants,
+                transfers,
+                ..
+            } => matrix::multiply_vector(
+                colorants,
+                [0, 1, 2].map(|i| transfers[i].decode(device[i])),
+            ),
+            DeviceModel::Gray(transfer) => {
+                let y = transfer.decode(device[0]);
+                self.illuminant.to_array().map(|v| v * y)
+            }
+            DeviceModel::Lut { pipeline, pcs } => decode_pcs(
+                &pipeline.evaluate(&device),
+                *pcs,
+                pipeline.legacy_lab,
+                &self.illuminant,
+            ),
+        };
+        Ok(XyzColor::from_array(
+            [0, 1, 2].map(|i| xyz[i] * self.scale[i]),
+            1_f64,
+        ))
+    }
+
+    fn to_device(&self, xyz: &XyzColor) -> Vec<f64> {
+        let xyz = xyz.to_array();
+        let xyz = [0, 1, 2].map(|i| xyz[i] / self.scale[i]);
+        let device = match &self.model {
+            DeviceModel::Matrix {
+                inverse, transfers, ..
+            } => {
+                let linear = matrix::multiply_vector(inverse, xyz);
+                (0..3)
+                    .map(|i| transfers[i].encode(linear[i].clamp(0_f64, 1_f64)))
+                    .collect()
+            }
+            DeviceModel::Gray(transfer) => {
+                vec![transfer.encode((xyz[1] / self.illuminant.y()).clamp(0_f64, 1_f64))]
+            }
+            DeviceModel::Lut { pipeline, pcs } => pipeline.evaluate(&encode_pcs(
+                xyz,
+                *pcs,
+                pipeline.legacy_lab,
+                &self.illuminant,
+            )),
+        };
+        device
+            .into_iter()
+            .map(|v: f64| v.clamp(0_f64, 1_f64))
+            .collect()
+    }
+}
+
+// PCS encodings: XYZ u1Fixed15 (1.0 = 32768 / 65535), Lab L 0 - 100 and a, b -128 - 127
+// the lut16 Lab encoding is the legacy one (L 100 = 0xff00)
+fn decode_pcs(values: &[f64], pcs: IccColorSpace, legacy_lab: bool, white: &XyzColor) -> [f64; 3] {
+    match pcs {
+        IccColorSpace::Lab => {
+            let scale = if legacy_lab {
+                65535_f64 / 65280_f64
+            } else {
+                1_f64
+            };
+            let [l, a, b] = [0, 1, 2].map(|i| values[i] * scale);
+            lab_to_xyz(
+                [l * 100_f64, a * 255_f64 - 128_f64, b * 255_f64 - 128_f64],
+                white,
+            )
+        }
+        _ => [0, 1, 2].map(|i| values[i] * 65535_f64 / 32768_f64),
+    }
+}
+
+fn encode_pcs(xyz: [f64; 3], pcs: IccColorSpace, legacy_lab: bool, white: &XyzColor) -> Vec<f64> {
+    match pcs {
+        IccColorSpace::Lab => {
+            let [l, a, b] = xyz_to_lab(xyz, white);
+            let scale = if legacy_lab {
+                65280_f64 / 65535_f64
+            } else {
+                1_f64
+            };
+            vec![
+                l / 100_f64 * scale,
+                (a + 128_f64) / 255_f64 * scale,
+                (b + 128_f64) / 255_f64 * scale,
+            ]
+        }
+        _ => xyz.map(|v| v * 32768_f64 / 65535_f64).to_vec(),
+    }
+}
+
+// CIE 1976 L*a*b* of the PCS
+const LAB_DELTA: f64 = 6_f64 / 29_f64;
+
+fn lab_to_xyz(lab: [f64; 3], white: &XyzColor) -> [f64; 3] {
+    let fy = (lab[0] + 16_f64) / 116_f64;
+    let f = [fy + lab[1] / 500_f64, fy, fy - lab[2] / 200_f64];
+    let white = white.to_array();
+    [0, 1, 2].map(|i| {
+        let t = f[i];
+        let v = if t > LAB_DELTA {
+            t.powi(3)
+        } else {
+            3_f64 * LAB_DELTA.powi(2) * (t - 4_f64 / 29_f64)
+        };
+        v * white[i]
+    })
+}
+
+fn xyz_to_lab(xyz: [f64; 3], white: &XyzColor) -> [f64; 3] {
+    let white = white.to_array();
+    let [fx, fy, fz] = [0, 1, 2].map(|i| {
+        let t = xyz[i] / white[i];
+        if t > LAB_DELTA.powi(3) {
+            t.cbrt()
+        } else {
+            t / (3_f64 * LAB_DELTA.powi(2)) + 4_f64 / 29_f64
+        }
+    });
+    [
+        116_f64 * fy - 16_f64,
+        500_f64 * (fx - fy),
+        200_f64 * (fy - fz),
+    ]
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Stage {
+    Curves(Vec<TransferFunction>),
+    // 3x3 and offset
+    Matrix(Matrix3, [f64; 3]),
+    Clut(Clut),
+}
+
+// multidimensional table, the first input varies slowest
+#[derive(Debug, PartialEq, Clone)]
+struct Clut {
+    grid: Vec<usize>,
+    outputs: usize,
+    values: Vec<f64>,
+}
+
+impl Clut {
+    // n-linear interpolation
+    fn evaluate(&self, input: &[f64]) -> Vec<f64> {
+        let mut base = 0;
+        let mut strides = vec![0; self.grid.len()];
+        let mut fractions = vec![0_f64; self.grid.len()];
+        let mut stride = self.outputs;
+        for i in (0..self.grid.len()).rev() {
+            let position = input[i].clamp(0_f64, 1_f64) * (self.grid[i] - 1) as f64;
+            let index = (position.floor() as usize).min(self.grid[i] - 2);
+            base += index * stride;
+            strides[i] = stride;
+            fractions[i] = position - index as f64;
+            stride *= self.grid[i];
+        }
+        let mut output = vec![0_f64; self.outputs];
+        for corner in 0..1_usize << self.grid.len() {
+            let mut weight = 1_f64;
+            let mut offset = base;
+            for i in 0..self.grid.len() {
+                if corner & (1 << i) != 0 {
+                    weight *= fractions[i];
+                    offset += strides[i];
+                } else {
+                    weight *= 1_f64 - fractions[i];
+                }
+            }
+            if weight == 0_f64 {
+                continue;
+            }
+            for (out, value) in output.iter_mut().zip(&self.values[offset..]) {
+                *out += value * weight;
+            }
+        }
+        output
+    }
+}
+
+// values between the stages are 0 - 1
+#[derive(Debug, PartialEq, Clone)]
+struct Pipeline {
+    stages: Vec<Stage>,
+    inputs: usize,
+    outputs: usize,
+    legacy_lab: bool,
+}
+
+impl Pipeline {
+    fn evaluate(&self, input: &[f64]) -> Vec<f64> {
+        let mut values = input.to_vec();
+        for stage in &self.stages {
+            values = match stage {
+                Stage::Curves(curves) => values
+                    .iter()
+                    .zip(curves)
+                    .map(|(v, curve)| curve.decode(*v))
+                    .collect(),
+                Stage::Matrix(m, offset) => {
+                    let v = matrix::multiply_vector(m, [values[0], values[1], values[2]]);
+                    (0..3).map(|i| v[i] + offset[i]).collect()
+                }
+                Stage::Clut(clut) => clut.evaluate(&values),
+            };
+            for value in values.iter_mut() {
+                *value = value.clamp(0_f64, 1_f64);
+            }
+        }
+        values
+    }
+}
+
+fn format_error(target: impl Into<String>) -> errors::Error {
+    errors::Error::new(errors::ErrorCode::InvalidArgumentFormat, target)
+}
+
+fn normalized_white_point(white: &XyzColor) -> Result<WhitePoint> {
+    if white.y() <= 0_f64 {
+        return Err(format_error(format!("white point {}", white)));
+    }
+    Ok(WhitePoint::Custom(XyzColor::new(
+        white.x() / white.y(),
+        1_f64,
+        white.z() / white.y(),
+    )))
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    data.get(offset..offset + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| format_error(format!("offset {}", offset)))
+}
+
+fn read_signature(data: &[u8], offset: usize) -> Result<[u8; 4]> {
+    read_bytes(data, offset)
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    Ok(u16::from_be_bytes(read_bytes(data, offset)?))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    Ok(u32::from_be_bytes(read_bytes(data, offset)?))
+}
+
+fn read_s15_fixed16(data: &[u8], offset: usize) -> Result<f64> {
+    Ok(i32::from_be_bytes(read_bytes(data, offset)?) as f64 / 65536_f64)
+}
+
+fn read_xyz_number(data: &[u8], offset: usize) -> Result<XyzColor> {
+    Ok(XyzColor::new(
+        read_s15_fixed16(data, offset)?,
+        read_s15_fixed16(data, offset + 4)?,
+        read_s15_fixed16(data, offset + 8)?,
+    ))
+}
+
+fn expect_type(data: &[u8], signature: &[u8; 4]) -> Result<()> {
+    let actual = read_signature(data, 0)?;
+    if &actual != signature {
+        return Err(format_error(format!(
+            "type {} expected {}",
+            String::from_utf8_lossy(&actual),
+            String::from_utf8_lossy(signature)
+        )));
+    }
+    Ok(())
+}
+
+fn read_xyz_type(data: &[u8]) -> Result<XyzColor> {
+    expect_type(data, b"XYZ ")?;
+    read_xyz_number(data, 8)
+}
+
+// curveType or parametricCurveType
+fn read_curve(data: &[u8]) -> Result<TransferFunction> {
+    match &read_signature(data, 0)? {
+        b"curv" => {
+            let count = read_u32(data, 8)? as usize;
+            match count {
+                0 => Ok(TransferFunction::Linear),
+                // u8Fixed8Number
+                1 => Ok(TransferFunction::Gamma(
+                    read_u16(data, 12)? as f64 / 256_f64,
+                )),
+                _ => {
+                    let values = (0..count)
+                        .map(|i| Ok(read_u16(data, 12 + i * 2)? as f64 / 65535_f64))
+                        .collect::<Result<Vec<_>>>()?;
+                    Ok(TransferFunction::Sampled(SampledCurve::new(values)?))
+                }
+            }
+        }
+        b"para" => {
+            let function_type = read_u16(data, 8)?;
+            let count = transfer::parameter_count(function_type)
+                .ok_or_else(|| format_error(format!("function type {}", function_type)))?;
+            let parameters = (0..count)
+                .map(|i| read_s15_fixed16(data, 12 + i * 4))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(TransferFunction::Parametric(ParametricCurve::new(
+                function_type,
+                &parameters,
+            )?))
+        }
+        signature => Err(format_error(format!(
+            "curve type {}",
+            String::from_utf8_lossy(signature)
+        ))),
+    }
+}
+
+// bytes of a curveType or parametricCurveType (without padding)
+fn curve_size(data: &[u8]) -> Result<usize> {
+    match &read_signature(data, 0)? {
+        b"curv" => Ok(12 + read_u32(data, 8)? as usize * 2),
+        b"para" => {
+            let function_type = read_u16(data, 8)?;
+            let count = transfer::parameter_count(function_type)
+                .ok_or_else(|| format_error(format!("function type {}", function_type)))?;
+            Ok(12 + count * 4)
+        }
+        signature => Err(format_error(format!(
+            "curve type {}",
+            String::from_utf8_lossy(signature)
+        ))),
+    }
+}
+
+// curves of lutAtoBType / lutBtoAType, each aligned to 4 bytes
+fn read_curve_sequence(data: &[u8], offset: usize, count: usize) -> Result<Vec<TransferFunction>> {
+    let mut offset = offset;
+    (0..count)
+        .map(|_| {
+            let curve = data
+                .get(offset..)
+                .ok_or_else(|| format_error(format!("offset {}", offset)))?;
+            let size = curve_size(curve)?;
+            let function = read_curve(curve)?;
+            offset = (offset + size).next_multiple_of(4);
+            Ok(function)
+        })
+        .collect()
+}
+
+// input / output tables of lut8Type (256 entries) and lut16Type
+fn read_tables(
+    data: &[u8],
+    offset: usize,
+    count: usize,
+    entries: usize,
+    precision: usize,
+) -> Result<Vec<TransferFunction>> {
+    (0..count)
+        .map(|i| {
+            let start = offset + i * entries * precision;
+            let values = read_samples(data, start, entries, precision)?;
+            Ok(TransferFunction::Sampled(SampledCurve::new(values)?))
+        })
+        .collect()
+}
+
+// 1 (u8) or 2 (u16) bytes per value, normalized to 0 - 1
+fn read_samples(data: &[u8], offset: usize, count: usize, precision: usize) -> Result<Vec<f64>> {
+    let bytes = count
+        .checked_mul(precision)
+        .and_then(|size| data.get(offset..offset.checked_add(size)?))
+        .ok_or_else(|| format_error(format!("offset {}", offset)))?;
+    Ok(match precision {
+        1 => bytes.iter().map(|&v| v as f64 / 255_f64).collect(),
+        _ => bytes
+            .chunks_exact(2)
+            .map(|v| u16::from_be_bytes([v[0], v[1]]) as f64 / 65535_f64)
+            .collect(),
+    })
+}
+
+fn read_clut(
+    data: &[u8],
+    offset: usize,
+    grid: Vec<usize>,
+    outputs: usize,
+    precision: usize,
+) -> Result<Clut> {
+    if grid.iter().any(|&points| points < 2) {
+        return Err(format_error(format!("grid {:?}", grid)));
+    }
+    let count = grid
+        .iter()
+        .try_fold(outputs, |count, &points| count.checked_mul(points))
+        .ok_or_else(|| format_error(format!("grid {:?}", grid)))?;
+    Ok(Clut {
+        values: read_samples(data, offset, count, precision)?,
+        grid,
+        outputs,
+    })
+}
+
+fn read_matrix(data: &[u8], offset: usize) -> Result<Matrix3> {
+    let mut m = [[0_f64; 3]; 3];
+    for (i, value) in m.iter_mut().flatten().enumerate() {
+        *value = read_s15_fixed16(data, offset + i * 4)?;
+    }
+    Ok(m)
+}
+
+// lut8Type, lut16Type, lutAtoBType or lutBtoAType
+// the matrix of lut8 / lut16 is used only for PCS XYZ input
+fn read_lut(data: &[u8], xyz_input: bool) -> Result<Pipeline> {
+    let signature = read_signature(data, 0)?;
+    let [inputs, outputs, grid] = read_bytes::<3>(data, 8)?.map(|v| v as usize);
+    if !(1..=15).contains(&inputs) || !(1..=15).contains(&outputs) {
+        return Err(format_error(format!("channels {} {}", inputs, outputs)));
+    }
+    let mut stages = Vec::new();
+    let legacy_lab = &signature == b"mft2";
+    match &signature {
+        b"mft1" | b"mft2" => {
+            if xyz_input && inputs == 3 {
+                stages.push(Stage::Matrix(read_matrix(data, 12)?, [0_f64; 3]));
+            }
+            let (input_entries, output_entries, offset, precision) = if legacy_lab {
+                (
+                    read_u16(data, 48)? as usize,
+                    read_u16(data, 50)? as usize,
+                    52,
+                    2,
+                )
+            } else {
+                (256, 256, 48, 1)
+            };
+            stages.push(Stage::Curves(read_tables(
+                data,
+                offset,
+                inputs,
+                input_entries,
+                precision,
+            )?));
+            let offset = offset + inputs * input_entries * precision;
+            let clut = read_clut(data, offset, vec![grid; inputs], outputs, precision)?;
+            let offset = offset + clut.values.len() * precision;
+            stages.push(Stage::Clut(clut));
+            stages.push(Stage::Curves(read_tables(
+                data,
+                offset,
+                outputs,
+                output_entries,
+                precision,
+            )?));
+        }
+        b"mAB " | b"mBA " => {
+            let [b, m, m_curves, clut, a] = [12, 16, 20, 24, 28]
+                .map(|offset| read_u32(data, offset).map(|value| value as usize));
+            let [b, m, m_curves, clut, a] = [b?, m?, m_curves?, clut?, a?];
+            let a_to_b = &signature == b"mAB ";
+            // channels at the matrix side of the CLUT
+            let matrix_channels = if a_to_b { outputs } else { inputs };
+            if b == 0
+                || (clut == 0 && inputs != outputs)
+                || ((m != 0 || m_curves != 0) && matrix_channels != 3)
+            {
+                return Err(format_error(String::from_utf8_lossy(&signature)));
+            }
+            let mut a_stages = Vec::new();
+            if a != 0 {
+                let count = if a_to_b { inputs } else { outputs };
+                a_stages.push(Stage::Curves(read_curve_sequence(data, a, count)?));
+            }
+            let mut clut_stages = Vec::new();
+            if clut != 0 {
+                let points = read_bytes::<16>(data, clut)?;
+                let grid = points[..inputs].iter().map(|&v| v as usize).collect();
+                let precision = *data.get(clut + 16).ok_or_else(|| format_error("clut"))? as usize;
+                if !(1..=2).contains(&precision) {
+                    return Err(format_error(format!("precision {}", precision)));
+                }
+                clut_stages.push(Stage::Clut(read_clut(
+                    data,
+                    clut + 20,
+                    grid,
+                    outputs,
+                    precision,
+                )?));
+            }
+            let mut matrix_stages = Vec::new();
+            if m_curves != 0 {
+                matrix_stages.push(Stage::Curves(read_curve_sequence(data, m_curves, 3)?));
+            }
+            if m != 0 {
+                let offset = [0, 1, 2].map(|i| read_s15_fixed16(data, m + 36 + i * 4));
+                let [o0, o1, o2] = offset;
+                matrix_stages.push(Stage::Matrix(read_matrix(data, m)?, [o0?, o1?, o2?]));
+            }
+            let b_count = if a_to_b { outputs } else { inputs };
+            let b_stages = vec![Stage::Curves(read_curve_sequence(data, b, b_count)?)];
+            // A => CLUT => M => matrix => B, the reverse for BtoA
+            if a_to_b {
+                stages.extend(a_stages);
+                stages.extend(clut_stages);
+                stages.extend(matrix_stages);
+                stages.extend(b_stages);
+            } else {
+                stages.extend(b_stages);
+                matrix_stages.reverse();
+                stages.extend(matrix_stages);
+                stages.extend(clut_stages);
+                stages.extend(a_stages);
+            }
+        }
+        signature => {
+            return Err(format_error(format!(
+                "lut type {}",
+                String::from_utf8_lossy(signature)
+            )))
+        }
+    }
+    Ok(Pipeline {
+        stages,
+        inputs,
+        outputs,
+        legacy_lab,
+    })
+}
+
+// textDescriptionType (ASCII part), multiLocalizedUnicodeType (first record) or textType
+fn read_text(data: &[u8]) -> Result<String> {
+    match &read_signature(data, 0)? {
+        b"desc" => {
+            let count = read_u32(data, 8)? as usize;
+            let bytes = data
+                .get(12..12 + count)
+                .ok_or_else(|| format_error("desc"))?;
+            Ok(String::from_utf8_lossy(bytes)
+                .trim_end_matches('\0')
+                .to_string())
+        }
+        b"mluc" => {
+            if read_u32(data, 8)? == 0 {
+                return Ok(String::new());
+            }
+            let length = read_u32(data, 20)? as usize;
+            let offset = read_u32(data, 24)? as usize;
+            let bytes = data
+                .get(offset..offset + length)
+                .ok_or_else(|| format_error("mluc"))?;
+            let units = bytes
+                .chunks_exact(2)
+                .map(|unit| u16::from_be_bytes([unit[0], unit[1]]))
+                .collect::<Vec<_>>();
+            Ok(String::from_utf16_lossy(&units)
+                .trim_end_matches('\0')
+                .to_string())
+        }
+        b"text" => Ok(String::from_utf8_lossy(&data[8..])
+            .trim_end_matches('\0')
+            .to_string()),
+        signature => Err(format_error(format!(
+            "text type {}",
+            String::from_utf8_lossy(signature)
+        ))),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::converter_builder::ColorConverterBuilder;
+    use crate::float_rgb::FloatRgbColor;
+
+    pub(crate) fn xyz_tag(xyz: [f64; 3]) -> Vec<u8> {
+        let mut tag = b"XYZ \0\0\0\0".to_vec();
+        for value in xyz {
+            tag.extend(s15_fixed16(value));
+        }
+        tag
+    }
+
+    pub(crate) fn para_tag(function_type: u16, parameters: &[f64]) -> Vec<u8> {
+        let mut tag = b"para\0\0\0\0".to_vec();
+        tag.extend(function_type.to_be_bytes());
+        tag.extend([0, 0]);
+        for value in parameters {
+            tag.extend(s15_fixed16(*value));
+        }
+        tag
+    }
+
+    pub(crate) fn curv_tag(values: &[u16]) -> Vec<u8> {
+        let mut tag = b"curv\0\0\0\0".to_vec();
+        tag.extend((values.len() as u32).to_be_bytes());
+        for value in values {
+            tag.extend(value.to_be_bytes());
+        }
+        tag
+    }
+
+    pub(crate) fn sf32_tag(m: [[f64; 3]; 3]) -> Vec<u8> {
+        let mut tag = b"sf32\0\0\0\0".to_vec();
+        for value in m.iter().flatten() {
+            tag.extend(s15_fixed16(*value));
+        }
+        tag
+    }
+
+    pub(crate) fn profile(
+        version: u8,
+        class: &[u8; 4],
+        color_space: &[u8; 4],
+        pcs: &[u8; 4],
+        tags: &[(&[u8; 4], Vec<u8>)],
+    ) -> Vec<u8> {
+        let mut header = vec![0_u8; HEADER_SIZE];
+        header[8] = version;
+        header[12..16].copy_from_slice(class);
+        header[16..20].copy_from_slice(color_space);
+        header[20..24].copy_from_slice(pcs);
+        header[36..40].copy_from_slice(b"acsp");
+        for (i, value) in [0.9642, 1_f64, 0.8249].into_iter().enumerate() {
+            header[68 + i * 4..72 + i * 4].copy_from_slice(&s15_fixed16(value));
+        }
+
+        let mut table = (tags.len() as u32).to_be_bytes().to_vec();
+        let mut body = Vec::new();
+        let start = HEADER_SIZE + 4 + tags.len() * TAG_ENTRY_SIZE;
+        for (signature, data) in tags {
+            table.extend(*signature);
+            table.extend(((start + body.len()) as u32).to_be_bytes());
+            table.extend((data.len() as u32).to_be_bytes());
+            body.extend(data);
+            body.resize(body.len().next_multiple_of(4), 0);
+        }
+
+        let mut data = header;
+        data.extend(table);
+        data.extend(body);
+        let size = (data.len() as u32).to_be_bytes();
+        data[..4].copy_from_slice(&size);
+        data
+    }
+
+    // sRGB as in the ICC v4 sRGB profile (D50 colorants with chad)
+    pub(crate) fn srgb_profile() -> Vec<u8> {
+        let chad = ChromaticAdaptation::new(WhitePoint::D65, WhitePoint::D50)
+            .unwrap()
+            .matrix();
+        let colorants = matrix::multiply(&chad, &RgbSpace::srgb().to_xyz_matrix());
+        let column = |i: usize| [colorants[0][i], colorants[1][i], colorants[2][i]];
+        let srgb = ParametricCurve::srgb();
+        let trc = para_tag(3, srgb.parameters());
+        profile(
+            4,
+            b"mntr",
+            b"RGB ",
+            b"XYZ ",
+            &[
+                (b"wtpt", xyz_tag([0.9642, 1_f64, 0.8249])),
+                (b"rXYZ", xyz_tag(column(0))),
+                (b"gXYZ", xyz_tag(column(1))),
+                (b"bXYZ", xyz_tag(column(2))),
+                (b"rTRC", trc.clone()),
+                (b"gTRC", trc.clone()),
+                (b"bTRC", trc),
+                (b"chad", sf32_tag(chad)),
+            ],
+        )
+    }
+
+    #[test]
+    fn icc_header_test() {
+        let profile = IccProfile::parse(&srgb_profile()).unwrap();
+        assert_eq!(profile.version(), (4, 0));
+        assert_eq!(profile.class(), IccProfileClass::Display);
+        assert_eq!(profile.color_space(), IccColorSpace::Rgb);
+        assert_eq!(profile.pcs(), IccColorSpace::Xyz);
+        assert_eq!(profile.rendering_intent(), RenderingIntent::Perceptual);
+        assert!((profile.illuminant().z() - 0.8249).abs() < 1e-4);
+        assert!(profile.has_tag(b"rTRC"));
+        assert!(!profile.has_tag(b"A2B0"));
+        assert!(profile.description().is_err());
+
+        let mut data = srgb_profile();
+        data[36] = b'x';
+        assert!(IccProfile::parse(&data).is_err());
+        let data = srgb_profile();
+        assert!(IccProfile::parse(&data[..200]).is_err());
+        assert!(IccProfile::parse(&data[..100]).is_err());
+    }
+
+    #[test]
+    fn icc_rgb_space_test() {
+        let space = IccProfile::parse(&srgb_profile())
+            .unwrap()
+            .to_rgb_space()
+            .unwrap();
+        let srgb = RgbSpace::srgb();
+        for (actual, expected) in [
+            (space.red(), srgb.red()),
+            (space.green(), srgb.green()),
+            (space.blue(), srgb.blue()),
+            (
+                space.white_point().chromaticity(),
+                srgb.white_point().chromaticity(),
+            ),
+        ] {
+            assert!((actual.x() - expected.x()).abs() < 1e-4, "{}", actual);
+            assert!((actual.y() - expected.y()).abs() < 1e-4, "{}", actual);
+        }
+        for value in [0.01, 0.5, 0.9] {
+            let color = FloatRgbColor::new(value, value, value);
+            let expected = srgb.decode(&color).r();
+            assert!((space.decode(&color).r() - expected).abs() < 1e-4);
+        }
+
+        // embedded profile as the source and the destination
+        let to_profile = ColorConverterBuilder::new()
+            .from_rgb()
+            .to_rgb_space(space.clone())
+            .build();
+        let from_profile = ColorConverterBuilder::new()
+            .from_rgb_space(space)
+            .to_rgb()
+            .build();
+        for color in [
+            RgbColor::new(255, 0, 0),
+            RgbColor::new(12, 200, 99),
+            RgbColor::new(128, 128, 128),
+        ] {
+            let encoded = to_profile.convert(&color).unwrap();
+            assert_eq!(from_profile.convert(&encoded).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn icc_curve_test() {
+        assert_eq!(
+            read_curve(&curv_tag(&[])).unwrap(),
+            TransferFunction::Linear
+        );
+        assert_eq!(
+            read_curve(&curv_tag(&[0x0233])).unwrap(),
+            TransferFunction::Gamma(2.19921875)
+        );
+        let table = read_curve(&curv_tag(&[0, 16384, 65535])).unwrap();
+        assert!((table.decode(0.25) - 0.125).abs() < 1e-4);
+        let gamma = read_curve(&para_tag(0, &[2.2])).unwrap();
+        assert!((gamma.decode(0.5) - 0.5_f64.powf(2.2)).abs() < 1e-4);
+
+        assert!(read_curve(&para_tag(5, &[2.2])).is_err());
+        assert!(read_curve(&para_tag(1, &[2.2])).is_err());
+        assert!(read_curve(&xyz_tag([0_f64; 3])).is_err());
+    }
+
+    #[test]
+    fn icc_gray_test() {
+        let data = profile(
+            2,
+            b"mntr",
+            b"GRAY",
+            b"XYZ ",
+            &[(b"kTRC", curv_tag(&[0x0233]))],
+        );
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.version(), (2, 0));
+        let space = profile.to_rgb_space().unwrap();
+        // no chad, the PCS white
+        assert!((space.white_point().xyz().z() - 0.8249).abs() < 1e-4);
+        let xyz = space.to_xyz(&FloatRgbColor::new(0.5, 0.5, 0.5));
+        assert!((xyz.y() - 0.5_f64.powf(2.19921875)).abs() < 1e-9);
+        assert!((xyz.x() / xyz.y() - 0.9642).abs() < 1e-4);
+
+        let data = profile_with_lab_pcs();
+        assert!(IccProfile::parse(&data).unwrap().to_rgb_space().is_err());
+    }
+
+    fn profile_with_lab_pcs() -> Vec<u8> {
+        profile(4, b"mntr", b"GRAY", b"Lab ", &[(b"kTRC", curv_tag(&[]))])
+    }
+
+    #[test]
+    fn icc_text_test() {
+        let mut desc = b"desc\0\0\0\0".to_vec();
+        desc.extend(5_u32.to_be_bytes());
+        desc.extend(b"sRGB\0");
+        assert_eq!(read_text(&desc).unwrap(), "sRGB");
+
+        let mut mluc = b"mluc\0\0\0\0".to_vec();
+        mluc.extend(1_u32.to_be_bytes());
+        mluc.extend(12_u32.to_be_bytes());
+        mluc.extend(b"enUS");
+        mluc.extend(6_u32.to_be_bytes());
+        mluc.extend(28_u32.to_be_bytes());
+        mluc.extend([0, b'P', 0, b'3', 0x30, 0x42]);
+        assert_eq!(read_text(&mluc).unwrap(), "P3\u{3042}");
+    }
+
+    // grid points of a CLUT, the first input varies slowest
+    fn clut_values(inputs: usize, grid: usize, f: &dyn Fn(&[f64]) -> Vec<f64>) -> Vec<u16> {
+        let mut values = Vec::new();
+        for index in 0..grid.pow(inputs as u32) {
+            let input = (0..inputs)
+                .map(|i| {
+                    let point = index / grid.pow((inputs - 1 - i) as u32) % grid;
+                    point as f64 / (grid - 1) as f64
+                })
+                .collect::<Vec<_>>();
+            values.extend(
+                f(&input)
+                    .into_iter()
+                    .map(|v| (v.clamp(0_f64, 1_f64) * 65535_f64).round() as u16),
+            );
+        }
+        values
+    }
+
+    // lut16Type with identity matrix and tables
+    fn mft2_tag(
+        inputs: usize,
+        outputs: usize,
+        grid: usize,
+        f: &dyn Fn(&[f64]) -> Vec<f64>,
+    ) -> Vec<u8> {
+        let mut tag = b"mft2\0\0\0\0".to_vec();
+        tag.extend([inputs as u8, outputs as u8, grid as u8, 0]);
+        for value in matrix::diagonal([1_f64; 3]).iter().flatten() {
+            tag.extend(s15_fixed16(*value));
+        }
+        tag.extend(2_u16.to_be_bytes());
+        tag.extend(2_u16.to_be_bytes());
+        let table = [0_u16, 65535];
+        let values = (0..inputs)
+            .flat_map(|_| table)
+            .chain(clut_values(inputs, grid, f))
+            .chain((0..outputs).flat_map(|_| table));
+        for value in values {
+            tag.extend(value.to_be_bytes());
+        }
+        tag
+    }
+
+    // lutAtoBType / lutBtoAType, empty elements are not written (offset 0)
+    #[allow(clippy::too_many_arguments)]
+    fn lut_ab_tag(
+        signature: &[u8; 4],
+        inputs: usize,
+        outputs: usize,
+        b: &[Vec<u8>],
+        matrix: Option<(Matrix3, [f64; 3])>,
+        m: &[Vec<u8>],
+        clut: Option<(usize, Vec<u16>)>,
+        a: &[Vec<u8>],
+    ) -> Vec<u8> {
+        let mut tag = signature.to_vec();
+        tag.extend([0, 0, 0, 0, inputs as u8, outputs as u8, 0, 0]);
+        tag.resize(32, 0);
+        let mut offsets = [0_u32; 5];
+        let mut append = |tag: &mut Vec<u8>, index: usize, element: Vec<u8>| {
+            if !element.is_empty() {
+                offsets[index] = tag.len() as u32;
+                tag.extend(element);
+                tag.resize(tag.len().next_multiple_of(4), 0);
+            }
+        };
+        let curves = |curves: &[Vec<u8>]| {
+            curves
+                .iter()
+                .flat_map(|curve| {
+                    let mut curve = curve.clone();
+                    curve.resize(curve.len().next_multiple_of(4), 0);
+                    curve
+                })
+                .collect::<Vec<_>>()
+        };
+        append(&mut tag, 0, curves(b));
+        let matrix = matrix
+            .map(|(m, offset)| {
+                m.iter()
+                    .flatten()
+                    .chain(offset.iter())
+                    .flat_map(|v| s15_fixed16(*v))
+                    .collect()
+            })
+            .unwrap_or_default();
+        append(&mut tag, 1, matrix);
+        append(&mut tag, 2, curves(m));
+        let clut = clut
+            .map(|(grid, values)| {
+                let mut data = vec![0_u8; 20];
+                data[..inputs].fill(grid as u8);
+                data[16] = 2;
+                data.extend(values.into_iter().flat_map(u16::to_be_bytes));
+                data
+            })
+            .unwrap_or_default();
+        append(&mut tag, 3, clut);
+        append(&mut tag, 4, curves(a));
+        for (i, offset) in offsets.into_iter().enumerate() {
+            tag[12 + i * 4..16 + i * 4].copy_from_slice(&offset.to_be_bytes());
+        }
+        tag
+    }
+
+    const PAPER: f64 = 0.9;
+    const INK_BLACK: f64 = 0.03;
+
+    // simple subtractive printer relative to the paper white (PCS XYZ)
+    fn printer_xyz(cmyk: &[f64]) -> [f64; 3] {
+        let colorants = DeviceTransform::from_rgb_space(&RgbSpace::linear_srgb()).unwrap();
+        let rgb = [0, 1, 2].map(|i| {
+            (INK_BLACK + (PAPER - INK_BLACK) * (1_f64 - cmyk[i]) * (1_f64 - cmyk[3])) / PAPER
+        });
+        colorants.to_pcs(&rgb).unwrap().to_array()
+    }
+
+    // maximum GCR separation of the printer
+    fn printer_cmyk(xyz: [f64; 3]) -> Vec<f64> {
+        let DeviceModel::Matrix { inverse, .. } =
+            DeviceTransform::from_rgb_space(&RgbSpace::linear_srgb())
+                .unwrap()
+                .model
+        else {
+            unreachable!()
+        };
+        let rgb = matrix::multiply_vector(&inverse, xyz)
+            .map(|v| ((v * PAPER - INK_BLACK) / (PAPER - INK_BLACK)).clamp(0_f64, 1_f64));
+        let k = 1_f64 - rgb.iter().fold(0_f64, |max, v| max.max(*v));
+        let mut cmyk = rgb
+            .map(|v| {
+                if k < 1_f64 {
+                    (1_f64 - v - k) / (1_f64 - k)
+                } else {
+                    0_f64
+                }
+            })
+            .to_vec();
+        cmyk.push(k);
+        cmyk
+    }
+
+    fn lab_values(xyz: [f64; 3], legacy: bool) -> Vec<f64> {
+        encode_pcs(xyz, IccColorSpace::Lab, legacy, &PCS_ILLUMINANT)
+    }
+
+    fn lab_xyz(values: &[f64], legacy: bool) -> [f64; 3] {
+        decode_pcs(values, IccColorSpace::Lab, legacy, &PCS_ILLUMINANT)
+    }
+
+    // Lab PCS printer, lut16 for perceptual and lutAtoB / lutBtoA for colorimetric
+    pub(crate) fn printer_profile() -> Vec<u8> {
+        let identity = |count: usize| vec![curv_tag(&[]); count];
+        let a2b = |input: &[f64]| lab_values(printer_xyz(input), false);
+        let b2a = |input: &[f64]| printer_cmyk(lab_xyz(input, false));
+        profile(
+            4,
+            b"prtr",
+            b"CMYK",
+            b"Lab ",
+            &[
+                (b"wtpt", xyz_tag([0.9642 * PAPER, PAPER, 0.8249 * PAPER])),
+                (
+                    b"A2B0",
+                    mft2_tag(4, 3, 9, &|input| lab_values(printer_xyz(input), true)),
+                ),
+                (
+                    b"B2A0",
+                    mft2_tag(3, 4, 17, &|input| printer_cmyk(lab_xyz(input, true))),
+                ),
+                (
+                    b"A2B1",
+                    lut_ab_tag(
+                        b"mAB ",
+                        4,
+                        3,
+                        &identity(3),
+                        None,
+                        &[],
+                        Some((9, clut_values(4, 9, &a2b))),
+                        &vec![para_tag(0, &[1_f64]); 4],
+                    ),
+                ),
+                (
+                    b"B2A1",
+                    lut_ab_tag(
+                        b"mBA ",
+                        3,
+                        4,
+                        &identity(3),
+                        None,
+                        &[],
+                        Some((17, clut_values(3, 17, &b2a))),
+                        &identity(4),
+                    ),
+                ),
+            ],
+        )
+    }
+
+    fn assert_xyz(actual: &XyzColor, expected: [f64; 3], tolerance: f64) {
+        for (a, e) in actual.to_array().into_iter().zip(expected) {
+            assert!((a - e).abs() < tolerance, "{} {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn icc_lab_test() {
+        let white = PCS_ILLUMINANT.to_array();
+        assert_eq!(xyz_to_lab(white, &PCS_ILLUMINANT), [100_f64, 0_f64, 0_f64]);
+        for xyz in [[0.2, 0.3, 0.1], [0.001, 0.002, 0.0005], [0.9, 0.8, 0.7]] {
+            let lab = xyz_to_lab(xyz, &PCS_ILLUMINANT);
+            let actual = lab_to_xyz(lab, &PCS_ILLUMINANT);
+            for i in 0..3 {
+                assert!((actual[i] - xyz[i]).abs() < 1e-12);
+            }
+        }
+        // legacy 16 bit Lab: L 100 = 0xff00
+        let values = lab_values(white, true);
+        assert!((values[0] - 65280_f64 / 65535_f64).abs() < 1e-12);
+        assert!((values[1] - 128_f64 / 255_f64 * 65280_f64 / 65535_f64).abs() < 1e-12);
+        // XYZ: 1.0 = 0x8000
+        let values = encode_pcs(white, IccColorSpace::Xyz, false, &PCS_ILLUMINANT);
+        assert!((values[1] - 32768_f64 / 65535_f64).abs() < 1e-12);
+
+        assert_eq!(IccColorSpace::Cmyk.channels(), 4);
+        assert_eq!(IccColorSpace::Other(*b"6CLR").channels(), 6);
+        assert_eq!(IccColorSpace::Other(*b"FCLR").channels(), 15);
+        assert_eq!(IccColorSpace::Other(*b"YCC ").channels(), 0);
+    }
+
+    #[test]
+    fn icc_lut_stages_test() {
+        // M curves (gamma 2) => matrix with offset, B identity
+        let data = lut_ab_tag(
+            b"mAB ",
+            3,
+            3,
+            &vec![curv_tag(&[]); 3],
+            Some((matrix::diagonal([0.5, 1_f64, 1_f64]), [0.1, 0_f64, 0_f64])),
+            &vec![para_tag(0, &[2_f64]); 3],
+            None,
+            &[],
+        );
+        let pipeline = read_lut(&data, false).unwrap();
+        let actual = pipeline.evaluate(&[0.6, 0.3, 0.2]);
+        for (a, e) in actual.iter().zip([0.28, 0.09, 0.04]) {
+            assert!((a - e).abs() < 1e-4, "{:?}", actual);
+        }
+        // lutBtoA applies B first
+        let mut data = data;
+        data[..4].copy_from_slice(b"mBA ");
+        let actual = read_lut(&data, false).unwrap().evaluate(&[0.6, 0.3, 0.2]);
+        for (a, e) in actual.iter().zip([0.4_f64.powi(2), 0.09, 0.04]) {
+            assert!((a - e).abs() < 1e-4, "{:?}", actual);
+        }
+
+        // lut8: the matrix is used only for XYZ input
+        let mut data = b"mft1\0\0\0\0".to_vec();
+        data.extend([3, 3, 2, 0]);
+        for value in matrix::diagonal([0.5, 1_f64, 1_f64]).iter().flatten() {
+            data.extend(s15_fixed16(*value));
+        }
+        let table = (0..=255).collect::<Vec<u8>>();
+        data.extend(table.repeat(3));
+        for values in clut_values(3, 2, &|input| input.to_vec()).chunks(3) {
+            data.extend(values.iter().map(|v| (v / 257) as u8));
+        }
+        data.extend(table.repeat(3));
+        let pipeline = read_lut(&data, false).unwrap();
+        assert!(!pipeline.legacy_lab);
+        let actual = pipeline.evaluate(&[0.6, 0.3, 0.2]);
+        assert!((actual[0] - 0.6).abs() < 1e-9);
+        let actual = read_lut(&data, true).unwrap().evaluate(&[0.6, 0.3, 0.2]);
+        assert!((actual[0] - 0.3).abs() < 1e-9);
+
+        assert!(read_lut(&data[..200], false).is_err());
+        data[10] = 1;
+        assert!(read_lut(&data, false).is_err());
+        assert!(read_lut(&curv_tag(&[]), false).is_err());
+    }
+
+    #[test]
+    fn icc_lut_test() {
+        let profile = IccProfile::parse(&printer_profile()).unwrap();
+        assert_eq!(profile.color_space().channels(), 4);
+        let paper = [0_f64; 4];
+        let white = PCS_ILLUMINANT.to_array();
+        for intent in [
+            RenderingIntent::Perceptual,
+            RenderingIntent::RelativeColorimetric,
+            RenderingIntent::Saturation,
+        ] {
+            let xyz = profile.device_to_pcs(&paper, intent).unwrap();
+            assert_xyz(&xyz, white, 1e-3);
+        }
+        let xyz = profile
+            .device_to_pcs(&paper, RenderingIntent::AbsoluteColorimetric)
+            .unwrap();
+        assert_xyz(&xyz, white.map(|v| v * PAPER), 1e-3);
+
+        // lut16 (A2B0) and lutAtoB (A2B1) at the grid points
+        for cmyk in [[0.5, 0.25, 0_f64, 0_f64], [0.75, 0_f64, 1_f64, 0.5]] {
+            let expected = printer_xyz(&cmyk);
+            for intent in [
+                RenderingIntent::Perceptual,
+                RenderingIntent::RelativeColorimetric,
+            ] {
+                let xyz = profile.device_to_pcs(&cmyk, intent).unwrap();
+                assert_xyz(&xyz, expected, 1e-3);
+            }
+        }
+
+        // colorimetric round trip through B2A
+        for cmyk in [[0.3, 0.6, 0.1, 0.2], [0.1, 0.1, 0.1, 0_f64]] {
+            for intent in [
+                RenderingIntent::Perceptual,
+                RenderingIntent::RelativeColorimetric,
+                RenderingIntent::AbsoluteColorimetric,
+            ] {
+                let xyz = profile.device_to_pcs(&cmyk, intent).unwrap();
+                let device = profile.pcs_to_device(&xyz, intent).unwrap();
+                assert_eq!(device.len(), 4);
+                let actual = profile.device_to_pcs(&device, intent).unwrap();
+                assert_xyz(&actual, xyz.to_array(), 1e-2);
+            }
+        }
+
+        let black = profile
+            .black_point(RenderingIntent::RelativeColorimetric)
+            .unwrap();
+        assert!((black.y() - INK_BLACK / PAPER).abs() < 2e-3, "{}", black);
+        assert!((black.x() / black.y() - 0.9642).abs() < 1e-4);
+        assert!(profile
+            .device_to_pcs(&[0_f64; 3], RenderingIntent::Perceptual)
+            .is_err());
+
+        // matrix / TRC without LUTs
+        let srgb = IccProfile::parse(&srgb_profile()).unwrap();
+        let xyz = srgb
+            .device_to_pcs(&[1_f64; 3], RenderingIntent::Perceptual)
+            .unwrap();
+        assert_xyz(&xyz, white, 1e-3);
+        let device = srgb
+            .pcs_to_device(&XyzColor::new(0.2, 0.3, 0.1), RenderingIntent::Saturation)
+            .unwrap();
+        let actual = srgb
+            .device_to_pcs(&device, RenderingIntent::Saturation)
+            .unwrap();
+        assert_xyz(&actual, [0.2, 0.3, 0.1], 1e-4);
+        assert!(srgb.black_point(RenderingIntent::Perceptual).unwrap().y() < 1e-9);
+    }
+
+    #[test]
+    fn icc_transform_test() {
+        let printer = IccProfile::parse(&printer_profile()).unwrap();
+        let srgb = RgbSpace::srgb();
+
+        let separation =
+            IccTransform::from_rgb_space(&srgb, &printer, RenderingIntent::RelativeColorimetric)
+                .unwrap();
+        assert_eq!(separation.input_channels(), 3);
+        assert_eq!(separation.output_channels(), 4);
+        let cmyk: CmykColor = separation
+            .convert(&RgbColor::from_rgba(255, 255, 255, 10))
+            .unwrap();
+        // the Lab grid has no node at a = b = 0
+        assert!(cmyk.total_ink() <= 2 && cmyk.a() == 10, "{}", cmyk);
+        let cmyk: CmykColor = separation.convert(&RgbColor::new(0, 0, 0)).unwrap();
+        assert_eq!(cmyk.k(), 100);
+        let cmyk: CmykColor = separation.convert(&RgbColor::new(0, 255, 255)).unwrap();
+        assert!(cmyk.c() > 90 && cmyk.m() < 10 && cmyk.y() < 10, "{}", cmyk);
+        let converted: Result<RgbColor> = separation.convert(&CmykColor::new(0, 0, 0, 0));
+        assert!(converted.is_err());
+
+        // soft proofing
+        let proof =
+            IccTransform::to_rgb_space(&printer, &srgb, RenderingIntent::RelativeColorimetric)
+                .unwrap();
+        let paper: RgbColor = proof.convert(&CmykColor::new(0, 0, 0, 0)).unwrap();
+        assert_eq!(paper, RgbColor::new(255, 255, 255));
+        let black: RgbColor = proof.convert(&CmykColor::new(0, 0, 0, 100)).unwrap();
+        assert_eq!(black.r(), black.b());
+        assert!((40..70).contains(&black.r()), "{}", black);
+
+        // black point compensation maps the ink black to the display black
+        let compensated = proof.clone().with_black_point_compensation(true);
+        assert!(compensated.black_point_compensation());
+        let black: RgbColor = compensated.convert(&CmykColor::new(0, 0, 0, 100)).unwrap();
+        assert!(black.r() <= 1 && black.g() <= 1, "{}", black);
+        let paper: RgbColor = compensated.convert(&CmykColor::new(0, 0, 0, 0)).unwrap();
+        assert_eq!(paper, RgbColor::new(255, 255, 255));
+        let gray: RgbColor = proof.convert(&CmykColor::new(0, 0, 0, 50)).unwrap();
+        let compensated_gray: RgbColor = compensated.convert(&CmykColor::new(0, 0, 0, 50)).unwrap();
+        assert!(compensated_gray.r() < gray.r());
+
+        // absolute colorimetric shows the paper white
+        let absolute =
+            IccTransform::to_rgb_space(&printer, &srgb, RenderingIntent::AbsoluteColorimetric)
+                .unwrap()
+                .with_black_point_compensation(true);
+        let paper: RgbColor = absolute.convert(&CmykColor::new(0, 0, 0, 0)).unwrap();
+        assert!((238..250).contains(&paper.r()), "{}", paper);
+        assert_eq!(paper.r(), paper.b());
+
+        // print to print and profile to profile
+        let reprint = IccTransform::new(&printer, &printer, RenderingIntent::Perceptual)
+            .unwrap()
+            .with_black_point_compensation(true);
+        let actual = reprint.transform(&[0.2, 0.4, 0.6, 0.1]).unwrap();
+        let expected = printer_xyz(&[0.2, 0.4, 0.6, 0.1]);
+        let xyz = printer
+            .device_to_pcs(&actual, RenderingIntent::Perceptual)
+            .unwrap();
+        assert_xyz(&xyz, expected, 1e-2);
+        let display = IccProfile::parse(&srgb_profile()).unwrap();
+        let identity = IccTransform::new(&display, &display, RenderingIntent::Perceptual).unwrap();
+        for color in [RgbColor::new(12, 200, 99), RgbColor::new(255, 0, 128)] {
+            let actual: RgbColor = identity.convert(&color).unwrap();
+            assert_eq!(actual, color);
+        }
+        assert!(identity.transform(&[0_f64; 4]).is_err());
+    }
+
+    fn tag_offset(data: &[u8], signature: &[u8; 4]) -> usize {
+        let profile = IccProfile::parse(data).unwrap();
+        let tag = profile
+            .tags
+            .iter()
+            .find(|tag| &tag.signature == signature)
+            .unwrap();
+        tag.offset
+    }
+
+    #[test]
+    fn icc_writer_test() {
+        let srgb = RgbSpace::srgb();
+        for (version, expected_version, white, tolerance) in [
+            (IccVersion::V4, (4, 3), PCS_ILLUMINANT, 1e-5),
+            (IccVersion::V2, (2, 4), srgb.white_point().xyz(), 1e-4),
+        ] {
+            let writer = IccProfileWriter::new()
+                .with_version(version)
+                .with_description("sRGB display")
+                .with_copyright("Public Domain");
+            assert_eq!(writer.version(), version);
+            let data = writer.to_bytes(&srgb);
+            assert_eq!(data.len() % 4, 0);
+            assert_eq!(read_u32(&data, 0).unwrap() as usize, data.len());
+            assert_eq!(&data[36..40], b"acsp");
+            // year of the creation date
+            assert!(read_u16(&data, 24).unwrap() >= 2024);
+
+            let profile = IccProfile::parse(&data).unwrap();
+            assert_eq!(profile.version(), expected_version);
+            assert_eq!(profile.class(), IccProfileClass::Display);
+            assert_eq!(profile.color_space(), IccColorSpace::Rgb);
+            assert_eq!(profile.pcs(), IccColorSpace::Xyz);
+            assert_eq!(profile.description().unwrap(), "sRGB display");
+            assert_eq!(
+                read_text(profile.tag(b"cprt").unwrap()).unwrap(),
+                "Public Domain"
+            );
+            let media_white = profile.media_white_point().unwrap();
+            assert!(
+                (media_white.z() - white.z()).abs() < 1e-4,
+                "{}",
+                media_white
+            );
+            // the TRCs are shared
+            assert_eq!(tag_offset(&data, b"rTRC"), tag_offset(&data, b"bTRC"));
+
+            let space = profile.to_rgb_space().unwrap();
+            for (actual, expected) in [
+                (space.red(), srgb.red()),
+                (space.green(), srgb.green()),
+                (space.blue(), srgb.blue()),
+                (
+                    space.white_point().chromaticity(),
+                    srgb.white_point().chromaticity(),
+                ),
+            ] {
+                assert!((actual.x() - expected.x()).abs() < 1e-4, "{}", actual);
+                assert!((actual.y() - expected.y()).abs() < 1e-4, "{}", actual);
+            }
+            for value in [0.01, 0.04, 0.5, 0.9] {
+                let color = FloatRgbColor::new(value, value, value);
+                let expected = srgb.decode(&color).r();
+                let actual = space.decode(&color).r();
+                assert!(
+                    (actual - expected).abs() < tolerance,
+                    "{} {}",
+                    value,
+                    actual
+                );
+            }
+        }
+
+        // the v2 ASCII description and the v4 Unicode description
+        let text = "\u{8868}\u{793a} P3";
+        let data = IccProfileWriter::new()
+            .with_description(text)
+            .to_bytes(&RgbSpace::display_p3());
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.description().unwrap(), text);
+        let data = IccProfileWriter::new()
+            .with_version(IccVersion::V2)
+            .with_description(text)
+            .to_bytes(&RgbSpace::display_p3());
+        let profile = IccProfile::parse(&data).unwrap();
+        assert_eq!(profile.description().unwrap(), "?? P3");
+    }
+
+    #[test]
+    fn icc_writer_curve_test() {
+        // measured primaries and white with per channel curves
+        let space = RgbSpace::new(
+            Chromaticity::new(0.655, 0.335),
+            Chromaticity::new(0.285, 0.615),
+            Chromaticity::new(0.152, 0.061),
+            WhitePoint::Custom(XyzColor::new(0.97, 1_f64, 1.02)),
+            TransferFunction::Linear,
+        )
+        .unwrap()
+        .with_transfers([
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Gamma(2.4),
+            TransferFunction::Pq,
+        ]);
+        let data = IccProfileWriter::new().to_bytes(&space);
+        assert_ne!(tag_offset(&data, b"rTRC"), tag_offset(&data, b"gTRC"));
+        let actual = IccProfile::parse(&data).unwrap().to_rgb_space().unwrap();
+        assert!((actual.red().x() - 0.655).abs() < 1e-4);
+        let white = actual.white_point().xyz();
+        assert!((white.z() / white.y() - 1.02).abs() < 1e-4, "{}", white);
+        // s15Fixed16Number
+        assert!(matches!(
+            &actual.transfers()[0],
+            TransferFunction::Parametric(curve)
+                if curve.function_type() == 0 && (curve.parameters()[0] - 2.2).abs() < 1e-4
+        ));
+        assert!(matches!(
+            actual.transfers()[2],
+            TransferFunction::Sampled(_)
+        ));
+        for value in [0.1, 0.5, 0.75] {
+            let expected = TransferFunction::Pq.decode(value);
+            assert!((actual.transfers()[2].decode(value) - expected).abs() < 1e-3);
+        }
+
+        // v2: u8Fixed8Number gamma and sampled curves
+        let data = IccProfileWriter::new()
+            .with_version(IccVersion::V2)
+            .to_bytes(&space);
+        let actual = IccProfile::parse(&data).unwrap().to_rgb_space().unwrap();
+        assert_eq!(
+            actual.transfers()[0],
+            TransferFunction::Gamma(563_f64 / 256_f64)
+        );
+        let sampled = curve_type(&TransferFunction::Srgb, IccVersion::V2);
+        assert_eq!(read_u32(&sampled, 8).unwrap() as usize, SAMPLED_CURVE_SIZE);
+        assert_eq!(
+            read_curve(&curve_type(&TransferFunction::Linear, IccVersion::V2)).unwrap(),
+            TransferFunction::Linear
+        );
+
+        // written profiles work with the transforms
+        let profile =
+            IccProfile::parse(&IccProfileWriter::new().to_bytes(&RgbSpace::display_p3())).unwrap();
+        let transform = IccTransform::from_rgb_space(
+            &RgbSpace::srgb(),
+            &profile,
+            RenderingIntent::RelativeColorimetric,
+        )
+        .unwrap();
+        let expected = ColorConverterBuilder::new()
+            .from_rgb()
+            .to_rgb_space(RgbSpace::display_p3())
+            .build();
+        for color in [RgbColor::new(255, 0, 0), RgbColor::new(12, 200, 99)] {
+            let actual: RgbColor = transform.convert(&color).unwrap();
+            let expected = expected.convert(&color).unwrap();
+            for (a, e) in [
+                (actual.r(), expected.r()),
+                (actual.g(), expected.g()),
+                (actual.b(), expected.b()),
+            ] {
+                assert!(
+                    (a as f64 / 255_f64 - e).abs() < 1_f64 / 255_f64,
+                    "{} {}",
+                    a,
+                    e
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn icc_date_time_test() {
+        assert_eq!(date_time(0), [1970, 1, 1, 0, 0, 0]);
+        assert_eq!(date_time(951_782_400), [2000, 2, 29, 0, 0, 0]);
+        assert_eq!(date_time(1_700_000_000), [2023, 11, 14, 22, 13, 20]);
+    }
+}
@@ -0,0 +1,283 @@
+use std::fmt::Display;
+
+use crate::float_rgb::FloatRgbColor;
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::transfer;
+use crate::transfer::TransferFunction;
+use crate::xyz::XyzColor;
+
+// ITU-R BT.2100
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum IctcpTransfer {
+    #[default]
+    Pq,
+    // scene light of a 1000 cd/m2 HLG display (system gamma 1.2)
+    Hlg,
+}
+
+pub const PQ_PEAK_LUMINANCE: f64 = 10000_f64;
+pub const HLG_PEAK_LUMINANCE: f64 = 1000_f64;
+
+// XYZ (D65) <=> linear ITU-R BT.2020
+const XYZ_TO_REC2020: Matrix3 = [
+    [1.7166511880, -0.3556707838, -0.2533662814],
+    [-0.6666843518, 1.6164812366, 0.0157685458],
+    [0.0176398574, -0.0427706133, 0.9421031212],
+];
+
+const REC2020_TO_XYZ: Matrix3 = [
+    [0.6369580483, 0.1446169036, 0.1688809752],
+    [0.2627002120, 0.6779980715, 0.0593017165],
+    [0.0000000000, 0.0280726930, 1.0609850577],
+];
+
+const REC2020_TO_LMS: Matrix3 = [
+    [1688_f64 / 4096_f64, 2146_f64 / 4096_f64, 262_f64 / 4096_f64],
+    [683_f64 / 4096_f64, 2951_f64 / 4096_f64, 462_f64 / 4096_f64],
+    [99_f64 / 4096_f64, 309_f64 / 4096_f64, 3688_f64 / 4096_f64],
+];
+
+const LMS_TO_REC2020: Matrix3 = [
+    [3.4366066943330784, -2.50645211865627, 0.06984542432319148],
+    [-0.7913295555989287, 1.9836004517922907, -0.192270896193362],
+    [
+        -0.025949899690592672,
+        -0.09891371471172644,
+        1.1248636144023192,
+    ],
+];
+
+const PQ_LMS_TO_ICTCP: Matrix3 = [
+    [2048_f64 / 4096_f64, 2048_f64 / 4096_f64, 0_f64],
+    [
+        6610_f64 / 4096_f64,
+        -13613_f64 / 4096_f64,
+        7003_f64 / 4096_f64,
+    ],
+    [
+        17933_f64 / 4096_f64,
+        -17390_f64 / 4096_f64,
+        -543_f64 / 4096_f64,
+    ],
+];
+
+const PQ_ICTCP_TO_LMS: Matrix3 = [
+    [1_f64, 0.008609037037932756, 0.11102962500302596],
+    [1_f64, -0.008609037037932756, -0.11102962500302596],
+    [1_f64, 0.5600313357106791, -0.32062717498731885],
+];
+
+const HLG_LMS_TO_ICTCP: Matrix3 = [
+    [2048_f64 / 4096_f64, 2048_f64 / 4096_f64, 0_f64],
+    [
+        3625_f64 / 4096_f64,
+        -7465_f64 / 4096_f64,
+        3840_f64 / 4096_f64,
+    ],
+    [
+        9500_f64 / 4096_f64,
+        -9212_f64 / 4096_f64,
+        -288_f64 / 4096_f64,
+    ],
+];
+
+const HLG_ICTCP_TO_LMS: Matrix3 = [
+    [1_f64, 0.015718580108730413, 0.2095810681164055],
+    [1_f64, -0.015718580108730413, -0.2095810681164055],
+    [1_f64, 1.0212710798422342, -0.6052744909924315],
+];
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct IctcpColor {
+    i: f64,
+    ct: f64,
+    cp: f64,
+    a: f64,
+}
+
+impl Display for IctcpColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "IctcpColor I = {} Ct = {} Cp = {} A = {}",
+            self.i, self.ct, self.cp, self.a
+        )
+    }
+}
+
+impl IctcpColor {
+    pub fn new(i: f64, ct: f64, cp: f64) -> Self {
+        Self::from_ictcpa(i, ct, cp, 1f64)
+    }
+
+    pub fn from_ictcpa(i: f64, ct: f64, cp: f64, a: f64) -> Self {
+        Self { i, ct, cp, a }
+    }
+
+    // absolute XYZ (D65, Y in cd/m2) of the display light
+    pub fn from_xyz(color: &XyzColor, transfer: IctcpTransfer) -> Self {
+        let rgb = matrix::multiply_vector(&XYZ_TO_REC2020, color.to_array());
+        let ictcp = match transfer {
+            IctcpTransfer::Pq => {
+                let lms = matrix::multiply_vector(&REC2020_TO_LMS, rgb);
+                let encoded =
+                    lms.map(|value| TransferFunction::Pq.encode(value / PQ_PEAK_LUMINANCE));
+                matrix::multiply_vector(&PQ_LMS_TO_ICTCP, encoded)
+            }
+            IctcpTransfer::Hlg => {
+                let display = FloatRgbColor::from_array(rgb, color.a());
+                let scene = transfer::hlg_inverse_ootf(&display, HLG_PEAK_LUMINANCE);
+                let lms = matrix::multiply_vector(&REC2020_TO_LMS, scene.to_array());
+                let encoded = lms.map(|value| TransferFunction::Hlg.encode(value));
+                matrix::multiply_vector(&HLG_LMS_TO_ICTCP, encoded)
+            }
+        };
+        Self::from_ictcpa(ictcp[0], ictcp[1], ictcp[2], color.a())
+    }
+
+    pub fn to_xyz(&self, transfer: IctcpTransfer) -> XyzColor {
+        let ictcp = [self.i, self.ct, self.cp];
+        let rgb = match transfer {
+            IctcpTransfer::Pq => {
+                let encoded = matrix::multiply_vector(&PQ_ICTCP_TO_LMS, ictcp);
+                let lms =
+                    encoded.map(|value| TransferFunction::Pq.decode(value) * PQ_PEAK_LUMINANCE);
+                matrix::multiply_vector(&LMS_TO_REC2020, lms)
+            }
+            IctcpTransfer::Hlg => {
+                let encoded = matrix::multiply_vector(&HLG_ICTCP_TO_LMS, ictcp);
+                let lms = encoded.map(|value| TransferFunction::Hlg.decode(value));
+                let scene = matrix::multiply_vector(&LMS_TO_REC2020, lms);
+                let display = transfer::hlg_ootf(
+                    &FloatRgbColor::from_array(scene, self.a),
+                    HLG_PEAK_LUMINANCE,
+                );
+                display.to_array()
+            }
+        };
+        XyzColor::from_array(matrix::multiply_vector(&REC2020_TO_XYZ, rgb), self.a)
+    }
+
+    pub fn i(&self) -> f64 {
+        self.i
+    }
+
+    pub fn ct(&self) -> f64 {
+        self.ct
+    }
+
+    pub fn cp(&self) -> f64 {
+        self.cp
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    // ΔE ITP (ITU-R BT.2124), 1 is about a just noticeable difference (PQ)
+    pub fn distance(&self, other: &IctcpColor) -> f64 {
+        720_f64
+            * ((self.i - other.i).powi(2)
+                + (0.5 * (self.ct - other.ct)).powi(2)
+                + (self.cp - other.cp).powi(2))
+            .sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::white_point::WhitePoint;
+
+    fn white(luminance: f64) -> XyzColor {
+        let xyz = WhitePoint::D65.xyz();
+        XyzColor::new(xyz.x() * luminance, luminance, xyz.z() * luminance)
+    }
+
+    #[test]
+    fn ictcp_matrix_test() {
+        for (m, inverse) in [
+            (REC2020_TO_LMS, LMS_TO_REC2020),
+            (PQ_LMS_TO_ICTCP, PQ_ICTCP_TO_LMS),
+            (HLG_LMS_TO_ICTCP, HLG_ICTCP_TO_LMS),
+            (XYZ_TO_REC2020, REC2020_TO_XYZ),
+        ] {
+            let identity = matrix::multiply(&m, &inverse);
+            for (i, row) in identity.iter().enumerate() {
+                for (j, value) in row.iter().enumerate() {
+                    let expected = if i == j { 1_f64 } else { 0_f64 };
+                    assert!((value - expected).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn ictcp_from_xyz_test() {
+        // achromatic colors have no chroma
+        for transfer in [IctcpTransfer::Pq, IctcpTransfer::Hlg] {
+            let ictcp = IctcpColor::from_xyz(&white(100_f64), transfer);
+            assert!(ictcp.ct().abs() < 1e-4, "{}", ictcp);
+            assert!(ictcp.cp().abs() < 1e-4, "{}", ictcp);
+        }
+
+        // colour-science 0.4 (BT.2020 RGB in cd/m2)
+        let rgb = [0.45620519, 0.03081071, 0.04091952];
+        let xyz = XyzColor::from_array(matrix::multiply_vector(&REC2020_TO_XYZ, rgb), 1_f64);
+        let ictcp = IctcpColor::from_xyz(&xyz, IctcpTransfer::Pq);
+        assert!((ictcp.i() - 0.0735136).abs() < 1e-6, "{}", ictcp);
+        assert!((ictcp.ct() - 0.0047525).abs() < 1e-6, "{}", ictcp);
+        assert!((ictcp.cp() - 0.0935159).abs() < 1e-6, "{}", ictcp);
+
+        // 100 cd/m2 is 0.508 in PQ, 1000 cd/m2 is 1.0 in HLG
+        let ictcp = IctcpColor::from_xyz(&white(100_f64), IctcpTransfer::Pq);
+        assert!((ictcp.i() - 0.508078).abs() < 1e-5);
+        let ictcp = IctcpColor::from_xyz(&white(1000_f64), IctcpTransfer::Hlg);
+        assert!((ictcp.i() - 1_f64).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ictcp_round_trip_test() {
+        for transfer in [IctcpTransfer::Pq, IctcpTransfer::Hlg] {
+            for xyz in [
+                XyzColor::new(41.24564, 21.26729, 1.93339),
+                XyzColor::new(0.5, 0.2, 0.8),
+                XyzColor::new(900_f64, 950_f64, 1000_f64),
+                XyzColor::new(0_f64, 0_f64, 0_f64),
+            ] {
+                let actual = IctcpColor::from_xyz(&xyz, transfer).to_xyz(transfer);
+                assert!(
+                    (actual.x() - xyz.x()).abs() < 1e-6,
+                    "{:?} {}",
+                    transfer,
+                    actual
+                );
+                assert!(
+                    (actual.y() - xyz.y()).abs() < 1e-6,
+                    "{:?} {}",
+                    transfer,
+                    actual
+                );
+                assert!(
+                    (actual.z() - xyz.z()).abs() < 1e-6,
+                    "{:?} {}",
+                    transfer,
+                    actual
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ictcp_distance_test() {
+        let a = IctcpColor::from_xyz(&white(100_f64), IctcpTransfer::Pq);
+        assert_eq!(a.distance(&a), 0_f64);
+
+        let b = IctcpColor::new(a.i() + 1_f64 / 720_f64, a.ct(), a.cp());
+        assert!((a.distance(&b) - 1_f64).abs() < 1e-9);
+        // Ct is halved
+        let c = IctcpColor::new(a.i(), a.ct() + 2_f64 / 720_f64, a.cp());
+        assert!((a.distance(&c) - 1_f64).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,226 @@
+use std::fmt::Display;
+
+use crate::chromaticity::Chromaticity;
+use crate::xyz::XyzColor;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Observer {
+    // 2°
+    Cie1931,
+    // 10°
+    Cie1964,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Illuminant {
+    A,
+    B,
+    C,
+    D50,
+    D55,
+    D65,
+    D75,
+    E,
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F11,
+    F12,
+    LedB1,
+    LedB2,
+    LedB3,
+    LedB4,
+    LedB5,
+    LedBh1,
+    LedRgb1,
+    LedV1,
+    LedV2,
+}
+
+impl Display for Illuminant {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LedB1 => write!(f, "LED-B1"),
+            Self::LedB2 => write!(f, "LED-B2"),
+            Self::LedB3 => write!(f, "LED-B3"),
+            Self::LedB4 => write!(f, "LED-B4"),
+            Self::LedB5 => write!(f, "LED-B5"),
+            Self::LedBh1 => write!(f, "LED-BH1"),
+            Self::LedRgb1 => write!(f, "LED-RGB1"),
+            Self::LedV1 => write!(f, "LED-V1"),
+            Self::LedV2 => write!(f, "LED-V2"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+const ILLUMINANTS: [Illuminant; 29] = [
+    Illuminant::A,
+    Illuminant::B,
+    Illuminant::C,
+    Illuminant::D50,
+    Illuminant::D55,
+    Illuminant::D65,
+    Illuminant::D75,
+    Illuminant::E,
+    Illuminant::F1,
+    Illuminant::F2,
+    Illuminant::F3,
+    Illuminant::F4,
+    Illuminant::F5,
+    Illuminant::F6,
+    Illuminant::F7,
+    Illuminant::F8,
+    Illuminant::F9,
+    Illuminant::F10,
+    Illuminant::F11,
+    Illuminant::F12,
+    Illuminant::LedB1,
+    Illuminant::LedB2,
+    Illuminant::LedB3,
+    Illuminant::LedB4,
+    Illuminant::LedB5,
+    Illuminant::LedBh1,
+    Illuminant::LedRgb1,
+    Illuminant::LedV1,
+    Illuminant::LedV2,
+];
+
+// CIE 15:2004, CIE 15:2018
+// LED series are only tabulated for the 2° observer.
+impl Illuminant {
+    pub fn as_enumerable() -> impl Iterator<Item = Illuminant> {
+        ILLUMINANTS.iter().copied()
+    }
+
+    pub fn total_number() -> usize {
+        ILLUMINANTS.len()
+    }
+
+    pub fn chromaticity(&self, observer: Observer) -> Option<Chromaticity> {
+        let xy = match observer {
+            Observer::Cie1931 => self.chromaticity_2(),
+            Observer::Cie1964 => self.chromaticity_10()?,
+        };
+        Some(Chromaticity::new(xy.0, xy.1))
+    }
+
+    pub fn white_point(&self, observer: Observer) -> Option<XyzColor> {
+        self.chromaticity(observer).map(|xy| xy.to_xyz(1_f64))
+    }
+
+    fn chromaticity_2(&self) -> (f64, f64) {
+        match self {
+            Self::A => (0.44757, 0.40745),
+            Self::B => (0.34842, 0.35161),
+            Self::C => (0.31006, 0.31616),
+            Self::D50 => (0.34567, 0.35850),
+            Self::D55 => (0.33242, 0.34743),
+            Self::D65 => (0.31271, 0.32902),
+            Self::D75 => (0.29902, 0.31485),
+            Self::E => (1_f64 / 3_f64, 1_f64 / 3_f64),
+            Self::F1 => (0.31310, 0.33727),
+            Self::F2 => (0.37208, 0.37529),
+            Self::F3 => (0.40910, 0.39430),
+            Self::F4 => (0.44018, 0.40329),
+            Self::F5 => (0.31379, 0.34531),
+            Self::F6 => (0.37790, 0.38835),
+            Self::F7 => (0.31292, 0.32933),
+            Self::F8 => (0.34588, 0.35875),
+            Self::F9 => (0.37417, 0.37281),
+            Self::F10 => (0.34609, 0.35986),
+            Self::F11 => (0.38052, 0.37713),
+            Self::F12 => (0.43695, 0.40441),
+            Self::LedB1 => (0.4560, 0.4078),
+            Self::LedB2 => (0.4357, 0.4012),
+            Self::LedB3 => (0.3756, 0.3723),
+            Self::LedB4 => (0.3422, 0.3502),
+            Self::LedB5 => (0.3118, 0.3236),
+            Self::LedBh1 => (0.4474, 0.4066),
+            Self::LedRgb1 => (0.4557, 0.4211),
+            Self::LedV1 => (0.4560, 0.4548),
+            Self::LedV2 => (0.3781, 0.3775),
+        }
+    }
+
+    fn chromaticity_10(&self) -> Option<(f64, f64)> {
+        match self {
+            Self::A => Some((0.45117, 0.40594)),
+            Self::B => Some((0.34980, 0.35270)),
+            Self::C => Some((0.31039, 0.31905)),
+            Self::D50 => Some((0.34773, 0.35952)),
+            Self::D55 => Some((0.33411, 0.34877)),
+            Self::D65 => Some((0.31382, 0.33100)),
+            Self::D75 => Some((0.29968, 0.31740)),
+            Self::E => Some((1_f64 / 3_f64, 1_f64 / 3_f64)),
+            Self::F1 => Some((0.31811, 0.33559)),
+            Self::F2 => Some((0.37925, 0.36733)),
+            Self::F3 => Some((0.41761, 0.38324)),
+            Self::F4 => Some((0.44920, 0.39074)),
+            Self::F5 => Some((0.31975, 0.34246)),
+            Self::F6 => Some((0.38660, 0.37847)),
+            Self::F7 => Some((0.31569, 0.32960)),
+            Self::F8 => Some((0.34902, 0.35939)),
+            Self::F9 => Some((0.37829, 0.37045)),
+            Self::F10 => Some((0.35090, 0.35444)),
+            Self::F11 => Some((0.38541, 0.37123)),
+            Self::F12 => Some((0.44256, 0.39717)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn illuminant_chromaticity_test() {
+        assert_eq!(
+            Illuminant::D65.chromaticity(Observer::Cie1931),
+            Some(Chromaticity::new(0.31271, 0.32902))
+        );
+        assert_eq!(
+            Illuminant::D65.chromaticity(Observer::Cie1964),
+            Some(Chromaticity::new(0.31382, 0.33100))
+        );
+        assert_eq!(Illuminant::LedB1.chromaticity(Observer::Cie1964), None);
+    }
+
+    #[test]
+    fn illuminant_white_point_test() {
+        let white = Illuminant::D50.white_point(Observer::Cie1931).unwrap();
+        assert!((white.x() - 0.96421).abs() < 1e-4);
+        assert_eq!(white.y(), 1_f64);
+        assert!((white.z() - 0.82519).abs() < 1e-4);
+
+        let e = Illuminant::E.white_point(Observer::Cie1964).unwrap();
+        assert!((e.x() - 1_f64).abs() < 1e-12);
+        assert!((e.z() - 1_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn illuminant_cie1931_catalog_test() {
+        for illuminant in Illuminant::as_enumerable() {
+            assert!(illuminant.chromaticity(Observer::Cie1931).is_some());
+        }
+        assert_eq!(
+            Illuminant::total_number(),
+            Illuminant::as_enumerable().count()
+        );
+    }
+
+    #[test]
+    fn illuminant_name_test() {
+        assert_eq!(Illuminant::D65.to_string(), "D65");
+        assert_eq!(Illuminant::F11.to_string(), "F11");
+        assert_eq!(Illuminant::LedBh1.to_string(), "LED-BH1");
+    }
+}
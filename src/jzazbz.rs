@@ -0,0 +1,262 @@
+use std::fmt::Display;
+
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::xyz::XyzColor;
+
+// M. Safdar et al., "Perceptually uniform color space for image signals including high dynamic
+// range and wide gamut", Optics Express 2017
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const C1: f64 = 3424_f64 / 4096_f64;
+const C2: f64 = 2413_f64 / 128_f64;
+const C3: f64 = 2392_f64 / 128_f64;
+const N: f64 = 2610_f64 / 16384_f64;
+const P: f64 = 1.7 * 2523_f64 / 32_f64;
+const D: f64 = -0.56;
+const D0: f64 = 1.6295499532821566e-11;
+const PEAK_LUMINANCE: f64 = 10000_f64;
+
+const XYZ_TO_LMS: Matrix3 = [
+    [0.41478972, 0.579999, 0.0146480],
+    [-0.2015100, 1.120649, 0.0531008],
+    [-0.0166008, 0.264800, 0.6684799],
+];
+
+const LMS_TO_XYZ: Matrix3 = [
+    [1.9242264357876067, -1.0047923125953655, 0.03765140403061801],
+    [
+        0.35031676209499907,
+        0.7264811939316552,
+        -0.06538442294808502,
+    ],
+    [-0.09098281098284758, -0.312728290523074, 1.5227665613052606],
+];
+
+const LMS_TO_IAB: Matrix3 = [
+    [0.5, 0.5, 0_f64],
+    [3.524000, -4.066708, 0.542708],
+    [0.199076, 1.096799, -1.295875],
+];
+
+const IAB_TO_LMS: Matrix3 = [
+    [1_f64, 0.1386050432715393, 0.058047316156118876],
+    [1_f64, -0.1386050432715393, -0.058047316156118876],
+    [1_f64, -0.09601924202631895, -0.811891896056039],
+];
+
+fn perceptual_quantize(value: f64) -> f64 {
+    let y = (value.max(0_f64) / PEAK_LUMINANCE).powf(N);
+    ((C1 + C2 * y) / (1_f64 + C3 * y)).powf(P)
+}
+
+fn perceptual_dequantize(value: f64) -> f64 {
+    let e = value.max(0_f64).powf(1_f64 / P);
+    PEAK_LUMINANCE * ((C1 - e) / (C3 * e - C2)).max(0_f64).powf(1_f64 / N)
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct JzazbzColor {
+    jz: f64,
+    az: f64,
+    bz: f64,
+    a: f64,
+}
+
+impl Display for JzazbzColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "JzazbzColor Jz = {} az = {} bz = {} A = {}",
+            self.jz, self.az, self.bz, self.a
+        )
+    }
+}
+
+impl JzazbzColor {
+    pub fn new(jz: f64, az: f64, bz: f64) -> Self {
+        Self::from_jzazbza(jz, az, bz, 1f64)
+    }
+
+    pub fn from_jzazbza(jz: f64, az: f64, bz: f64, a: f64) -> Self {
+        Self { jz, az, bz, a }
+    }
+
+    // absolute XYZ (D65, Y in cd/m2)
+    pub fn from_xyz(color: &XyzColor) -> Self {
+        let x = B * color.x() - (B - 1_f64) * color.z();
+        let y = G * color.y() - (G - 1_f64) * color.x();
+        let lms = matrix::multiply_vector(&XYZ_TO_LMS, [x, y, color.z()]);
+        let [iz, az, bz] = matrix::multiply_vector(&LMS_TO_IAB, lms.map(perceptual_quantize));
+        let jz = (1_f64 + D) * iz / (1_f64 + D * iz) - D0;
+        Self::from_jzazbza(jz, az, bz, color.a())
+    }
+
+    pub fn to_xyz(&self) -> XyzColor {
+        let jz = self.jz + D0;
+        let iz = jz / (1_f64 + D - D * jz);
+        let lms = matrix::multiply_vector(&IAB_TO_LMS, [iz, self.az, self.bz]);
+        let [x, y, z] = matrix::multiply_vector(&LMS_TO_XYZ, lms.map(perceptual_dequantize));
+        let x = (x + (B - 1_f64) * z) / B;
+        let y = (y + (G - 1_f64) * x) / G;
+        XyzColor::from_xyza(x, y, z, self.a)
+    }
+
+    pub fn jz(&self) -> f64 {
+        self.jz
+    }
+
+    pub fn az(&self) -> f64 {
+        self.az
+    }
+
+    pub fn bz(&self) -> f64 {
+        self.bz
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    // ΔEz
+    pub fn distance(&self, other: &JzazbzColor) -> f64 {
+        JzczhzColor::from_jzazbz(self).distance(&JzczhzColor::from_jzazbz(other))
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct JzczhzColor {
+    jz: f64,
+    cz: f64,
+    hz: f64,
+    a: f64,
+}
+
+impl Display for JzczhzColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "JzczhzColor Jz = {} Cz = {} hz = {} A = {}",
+            self.jz, self.cz, self.hz, self.a
+        )
+    }
+}
+
+impl JzczhzColor {
+    pub fn new(jz: f64, cz: f64, hz: f64) -> Self {
+        Self::from_jczha(jz, cz, hz, 1f64)
+    }
+
+    pub fn from_jczha(jz: f64, cz: f64, hz: f64, a: f64) -> Self {
+        Self {
+            jz,
+            cz: cz.max(0f64),
+            hz: hz.rem_euclid(360f64),
+            a,
+        }
+    }
+
+    pub fn from_jzazbz(color: &JzazbzColor) -> Self {
+        let cz = color.az().hypot(color.bz());
+        // hue is meaningless for achromatic colors
+        let hz = if cz < 1e-12 {
+            0_f64
+        } else {
+            color.bz().atan2(color.az()).to_degrees()
+        };
+        Self::from_jczha(color.jz(), cz, hz, color.a())
+    }
+
+    pub fn to_jzazbz(&self) -> JzazbzColor {
+        let h = self.hz.to_radians();
+        JzazbzColor::from_jzazbza(self.jz, self.cz * h.cos(), self.cz * h.sin(), self.a)
+    }
+
+    pub fn jz(&self) -> f64 {
+        self.jz
+    }
+
+    pub fn cz(&self) -> f64 {
+        self.cz
+    }
+
+    pub fn hz(&self) -> f64 {
+        self.hz
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    // ΔEz
+    pub fn distance(&self, other: &JzczhzColor) -> f64 {
+        let dh =
+            2_f64 * (self.cz * other.cz).sqrt() * ((self.hz - other.hz).to_radians() / 2_f64).sin();
+        ((self.jz - other.jz).powi(2) + (self.cz - other.cz).powi(2) + dh.powi(2)).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jzazbz_matrix_test() {
+        for (m, inverse) in [(XYZ_TO_LMS, LMS_TO_XYZ), (LMS_TO_IAB, IAB_TO_LMS)] {
+            let identity = matrix::multiply(&m, &inverse);
+            for (i, row) in identity.iter().enumerate() {
+                for (j, value) in row.iter().enumerate() {
+                    let expected = if i == j { 1_f64 } else { 0_f64 };
+                    assert!((value - expected).abs() < 1e-9);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn jzazbz_from_xyz_test() {
+        // colour-science 0.4
+        let jzazbz = JzazbzColor::from_xyz(&XyzColor::new(0.20654008, 0.12197225, 0.05136952));
+        assert!((jzazbz.jz() - 0.0053504).abs() < 1e-6, "{}", jzazbz);
+        assert!((jzazbz.az() - 0.0092430).abs() < 1e-6, "{}", jzazbz);
+        assert!((jzazbz.bz() - 0.0052600).abs() < 1e-6, "{}", jzazbz);
+
+        let black = JzazbzColor::from_xyz(&XyzColor::new(0_f64, 0_f64, 0_f64));
+        assert!(black.jz().abs() < 1e-12);
+    }
+
+    #[test]
+    fn jzazbz_round_trip_test() {
+        for xyz in [
+            XyzColor::new(0.20654008, 0.12197225, 0.05136952),
+            XyzColor::new(95.047, 100_f64, 108.883),
+            XyzColor::new(4000_f64, 3000_f64, 500_f64),
+        ] {
+            let actual = JzazbzColor::from_xyz(&xyz).to_xyz();
+            assert!((actual.x() - xyz.x()).abs() < 1e-6 * xyz.y(), "{}", actual);
+            assert!((actual.y() - xyz.y()).abs() < 1e-6 * xyz.y(), "{}", actual);
+            assert!((actual.z() - xyz.z()).abs() < 1e-6 * xyz.y(), "{}", actual);
+        }
+    }
+
+    #[test]
+    fn jzczhz_test() {
+        let jzazbz = JzazbzColor::new(0.1, 0.01, -0.01);
+        let jzczhz = JzczhzColor::from_jzazbz(&jzazbz);
+        assert!((jzczhz.cz() - 0.02_f64.sqrt() / 10_f64).abs() < 1e-12);
+        assert!((jzczhz.hz() - 315_f64).abs() < 1e-9);
+        let actual = jzczhz.to_jzazbz();
+        assert!((actual.az() - 0.01).abs() < 1e-12);
+        assert!((actual.bz() + 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn jzazbz_distance_test() {
+        let a = JzazbzColor::new(0.1, 0.01, 0_f64);
+        assert_eq!(a.distance(&a), 0_f64);
+        // same as the euclidean distance
+        let b = JzazbzColor::new(0.12, 0_f64, 0.01);
+        let euclidean = (0.02_f64.powi(2) + 0.01_f64.powi(2) + 0.01_f64.powi(2)).sqrt();
+        assert!((a.distance(&b) - euclidean).abs() < 1e-12);
+    }
+}
@@ -1,23 +1,109 @@
+mod cmf;
+mod matrix;
 mod utils;
 
+pub mod chromaticity;
+pub mod cmyk;
 pub mod colors;
+pub mod curves;
+pub mod float_rgb;
 pub mod hsl;
+pub mod hsluv;
 pub mod hsv;
+pub mod hwb;
+pub mod icc;
+pub mod ictcp;
+pub mod jzazbz;
+pub mod lut;
+pub mod luv;
+pub mod mixing;
+pub mod oklab;
 pub mod rgb;
+pub mod rgb_space;
+pub mod spectral;
 pub mod srgb;
+pub mod xyy;
+pub mod xyz;
+pub mod ycbcr;
+pub mod ycocg;
+
+pub mod adaptation;
+pub mod cct;
+pub mod gamut;
+pub mod illuminant;
+pub mod tone_mapping;
+pub mod transfer;
+pub mod upsampling;
+pub mod white_point;
 
 pub mod converter;
 pub mod converter_builder;
 pub mod errors;
 
+pub use adaptation::{AdaptationMethod, ChromaticAdaptation};
+pub use cct::{CctMethod, ColorTemperature};
+pub use chromaticity::{Chromaticity, UvChromaticity, UvPrimeChromaticity};
+pub use cmyk::{BlackGeneration, CmykColor, CmykSeparation};
 pub use colors::Colors;
+pub use converter::LinearRgbToRgbConverter;
+pub use converter::{CmykToCmykConverter, CmykToHslConverter, CmykToHsvConverter};
+pub use converter::{CmykToHwbConverter, CmykToRgbConverter};
+pub use converter::{ColorTemperatureToRgbConverter, RgbToColorTemperatureConverter};
+pub use converter::{HpluvToRgbConverter, HsluvToRgbConverter};
+pub use converter::{
+    HslToCmykConverter, HsvToCmykConverter, HwbToCmykConverter, RgbToCmykConverter,
+};
 pub use converter::{HslToHslConverter, HslToHsvConverter, HslToRgbConverter};
+pub use converter::{HslToHwbConverter, HsvToHwbConverter, RgbToHwbConverter};
 pub use converter::{HsvToHslConverter, HsvToHsvConverter, HsvToRgbConverter};
+pub use converter::{HwbToHslConverter, HwbToHsvConverter, HwbToHwbConverter, HwbToRgbConverter};
+pub use converter::{IctcpToRgbConverter, RgbToIctcpConverter};
+pub use converter::{JzazbzToRgbConverter, JzczhzToRgbConverter};
+pub use converter::{LchuvToRgbConverter, LuvToRgbConverter};
+pub use converter::{OklabToRgbConverter, OklchToRgbConverter};
+pub use converter::{ReflectanceToRgbConverter, RgbToReflectanceConverter};
+pub use converter::{RgbSpaceToRgbConverter, RgbSpaceToRgbSpaceConverter, RgbToRgbSpaceConverter};
+pub use converter::{RgbSpaceToXyzConverter, XyzToRgbSpaceConverter};
+pub use converter::{RgbToHpluvConverter, RgbToHsluvConverter};
 pub use converter::{RgbToHslConverter, RgbToHsvConverter, RgbToHtmlConverter, RgbToRgbConverter};
+pub use converter::{RgbToJzazbzConverter, RgbToJzczhzConverter};
+pub use converter::{RgbToLchuvConverter, RgbToLuvConverter};
+pub use converter::{RgbToOklabConverter, RgbToOklchConverter};
+pub use converter::{RgbToXyYConverter, XyYToRgbConverter};
+pub use converter::{RgbToXyzConverter, XyzToRgbConverter};
+pub use converter::{RgbToYCbCrConverter, YCbCrToRgbConverter};
+pub use converter::{RgbToYCoCgConverter, RgbToYCoCgRConverter};
+pub use converter::{YCoCgRToRgbConverter, YCoCgToRgbConverter};
 pub use converter_builder::{
     ColorConverterBuilder, ColorConverterFromBuilder, ColorConverterFromToBuilder,
 };
+pub use converter_builder::{RgbSpaceConverterFromBuilder, RgbSpaceConverterFromToBuilder};
+pub use converter_builder::{YCbCrConverterFromBuilder, YCbCrConverterFromToBuilder};
+pub use curves::{Curve, Curves, Levels};
 pub use errors::{Error, ErrorCategory};
+pub use float_rgb::FloatRgbColor;
+pub use gamut::GamutMapping;
 pub use hsl::HslColor;
+pub use hsluv::{HpluvColor, HsluvColor};
 pub use hsv::HsvColor;
+pub use hwb::HwbColor;
+pub use icc::{IccColorSpace, IccProfile, IccProfileClass, IccProfileWriter, IccTransform};
+pub use icc::{IccVersion, RenderingIntent};
+pub use ictcp::{IctcpColor, IctcpTransfer};
+pub use illuminant::{Illuminant, Observer};
+pub use jzazbz::{JzazbzColor, JzczhzColor};
+pub use lut::{hald_identity, Lut1D, Lut3D, LutInterpolation};
+pub use luv::{LchuvColor, LuvColor};
+pub use mixing::PigmentMixer;
+pub use oklab::{OklabColor, OklchColor};
 pub use rgb::RgbColor;
+pub use rgb_space::RgbSpace;
+pub use spectral::SampledSpectrum;
+pub use tone_mapping::ToneMapping;
+pub use transfer::{ParametricCurve, SampledCurve, TransferFunction};
+pub use upsampling::SpectralUpsampling;
+pub use white_point::WhitePoint;
+pub use xyy::XyYColor;
+pub use xyz::XyzColor;
+pub use ycbcr::{YCbCrColor, YCbCrFormat, YCbCrMatrix, YCbCrRange};
+pub use ycocg::{YCoCgColor, YCoCgRColor};
@@ -0,0 +1,938 @@
+use std::fs;
+use std::path::Path;
+
+use crate::converter::{slice_conversion, Conversion};
+use crate::errors;
+use crate::errors::Result;
+use crate::float_rgb::FloatRgbColor;
+use crate::rgb::RgbColor;
+use crate::utils;
+
+const DEFAULT_DOMAIN_MIN: [f64; 3] = [0_f64; 3];
+const DEFAULT_DOMAIN_MAX: [f64; 3] = [1_f64; 3];
+// .cube readers are required to support at least 2 - 256
+const MAX_LUT_3D_SIZE: usize = 256;
+const MAX_LUT_1D_SIZE: usize = 65536;
+// level 16 is a 4096 x 4096 image (cube size 256)
+const MAX_HALD_LEVEL: usize = 16;
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum LutInterpolation {
+    Trilinear,
+    #[default]
+    Tetrahedral,
+}
+
+// the table is ordered with red changing fastest (same as .cube)
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lut3D {
+    size: usize,
+    table: Vec<[f64; 3]>,
+    domain_min: [f64; 3],
+    domain_max: [f64; 3],
+    interpolation: LutInterpolation,
+    title: String,
+}
+
+impl Lut3D {
+    pub fn new(size: usize, values: Vec<FloatRgbColor>) -> Result<Self> {
+        validate_size(size)?;
+        if values.len() != size.pow(3) {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("size {} values {}", size, values.len()),
+            ));
+        }
+        Ok(Self::from_table(
+            size,
+            values.into_iter().map(FloatRgbColor::to_array).collect(),
+        ))
+    }
+
+    pub fn identity(size: usize) -> Result<Self> {
+        Self::from_fn(size, |color| Ok(*color))
+    }
+
+    // samples the function at the grid points of the default domain (0 - 1)
+    pub fn from_fn(
+        size: usize,
+        f: impl Fn(&FloatRgbColor) -> Result<FloatRgbColor>,
+    ) -> Result<Self> {
+        validate_size(size)?;
+        let scale = (size - 1) as f64;
+        let mut table = Vec::with_capacity(size.pow(3));
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    let input =
+                        FloatRgbColor::new(r as f64 / scale, g as f64 / scale, b as f64 / scale);
+                    table.push(f(&input)?.to_array());
+                }
+            }
+        }
+        Ok(Self::from_table(size, table))
+    }
+
+    pub fn from_converter<C>(size: usize, converter: &C) -> Result<Self>
+    where
+        C: for<'a> Conversion<&'a FloatRgbColor, FloatRgbColor>,
+    {
+        Self::from_fn(size, |color| converter.convert(color))
+    }
+
+    // the grid points are rounded to 8 bit before the conversion,
+    // sizes of 255 / n + 1 (2, 4, 6, 16, 18, 52, 86, 256) are exact
+    pub fn from_rgb_converter<C>(size: usize, converter: &C) -> Result<Self>
+    where
+        C: for<'a> Conversion<&'a RgbColor, RgbColor>,
+    {
+        Self::from_fn(size, |color| {
+            let input = RgbColor::new(
+                utils::float_to_u8(color.r()),
+                utils::float_to_u8(color.g()),
+                utils::float_to_u8(color.b()),
+            );
+            Ok(to_float(&converter.convert(&input)?))
+        })
+    }
+
+    fn from_table(size: usize, table: Vec<[f64; 3]>) -> Self {
+        Self {
+            size,
+            table,
+            domain_min: DEFAULT_DOMAIN_MIN,
+            domain_max: DEFAULT_DOMAIN_MAX,
+            interpolation: LutInterpolation::default(),
+            title: String::new(),
+        }
+    }
+
+    // Hald CLUT image of level N (N^3 x N^3 pixels, cube size N^2), RGB8 pixels in row order
+    pub fn from_hald(level: usize, pixels: &[u8]) -> Result<Self> {
+        let size = hald_size(level)?;
+        if pixels.len() != 3 * size.pow(3) {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("level {} pixels {}", level, pixels.len()),
+            ));
+        }
+        let table = pixels
+            .chunks_exact(3)
+            .map(|pixel| [0, 1, 2].map(|i| pixel[i] as f64 / 255_f64))
+            .collect();
+        Ok(Self::from_table(size, table))
+    }
+
+    // RGB8 Hald CLUT image, the cube size must be a square (4, 9, 16, ..., 256)
+    pub fn to_hald(&self) -> Result<Vec<u8>> {
+        let level = (self.size as f64).sqrt().round() as usize;
+        if level * level != self.size {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("size {}", self.size),
+            ));
+        }
+        Ok(self
+            .table
+            .iter()
+            .flat_map(|rgb| rgb.map(utils::float_to_u8))
+            .collect())
+    }
+
+    pub fn with_interpolation(self, interpolation: LutInterpolation) -> Self {
+        Self {
+            interpolation,
+            ..self
+        }
+    }
+
+    pub fn with_domain(self, domain_min: [f64; 3], domain_max: [f64; 3]) -> Self {
+        Self {
+            domain_min,
+            domain_max,
+            ..self
+        }
+    }
+
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..self
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn interpolation(&self) -> LutInterpolation {
+        self.interpolation
+    }
+
+    pub fn domain_min(&self) -> [f64; 3] {
+        self.domain_min
+    }
+
+    pub fn domain_max(&self) -> [f64; 3] {
+        self.domain_max
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    // red changes fastest
+    pub fn values(&self) -> Vec<FloatRgbColor> {
+        self.table
+            .iter()
+            .map(|rgb| FloatRgbColor::from_array(*rgb, 1_f64))
+            .collect()
+    }
+
+    // input outside of the domain is clamped, alpha is kept
+    pub fn apply(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        let mut index = [0_usize; 3];
+        let mut fraction = [0_f64; 3];
+        for (i, value) in color.to_array().into_iter().enumerate() {
+            (index[i], fraction[i]) =
+                grid_position(value, self.domain_min[i], self.domain_max[i], self.size);
+        }
+
+        let corner = |r: usize, g: usize, b: usize| {
+            self.table
+                [(index[0] + r) + (index[1] + g) * self.size + (index[2] + b) * self.size.pow(2)]
+        };
+        let rgb = match self.interpolation {
+            LutInterpolation::Trilinear => trilinear(corner, fraction),
+            LutInterpolation::Tetrahedral => tetrahedral(corner, fraction),
+        };
+        FloatRgbColor::from_array(rgb, color.a())
+    }
+
+    // Adobe / Resolve .cube format
+    pub fn from_cube(text: &str) -> Result<Self> {
+        let cube = parse_cube(text, CubeDimension::Three)?;
+        Ok(Self::from_table(cube.size, cube.table)
+            .with_domain(cube.domain_min, cube.domain_max)
+            .with_title(cube.title))
+    }
+
+    pub fn to_cube(&self) -> String {
+        format_cube(
+            CubeDimension::Three,
+            self.size,
+            &self.title,
+            self.domain_min,
+            self.domain_max,
+            &self.table,
+        )
+    }
+
+    pub fn read_cube(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_cube(&read_text(path.as_ref())?)
+    }
+
+    pub fn write_cube(&self, path: impl AsRef<Path>) -> Result<()> {
+        write_text(path.as_ref(), &self.to_cube())
+    }
+}
+
+// identity Hald CLUT image of level N (N^3 x N^3 pixels), RGB8 pixels in row order
+pub fn hald_identity(level: usize) -> Result<Vec<u8>> {
+    Lut3D::identity(hald_size(level)?)?.to_hald()
+}
+
+fn hald_size(level: usize) -> Result<usize> {
+    if !(2..=MAX_HALD_LEVEL).contains(&level) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("level {}", level),
+        ));
+    }
+    Ok(level * level)
+}
+
+slice_conversion!(Lut3D, RgbColor, RgbColor);
+slice_conversion!(Lut3D, convert_float_slice, FloatRgbColor, FloatRgbColor);
+
+impl Conversion<&FloatRgbColor, FloatRgbColor> for Lut3D {
+    fn convert(&self, color: &FloatRgbColor) -> Result<FloatRgbColor> {
+        Ok(self.apply(color))
+    }
+}
+
+impl Conversion<&RgbColor, RgbColor> for Lut3D {
+    fn convert(&self, color: &RgbColor) -> Result<RgbColor> {
+        Ok(convert_rgb(color, |color| self.apply(color)))
+    }
+}
+
+// per channel table, each output channel depends only on the same input channel
+#[derive(Debug, PartialEq, Clone)]
+pub struct Lut1D {
+    table: Vec<[f64; 3]>,
+    domain_min: [f64; 3],
+    domain_max: [f64; 3],
+    title: String,
+}
+
+impl Lut1D {
+    pub fn new(values: Vec<FloatRgbColor>) -> Result<Self> {
+        validate_1d_size(values.len())?;
+        Ok(Self::from_table(
+            values.into_iter().map(FloatRgbColor::to_array).collect(),
+        ))
+    }
+
+    pub fn identity(size: usize) -> Result<Self> {
+        Self::from_fn(size, |color| Ok(*color))
+    }
+
+    // samples the function on the neutral axis of the default domain (0 - 1)
+    pub fn from_fn(
+        size: usize,
+        f: impl Fn(&FloatRgbColor) -> Result<FloatRgbColor>,
+    ) -> Result<Self> {
+        validate_1d_size(size)?;
+        let scale = (size - 1) as f64;
+        let table = (0..size)
+            .map(|i| {
+                let value = i as f64 / scale;
+                Ok(f(&FloatRgbColor::new(value, value, value))?.to_array())
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::from_table(table))
+    }
+
+    pub fn from_converter<C>(size: usize, converter: &C) -> Result<Self>
+    where
+        C: for<'a> Conversion<&'a FloatRgbColor, FloatRgbColor>,
+    {
+        Self::from_fn(size, |color| converter.convert(color))
+    }
+
+    fn from_table(table: Vec<[f64; 3]>) -> Self {
+        Self {
+            table,
+            domain_min: DEFAULT_DOMAIN_MIN,
+            domain_max: DEFAULT_DOMAIN_MAX,
+            title: String::new(),
+        }
+    }
+
+    pub fn with_domain(self, domain_min: [f64; 3], domain_max: [f64; 3]) -> Self {
+        Self {
+            domain_min,
+            domain_max,
+            ..self
+        }
+    }
+
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..self
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.table.len()
+    }
+
+    pub fn domain_min(&self) -> [f64; 3] {
+        self.domain_min
+    }
+
+    pub fn domain_max(&self) -> [f64; 3] {
+        self.domain_max
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn values(&self) -> Vec<FloatRgbColor> {
+        self.table
+            .iter()
+            .map(|rgb| FloatRgbColor::from_array(*rgb, 1_f64))
+            .collect()
+    }
+
+    // linear interpolation, input outside of the domain is clamped, alpha is kept
+    pub fn apply(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        let mut rgb = color.to_array();
+        for (i, value) in rgb.iter_mut().enumerate() {
+            let (index, fraction) =
+                grid_position(*value, self.domain_min[i], self.domain_max[i], self.size());
+            let (lower, upper) = (self.table[index][i], self.table[index + 1][i]);
+            *value = lower + (upper - lower) * fraction;
+        }
+        FloatRgbColor::from_array(rgb, color.a())
+    }
+
+    // Adobe / Resolve .cube format
+    pub fn from_cube(text: &str) -> Result<Self> {
+        let cube = parse_cube(text, CubeDimension::One)?;
+        Ok(Self::from_table(cube.table)
+            .with_domain(cube.domain_min, cube.domain_max)
+            .with_title(cube.title))
+    }
+
+    pub fn to_cube(&self) -> String {
+        format_cube(
+            CubeDimension::One,
+            self.size(),
+            &self.title,
+            self.domain_min,
+            self.domain_max,
+            &self.table,
+        )
+    }
+
+    pub fn read_cube(path: impl AsRef<Path>) -> Result<Self> {
+        Self::from_cube(&read_text(path.as_ref())?)
+    }
+
+    pub fn write_cube(&self, path: impl AsRef<Path>) -> Result<()> {
+        write_text(path.as_ref(), &self.to_cube())
+    }
+}
+
+slice_conversion!(Lut1D, RgbColor, RgbColor);
+slice_conversion!(Lut1D, convert_float_slice, FloatRgbColor, FloatRgbColor);
+
+impl Conversion<&FloatRgbColor, FloatRgbColor> for Lut1D {
+    fn convert(&self, color: &FloatRgbColor) -> Result<FloatRgbColor> {
+        Ok(self.apply(color))
+    }
+}
+
+impl Conversion<&RgbColor, RgbColor> for Lut1D {
+    fn convert(&self, color: &RgbColor) -> Result<RgbColor> {
+        Ok(convert_rgb(color, |color| self.apply(color)))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum CubeDimension {
+    One,
+    Three,
+}
+
+impl CubeDimension {
+    fn keyword(&self) -> &'static str {
+        match self {
+            Self::One => "LUT_1D",
+            Self::Three => "LUT_3D",
+        }
+    }
+
+    fn max_size(&self) -> usize {
+        match self {
+            Self::One => MAX_LUT_1D_SIZE,
+            Self::Three => MAX_LUT_3D_SIZE,
+        }
+    }
+
+    fn entries(&self, size: usize) -> usize {
+        match self {
+            Self::One => size,
+            Self::Three => size.pow(3),
+        }
+    }
+}
+
+struct Cube {
+    size: usize,
+    title: String,
+    domain_min: [f64; 3],
+    domain_max: [f64; 3],
+    table: Vec<[f64; 3]>,
+}
+
+fn parse_cube(text: &str, dimension: CubeDimension) -> Result<Cube> {
+    let size_keyword = format!("{}_SIZE", dimension.keyword());
+    let range_keyword = format!("{}_INPUT_RANGE", dimension.keyword());
+    let mut size = None;
+    let mut title = String::new();
+    let mut domain_min = DEFAULT_DOMAIN_MIN;
+    let mut domain_max = DEFAULT_DOMAIN_MAX;
+    let mut table = Vec::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let format_error = |message: &str| {
+            errors::Error::new(
+                errors::ErrorCode::InvalidArgumentFormat,
+                format!("line {}: {}", number + 1, message),
+            )
+        };
+        let (keyword, arguments) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let arguments = arguments.trim();
+        match keyword {
+            "TITLE" => title = arguments.trim_matches('"').to_string(),
+            "LUT_1D_SIZE" | "LUT_3D_SIZE" if keyword != size_keyword => {
+                return Err(format_error(keyword))
+            }
+            _ if keyword == size_keyword => {
+                let value = arguments.parse::<usize>().map_err(|_| format_error(line))?;
+                if !(2..=dimension.max_size()).contains(&value) {
+                    return Err(format_error(line));
+                }
+                size = Some(value);
+            }
+            "DOMAIN_MIN" => domain_min = parse_triplet(arguments).ok_or(format_error(line))?,
+            "DOMAIN_MAX" => domain_max = parse_triplet(arguments).ok_or(format_error(line))?,
+            // Resolve extension
+            _ if keyword == range_keyword => {
+                let range = arguments
+                    .split_whitespace()
+                    .map(|value| value.parse::<f64>())
+                    .collect::<std::result::Result<Vec<_>, _>>()
+                    .map_err(|_| format_error(line))?;
+                let [min, max] = range[..] else {
+                    return Err(format_error(line));
+                };
+                domain_min = [min; 3];
+                domain_max = [max; 3];
+            }
+            _ => match parse_triplet(line) {
+                Some(rgb) => table.push(rgb),
+                // unknown keywords are ignored
+                None if keyword.starts_with(|c: char| c.is_ascii_uppercase()) => {}
+                None => return Err(format_error(line)),
+            },
+        }
+    }
+
+    let Some(size) = size else {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgumentFormat,
+            size_keyword,
+        ));
+    };
+    if table.len() != dimension.entries(size) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgumentFormat,
+            format!("size {} values {}", size, table.len()),
+        ));
+    }
+    if (0..3).any(|i| domain_min[i] >= domain_max[i]) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgumentFormat,
+            format!("domain {:?} {:?}", domain_min, domain_max),
+        ));
+    }
+    Ok(Cube {
+        size,
+        title,
+        domain_min,
+        domain_max,
+        table,
+    })
+}
+
+fn format_cube(
+    dimension: CubeDimension,
+    size: usize,
+    title: &str,
+    domain_min: [f64; 3],
+    domain_max: [f64; 3],
+    table: &[[f64; 3]],
+) -> String {
+    let mut text = String::new();
+    if !title.is_empty() {
+        text.push_str(&format!("TITLE \"{}\"\n", title));
+    }
+    text.push_str(&format!("{}_SIZE {}\n", dimension.keyword(), size));
+    if domain_min != DEFAULT_DOMAIN_MIN || domain_max != DEFAULT_DOMAIN_MAX {
+        let [r, g, b] = domain_min;
+        text.push_str(&format!("DOMAIN_MIN {} {} {}\n", r, g, b));
+        let [r, g, b] = domain_max;
+        text.push_str(&format!("DOMAIN_MAX {} {} {}\n", r, g, b));
+    }
+    for [r, g, b] in table.iter() {
+        text.push_str(&format!("{} {} {}\n", r, g, b));
+    }
+    text
+}
+
+fn read_text(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| {
+        errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("{} {}", path.display(), error),
+        )
+    })
+}
+
+fn write_text(path: &Path, text: &str) -> Result<()> {
+    fs::write(path, text).map_err(|error| {
+        errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("{} {}", path.display(), error),
+        )
+    })
+}
+
+// position of a value in the domain on a grid of `size` points, the lower index and the fraction
+fn grid_position(value: f64, min: f64, max: f64, size: usize) -> (usize, f64) {
+    let span = max - min;
+    let t = if span > 0_f64 {
+        ((value - min) / span).clamp(0_f64, 1_f64)
+    } else {
+        0_f64
+    };
+    // NaN is mapped to the lower bound
+    let position = if t.is_nan() {
+        0_f64
+    } else {
+        t * (size - 1) as f64
+    };
+    let index = (position.floor() as usize).min(size - 2);
+    (index, position - index as f64)
+}
+
+fn validate_size(size: usize) -> Result<()> {
+    if !(2..=MAX_LUT_3D_SIZE).contains(&size) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("size {}", size),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_1d_size(size: usize) -> Result<()> {
+    if !(2..=MAX_LUT_1D_SIZE).contains(&size) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("size {}", size),
+        ));
+    }
+    Ok(())
+}
+
+fn to_float(color: &RgbColor) -> FloatRgbColor {
+    FloatRgbColor::from_rgba(
+        color.r() as f64 / 255_f64,
+        color.g() as f64 / 255_f64,
+        color.b() as f64 / 255_f64,
+        color.a() as f64 / 255_f64,
+    )
+}
+
+// 8 bit color through a float adjustment, alpha is kept
+pub(crate) fn convert_rgb(
+    color: &RgbColor,
+    f: impl Fn(&FloatRgbColor) -> FloatRgbColor,
+) -> RgbColor {
+    let output = f(&to_float(color));
+    RgbColor::from_rgba(
+        utils::float_to_u8(output.r()),
+        utils::float_to_u8(output.g()),
+        utils::float_to_u8(output.b()),
+        color.a(),
+    )
+}
+
+fn parse_triplet(text: &str) -> Option<[f64; 3]> {
+    let mut values = text.split_whitespace().map(|value| value.parse::<f64>());
+    let rgb = [
+        values.next()?.ok()?,
+        values.next()?.ok()?,
+        values.next()?.ok()?,
+    ];
+    values.next().is_none().then_some(rgb)
+}
+
+fn weighted_sum(terms: [(f64, [f64; 3]); 4]) -> [f64; 3] {
+    let mut rgb = [0_f64; 3];
+    for (weight, value) in terms {
+        for (out, v) in rgb.iter_mut().zip(value) {
+            *out += weight * v;
+        }
+    }
+    rgb
+}
+
+fn trilinear(corner: impl Fn(usize, usize, usize) -> [f64; 3], fraction: [f64; 3]) -> [f64; 3] {
+    let [fr, fg, fb] = fraction;
+    let lerp = |a: [f64; 3], b: [f64; 3], t: f64| [0, 1, 2].map(|i| a[i] + (b[i] - a[i]) * t);
+    let c00 = lerp(corner(0, 0, 0), corner(1, 0, 0), fr);
+    let c10 = lerp(corner(0, 1, 0), corner(1, 1, 0), fr);
+    let c01 = lerp(corner(0, 0, 1), corner(1, 0, 1), fr);
+    let c11 = lerp(corner(0, 1, 1), corner(1, 1, 1), fr);
+    lerp(lerp(c00, c10, fg), lerp(c01, c11, fg), fb)
+}
+
+// the cube is split into six tetrahedra along the black - white diagonal
+fn tetrahedral(corner: impl Fn(usize, usize, usize) -> [f64; 3], fraction: [f64; 3]) -> [f64; 3] {
+    let [fr, fg, fb] = fraction;
+    let c000 = corner(0, 0, 0);
+    let c111 = corner(1, 1, 1);
+    if fr > fg {
+        if fg > fb {
+            weighted_sum([
+                (1_f64 - fr, c000),
+                (fr - fg, corner(1, 0, 0)),
+                (fg - fb, corner(1, 1, 0)),
+                (fb, c111),
+            ])
+        } else if fr > fb {
+            weighted_sum([
+                (1_f64 - fr, c000),
+                (fr - fb, corner(1, 0, 0)),
+                (fb - fg, corner(1, 0, 1)),
+                (fg, c111),
+            ])
+        } else {
+            weighted_sum([
+                (1_f64 - fb, c000),
+                (fb - fr, corner(0, 0, 1)),
+                (fr - fg, corner(1, 0, 1)),
+                (fg, c111),
+            ])
+        }
+    } else if fb > fg {
+        weighted_sum([
+            (1_f64 - fb, c000),
+            (fb - fg, corner(0, 0, 1)),
+            (fg - fr, corner(0, 1, 1)),
+            (fr, c111),
+        ])
+    } else if fb > fr {
+        weighted_sum([
+            (1_f64 - fg, c000),
+            (fg - fb, corner(0, 1, 0)),
+            (fb - fr, corner(0, 1, 1)),
+            (fr, c111),
+        ])
+    } else {
+        weighted_sum([
+            (1_f64 - fg, c000),
+            (fg - fr, corner(0, 1, 0)),
+            (fr - fb, corner(1, 1, 0)),
+            (fb, c111),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::RgbSpaceToRgbSpaceConverter;
+    use crate::rgb_space::RgbSpace;
+
+    fn assert_color_eq(actual: &FloatRgbColor, expected: &FloatRgbColor, tolerance: f64) {
+        assert!(
+            (actual.r() - expected.r()).abs() < tolerance
+                && (actual.g() - expected.g()).abs() < tolerance
+                && (actual.b() - expected.b()).abs() < tolerance,
+            "{} {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn lut3d_identity_test() {
+        for interpolation in [LutInterpolation::Trilinear, LutInterpolation::Tetrahedral] {
+            let lut = Lut3D::identity(5)
+                .unwrap()
+                .with_interpolation(interpolation);
+            for color in [
+                FloatRgbColor::new(0.1, 0.5, 0.9),
+                FloatRgbColor::new(0.9, 0.3, 0.6),
+                FloatRgbColor::new(1_f64, 1_f64, 0_f64),
+            ] {
+                assert_color_eq(&lut.apply(&color), &color, 1e-12);
+            }
+            // clamped to the domain
+            let clamped = lut.apply(&FloatRgbColor::new(-1_f64, 2_f64, 0.5));
+            assert_color_eq(&clamped, &FloatRgbColor::new(0_f64, 1_f64, 0.5), 1e-12);
+        }
+
+        let rgb = RgbColor::from_rgba(12, 200, 255, 100);
+        assert_eq!(Lut3D::identity(2).unwrap().convert(&rgb).unwrap(), rgb);
+        assert!(Lut3D::identity(1).is_err());
+        assert!(Lut3D::new(2, vec![FloatRgbColor::default(); 7]).is_err());
+    }
+
+    #[test]
+    fn lut3d_interpolation_test() {
+        // both interpolations are exact for a linear function
+        let matrix = |color: &FloatRgbColor| {
+            Ok(FloatRgbColor::new(
+                0.8 * color.r() + 0.2 * color.b(),
+                0.5 * color.g() + 0.1,
+                color.r() * 0.3 + color.g() * 0.3 + color.b() * 0.4,
+            ))
+        };
+        let color = FloatRgbColor::new(0.37, 0.81, 0.12);
+        let expected = matrix(&color).unwrap();
+        for interpolation in [LutInterpolation::Trilinear, LutInterpolation::Tetrahedral] {
+            let lut = Lut3D::from_fn(3, matrix)
+                .unwrap()
+                .with_interpolation(interpolation);
+            assert_color_eq(&lut.apply(&color), &expected, 1e-12);
+        }
+
+        // tetrahedral keeps the neutral axis on a diagonal of the cell
+        let curve = |color: &FloatRgbColor| {
+            Ok(FloatRgbColor::new(
+                color.r().powi(2),
+                color.g().powi(2),
+                color.b().powi(2),
+            ))
+        };
+        let lut = Lut3D::from_fn(2, curve).unwrap();
+        let gray = lut.apply(&FloatRgbColor::new(0.5, 0.5, 0.5));
+        assert_color_eq(&gray, &FloatRgbColor::new(0.5, 0.5, 0.5), 1e-12);
+        let trilinear = lut.with_interpolation(LutInterpolation::Trilinear);
+        let gray = trilinear.apply(&FloatRgbColor::new(0.5, 0.5, 0.5));
+        assert_color_eq(&gray, &FloatRgbColor::new(0.5, 0.5, 0.5), 1e-12);
+    }
+
+    #[test]
+    fn lut3d_from_converter_test() {
+        let converter = RgbSpaceToRgbSpaceConverter::new(RgbSpace::srgb(), RgbSpace::display_p3());
+        let lut = Lut3D::from_converter(33, &converter).unwrap();
+        let color = FloatRgbColor::new(0.8, 0.4, 0.2);
+        let expected = converter.convert(&color).unwrap();
+        assert_color_eq(&lut.apply(&color), &expected, 1e-3);
+    }
+
+    #[test]
+    fn lut1d_test() {
+        let identity = Lut1D::identity(4).unwrap();
+        let color = FloatRgbColor::from_rgba(0.1, 0.55, 0.9, 0.5);
+        assert_color_eq(&identity.apply(&color), &color, 1e-12);
+        assert_eq!(identity.apply(&color).a(), 0.5);
+
+        // channels are independent
+        let lut = Lut1D::new(vec![
+            FloatRgbColor::new(0_f64, 1_f64, 0_f64),
+            FloatRgbColor::new(1_f64, 0_f64, 0.5),
+        ])
+        .unwrap();
+        let actual = lut.apply(&FloatRgbColor::new(0.25, 0.25, 2_f64));
+        assert_color_eq(&actual, &FloatRgbColor::new(0.25, 0.75, 0.5), 1e-12);
+
+        let lut = Lut1D::from_fn(1024, |color| {
+            Ok(FloatRgbColor::new(
+                color.r().sqrt(),
+                color.g(),
+                color.b().powi(2),
+            ))
+        })
+        .unwrap();
+        let actual = lut.apply(&FloatRgbColor::new(0.5, 0.5, 0.5));
+        assert!((actual.r() - 0.5_f64.sqrt()).abs() < 1e-3);
+        assert!((actual.b() - 0.25).abs() < 1e-3);
+
+        assert!(Lut1D::identity(1).is_err());
+        assert!(Lut1D::new(vec![]).is_err());
+    }
+
+    #[test]
+    fn lut1d_cube_test() {
+        let text = "TITLE \"contrast\"\nLUT_1D_SIZE 3\nLUT_1D_INPUT_RANGE 0 2\n\
+            0 0 0\n0.4 0.5 0.6\n1 1 1\n";
+        let lut = Lut1D::from_cube(text).unwrap();
+        assert_eq!(lut.size(), 3);
+        assert_eq!(lut.title(), "contrast");
+        assert_eq!(lut.domain_max(), [2_f64; 3]);
+        let actual = lut.apply(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert_color_eq(&actual, &FloatRgbColor::new(0.4, 0.5, 0.6), 1e-12);
+        assert_eq!(Lut1D::from_cube(&lut.to_cube()).unwrap(), lut);
+
+        // the dimensions are not interchangeable
+        assert!(Lut3D::from_cube(text).is_err());
+        assert!(Lut1D::from_cube(&Lut3D::identity(2).unwrap().to_cube()).is_err());
+        assert!(Lut1D::from_cube("LUT_1D_SIZE 3\n0 0 0\n1 1 1\n").is_err());
+    }
+
+    #[test]
+    fn hald_test() {
+        let identity = hald_identity(2).unwrap();
+        // 8 x 8 pixels, the first row is the first two green steps of the black plane
+        assert_eq!(identity.len(), 8 * 8 * 3);
+        assert_eq!(identity[..12], [0, 0, 0, 85, 0, 0, 170, 0, 0, 255, 0, 0]);
+        assert_eq!(identity[12..15], [0, 85, 0]);
+        assert_eq!(identity[identity.len() - 3..], [255, 255, 255]);
+
+        let lut = Lut3D::from_hald(8, &hald_identity(8).unwrap()).unwrap();
+        assert_eq!(lut.size(), 64);
+        for color in [
+            RgbColor::new(0, 0, 0),
+            RgbColor::new(255, 128, 3),
+            RgbColor::new(17, 200, 91),
+        ] {
+            let actual = lut.convert(&color).unwrap();
+            assert!(actual.r().abs_diff(color.r()) <= 1, "{} {}", actual, color);
+            assert!(actual.g().abs_diff(color.g()) <= 1, "{} {}", actual, color);
+            assert!(actual.b().abs_diff(color.b()) <= 1, "{} {}", actual, color);
+        }
+
+        // a grayscale film preset
+        let gray: Vec<u8> = hald_identity(4)
+            .unwrap()
+            .chunks_exact(3)
+            .flat_map(|p| {
+                let y = (0.2126 * p[0] as f64 + 0.7152 * p[1] as f64 + 0.0722 * p[2] as f64).round()
+                    as u8;
+                [y; 3]
+            })
+            .collect();
+        let lut = Lut3D::from_hald(4, &gray).unwrap();
+        let actual = lut.convert(&RgbColor::new(255, 0, 0)).unwrap();
+        assert_eq!(actual, RgbColor::new(54, 54, 54));
+        assert_eq!(lut.to_hald().unwrap(), gray);
+
+        assert!(hald_identity(1).is_err());
+        assert!(hald_identity(17).is_err());
+        assert!(Lut3D::from_hald(2, &[0; 10]).is_err());
+        assert!(Lut3D::identity(5).unwrap().to_hald().is_err());
+    }
+
+    #[test]
+    fn lut3d_cube_test() {
+        let text = "# comment\n\
+            TITLE \"warm\"\n\
+            LUT_3D_SIZE 2\n\
+            DOMAIN_MIN 0 0 0\n\
+            DOMAIN_MAX 1 1 1\n\
+            \n\
+            0 0 0\n1 0 0\n0 1 0\n1 1 0\n\
+            0 0 1\n1 0 1\n0 1 1\n1 1 0.9\n";
+        let lut = Lut3D::from_cube(text).unwrap();
+        assert_eq!(lut.title(), "warm");
+        assert_eq!(lut.size(), 2);
+        let white = lut.apply(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert!((white.b() - 0.9).abs() < 1e-12);
+
+        let round_trip = Lut3D::from_cube(&lut.to_cube()).unwrap();
+        assert_eq!(round_trip, lut);
+
+        let lut = Lut3D::identity(3).unwrap().with_domain([-0.5; 3], [1.5; 3]);
+        let round_trip = Lut3D::from_cube(&lut.to_cube()).unwrap();
+        assert_eq!(round_trip.domain_min(), [-0.5; 3]);
+        assert_eq!(round_trip.domain_max(), [1.5; 3]);
+        // the domain is mapped onto the grid
+        let color = FloatRgbColor::new(-0.5, 0.5, 1.5);
+        let expected = FloatRgbColor::new(0_f64, 0.5, 1_f64);
+        assert_color_eq(&round_trip.apply(&color), &expected, 1e-12);
+
+        let resolve = "LUT_3D_SIZE 2\nLUT_3D_INPUT_RANGE 0 2\n".to_string() + &"0 0 0\n".repeat(8);
+        assert_eq!(Lut3D::from_cube(&resolve).unwrap().domain_max(), [2_f64; 3]);
+
+        assert!(Lut3D::from_cube("0 0 0\n").is_err());
+        assert!(Lut3D::from_cube("LUT_3D_SIZE 2\n0 0 0\n").is_err());
+        assert!(Lut3D::from_cube("LUT_1D_SIZE 2\n0 0 0\n1 1 1\n").is_err());
+        assert!(Lut3D::from_cube("LUT_3D_SIZE 2\n0 0 x\n").is_err());
+    }
+}
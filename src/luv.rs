@@ -0,0 +1,230 @@
+use std::fmt::Display;
+
+use crate::white_point::WhitePoint;
+use crate::xyz::XyzColor;
+
+// http://www.brucelindbloom.com/index.html?Eqn_XYZ_to_Luv.html
+const EPSILON: f64 = 216_f64 / 24389_f64;
+const KAPPA: f64 = 24389_f64 / 27_f64;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LuvColor {
+    l: f64,
+    u: f64,
+    v: f64,
+    a: f64,
+}
+
+impl Display for LuvColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LuvColor L = {} u = {} v = {} A = {}",
+            self.l, self.u, self.v, self.a
+        )
+    }
+}
+
+impl LuvColor {
+    pub fn new(l: f64, u: f64, v: f64) -> Self {
+        Self::from_luva(l, u, v, 1f64)
+    }
+
+    pub fn from_luva(l: f64, u: f64, v: f64, a: f64) -> Self {
+        Self { l, u, v, a }
+    }
+
+    pub fn from_xyz(color: &XyzColor, white_point: &WhitePoint) -> Self {
+        let white = white_point.xyz();
+        let y = color.y() / white.y();
+        let l = if y > EPSILON {
+            116_f64 * y.cbrt() - 16_f64
+        } else {
+            KAPPA * y
+        };
+        if l == 0_f64 {
+            return Self::from_luva(0_f64, 0_f64, 0_f64, color.a());
+        }
+
+        let (u, v) = uv_prime(color);
+        let (white_u, white_v) = uv_prime(&white);
+        Self::from_luva(
+            l,
+            13_f64 * l * (u - white_u),
+            13_f64 * l * (v - white_v),
+            color.a(),
+        )
+    }
+
+    pub fn to_xyz(&self, white_point: &WhitePoint) -> XyzColor {
+        if self.l <= 0_f64 {
+            return XyzColor::from_xyza(0_f64, 0_f64, 0_f64, self.a);
+        }
+
+        let white = white_point.xyz();
+        let (white_u, white_v) = uv_prime(&white);
+        let u = self.u / (13_f64 * self.l) + white_u;
+        let v = self.v / (13_f64 * self.l) + white_v;
+        let y = if self.l > KAPPA * EPSILON {
+            ((self.l + 16_f64) / 116_f64).powi(3)
+        } else {
+            self.l / KAPPA
+        } * white.y();
+        let x = y * 9_f64 * u / (4_f64 * v);
+        let z = y * (12_f64 - 3_f64 * u - 20_f64 * v) / (4_f64 * v);
+
+        XyzColor::from_xyza(x, y, z, self.a)
+    }
+
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    pub fn u(&self) -> f64 {
+        self.u
+    }
+
+    pub fn v(&self) -> f64 {
+        self.v
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+fn uv_prime(color: &XyzColor) -> (f64, f64) {
+    let denominator = color.x() + 15_f64 * color.y() + 3_f64 * color.z();
+    if denominator == 0_f64 {
+        return (0_f64, 0_f64);
+    }
+    (
+        4_f64 * color.x() / denominator,
+        9_f64 * color.y() / denominator,
+    )
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct LchuvColor {
+    l: f64,
+    c: f64,
+    h: f64,
+    a: f64,
+}
+
+impl Display for LchuvColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "LchuvColor L = {} C = {} H = {} A = {}",
+            self.l, self.c, self.h, self.a
+        )
+    }
+}
+
+impl LchuvColor {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self::from_lcha(l, c, h, 1f64)
+    }
+
+    pub fn from_lcha(l: f64, c: f64, h: f64, a: f64) -> Self {
+        Self {
+            l,
+            c: c.max(0f64),
+            h: h.rem_euclid(360f64),
+            a,
+        }
+    }
+
+    pub fn from_luv(color: &LuvColor) -> Self {
+        let c = color.u().hypot(color.v());
+        // hue is meaningless for achromatic colors
+        let h = if c < 1e-8 {
+            0_f64
+        } else {
+            color.v().atan2(color.u()).to_degrees()
+        };
+        Self::from_lcha(color.l(), c, h, color.a())
+    }
+
+    pub fn to_luv(&self) -> LuvColor {
+        let h = self.h.to_radians();
+        LuvColor::from_luva(self.l, self.c * h.cos(), self.c * h.sin(), self.a)
+    }
+
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: f64, expected: f64) {
+        assert!(
+            (actual - expected).abs() < 1e-3,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn luv_from_xyz_test() {
+        // sRGB red
+        let xyz = XyzColor::new(0.4124564, 0.2126729, 0.0193339);
+        let luv = LuvColor::from_xyz(&xyz, &WhitePoint::D65);
+        assert_near(luv.l(), 53.2408);
+        assert_near(luv.u(), 175.0150);
+        assert_near(luv.v(), 37.7564);
+
+        let white = LuvColor::from_xyz(&WhitePoint::D65.xyz(), &WhitePoint::D65);
+        assert_near(white.l(), 100_f64);
+        assert_near(white.u(), 0_f64);
+        assert_near(white.v(), 0_f64);
+
+        let black = LuvColor::from_xyz(&XyzColor::new(0_f64, 0_f64, 0_f64), &WhitePoint::D65);
+        assert_eq!(black, LuvColor::new(0_f64, 0_f64, 0_f64));
+    }
+
+    #[test]
+    fn luv_to_xyz_test() {
+        for xyz in [
+            XyzColor::new(0.4124564, 0.2126729, 0.0193339),
+            XyzColor::new(0.2, 0.005, 0.3),
+            XyzColor::new(0.9, 0.8, 0.1),
+        ] {
+            let actual = LuvColor::from_xyz(&xyz, &WhitePoint::D50).to_xyz(&WhitePoint::D50);
+            assert!((actual.x() - xyz.x()).abs() < 1e-9);
+            assert!((actual.y() - xyz.y()).abs() < 1e-9);
+            assert!((actual.z() - xyz.z()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn lchuv_from_luv_test() {
+        let lch = LchuvColor::from_luv(&LuvColor::new(53.2408, 175.0150, 37.7564));
+        assert_near(lch.c(), 179.0414);
+        assert_near(lch.h(), 12.1741);
+
+        let luv = lch.to_luv();
+        assert_near(luv.u(), 175.0150);
+        assert_near(luv.v(), 37.7564);
+
+        let gray = LchuvColor::from_luv(&LuvColor::new(50_f64, 0_f64, 0_f64));
+        assert_eq!(gray.h(), 0_f64);
+    }
+}
@@ -0,0 +1,113 @@
+pub(crate) type Matrix3 = [[f64; 3]; 3];
+
+pub(crate) fn multiply(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut result = [[0_f64; 3]; 3];
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    result
+}
+
+pub(crate) fn multiply_vector(m: &Matrix3, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub(crate) fn diagonal(v: [f64; 3]) -> Matrix3 {
+    [
+        [v[0], 0_f64, 0_f64],
+        [0_f64, v[1], 0_f64],
+        [0_f64, 0_f64, v[2]],
+    ]
+}
+
+pub(crate) fn determinant(m: &Matrix3) -> f64 {
+    m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+        - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+        + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+}
+
+pub(crate) fn inverse(m: &Matrix3) -> Option<Matrix3> {
+    // singular relative to the scale of the rows (|det| <= product of the row norms)
+    let det = determinant(m);
+    let scale = m
+        .iter()
+        .map(|row| row.iter().map(|v| v * v).sum::<f64>().sqrt())
+        .product::<f64>();
+    if !det.is_finite() || det.abs() <= scale * f64::EPSILON {
+        return None;
+    }
+
+    Some([
+        [
+            (m[1][1] * m[2][2] - m[1][2] * m[2][1]) / det,
+            (m[0][2] * m[2][1] - m[0][1] * m[2][2]) / det,
+            (m[0][1] * m[1][2] - m[0][2] * m[1][1]) / det,
+        ],
+        [
+            (m[1][2] * m[2][0] - m[1][0] * m[2][2]) / det,
+            (m[0][0] * m[2][2] - m[0][2] * m[2][0]) / det,
+            (m[0][2] * m[1][0] - m[0][0] * m[1][2]) / det,
+        ],
+        [
+            (m[1][0] * m[2][1] - m[1][1] * m[2][0]) / det,
+            (m[0][1] * m[2][0] - m[0][0] * m[2][1]) / det,
+            (m[0][0] * m[1][1] - m[0][1] * m[1][0]) / det,
+        ],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDENTITY: Matrix3 = [
+        [1_f64, 0_f64, 0_f64],
+        [0_f64, 1_f64, 0_f64],
+        [0_f64, 0_f64, 1_f64],
+    ];
+
+    #[test]
+    fn matrix_inverse_test() {
+        let m = [
+            [0.8951, 0.2664, -0.1614],
+            [-0.7502, 1.7135, 0.0367],
+            [0.0389, -0.0685, 1.0296],
+        ];
+        let product = multiply(&m, &inverse(&m).unwrap());
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((product[i][j] - IDENTITY[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn matrix_inverse_singular_test() {
+        let m = [
+            [1_f64, 2_f64, 3_f64],
+            [2_f64, 4_f64, 6_f64],
+            [0_f64, 1_f64, 0_f64],
+        ];
+        assert!(inverse(&m).is_none());
+        assert!(inverse(&[[0_f64; 3]; 3]).is_none());
+    }
+
+    #[test]
+    fn matrix_inverse_small_scale_test() {
+        // well conditioned, but the determinant is 1e-18
+        let m = diagonal([1e-6; 3]);
+        let product = multiply(&m, &inverse(&m).unwrap());
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((product[i][j] - IDENTITY[i][j]).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::converter::RgbToXyzConverter;
+use crate::converter::{Conversion, ReflectanceToRgbConverter, RgbToReflectanceConverter};
+use crate::errors;
+use crate::errors::Result;
+use crate::rgb::RgbColor;
+use crate::spectral::SampledSpectrum;
+use crate::upsampling::SpectralUpsampling;
+
+// reflectance is kept away from 0 so that K/S stays finite
+const MIN_REFLECTANCE: f64 = 0.001;
+// relative scattering of a black pigment
+const MIN_SCATTERING: f64 = 0.01;
+
+// single constant Kubelka-Munk theory, the absorption / scattering ratio of an opaque layer
+fn absorption_scattering(reflectance: f64) -> f64 {
+    let r = reflectance.clamp(MIN_REFLECTANCE, 1_f64);
+    (1_f64 - r).powi(2) / (2_f64 * r)
+}
+
+fn reflectance(absorption_scattering: f64) -> f64 {
+    let ks = absorption_scattering;
+    1_f64 + ks - (ks * ks + 2_f64 * ks).sqrt()
+}
+
+// subtractive mixing of reflectance spectra weighted by concentration
+// all spectra must be sampled at the same wavelengths
+pub fn kubelka_munk(pigments: &[(&SampledSpectrum, f64)]) -> Result<SampledSpectrum> {
+    let Some((first, _)) = pigments.first() else {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            "no pigments",
+        ));
+    };
+    let total: f64 = pigments
+        .iter()
+        .map(|(_, concentration)| concentration)
+        .sum();
+    if pigments
+        .iter()
+        .any(|(_, concentration)| *concentration < 0_f64)
+        || total <= 0_f64
+    {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!("concentration total {}", total),
+        ));
+    }
+    if let Some((spectrum, _)) = pigments.iter().find(|(spectrum, _)| {
+        spectrum.start() != first.start()
+            || spectrum.interval() != first.interval()
+            || spectrum.values().len() != first.values().len()
+    }) {
+        return Err(errors::Error::new(
+            errors::ErrorCode::InvalidArgument,
+            format!(
+                "start {} interval {} samples {}",
+                spectrum.start(),
+                spectrum.interval(),
+                spectrum.values().len()
+            ),
+        ));
+    }
+
+    let values = (0..first.values().len())
+        .map(|i| {
+            let ks: f64 = pigments
+                .iter()
+                .map(|(spectrum, concentration)| {
+                    absorption_scattering(spectrum.values()[i]) * concentration / total
+                })
+                .sum();
+            reflectance(ks)
+        })
+        .collect();
+    SampledSpectrum::new(first.start(), first.interval(), values)
+}
+
+// paint like mixing of sRGB colors (upsampled to reflectance, mixed by Kubelka-Munk under D65)
+#[derive(PartialEq, Clone, Debug, Default)]
+pub struct PigmentMixer {
+    upsampling: RgbToReflectanceConverter,
+    reflectance: ReflectanceToRgbConverter,
+}
+
+impl PigmentMixer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_upsampling(self, upsampling: SpectralUpsampling) -> Self {
+        Self {
+            upsampling: RgbToReflectanceConverter::new().with_upsampling(upsampling),
+            ..self
+        }
+    }
+
+    pub fn upsampling(&self) -> SpectralUpsampling {
+        self.upsampling.upsampling()
+    }
+
+    // ratio is the amount of `to` (0 - 1)
+    pub fn mix(&self, from: &RgbColor, to: &RgbColor, ratio: f64) -> Result<RgbColor> {
+        let ratio = ratio.clamp(0_f64, 1_f64);
+        self.mix_all(&[(*from, 1_f64 - ratio), (*to, ratio)])
+    }
+
+    // the scattering of each pigment is estimated from its luminance (two constant Kubelka-Munk),
+    // otherwise dark pigments overwhelm light ones (blue and yellow would mix into teal)
+    // alpha is averaged by concentration
+    pub fn mix_all(&self, colors: &[(RgbColor, f64)]) -> Result<RgbColor> {
+        let spectra = colors
+            .iter()
+            .map(|(color, _)| self.upsampling.convert(color))
+            .collect::<Result<Vec<_>>>()?;
+        let mut pigments = Vec::with_capacity(colors.len());
+        for (spectrum, (color, concentration)) in spectra.iter().zip(colors) {
+            let scattering = RgbToXyzConverter {}.convert(color)?.y().max(MIN_SCATTERING);
+            pigments.push((spectrum, concentration * scattering));
+        }
+        let mixed = self.reflectance.convert(&kubelka_munk(&pigments)?)?;
+
+        let total: f64 = colors.iter().map(|(_, concentration)| concentration).sum();
+        let alpha: f64 = colors
+            .iter()
+            .map(|(color, concentration)| color.a() as f64 * concentration / total)
+            .sum();
+        Ok(RgbColor::from_rgba(
+            mixed.r(),
+            mixed.g(),
+            mixed.b(),
+            alpha.round() as u8,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kubelka_munk_test() {
+        let white = SampledSpectrum::new(380_f64, 10_f64, vec![1_f64; 4]).unwrap();
+        let gray = SampledSpectrum::new(380_f64, 10_f64, vec![0.2; 4]).unwrap();
+        let mixed = kubelka_munk(&[(&white, 1_f64)]).unwrap();
+        assert!(mixed.values().iter().all(|&value| value == 1_f64));
+        let mixed = kubelka_munk(&[(&gray, 2_f64)]).unwrap();
+        assert!(mixed
+            .values()
+            .iter()
+            .all(|&value| (value - 0.2).abs() < 1e-12));
+
+        // a small amount of dark pigment darkens more than the average reflectance
+        let mixed = kubelka_munk(&[(&white, 0.5), (&gray, 0.5)]).unwrap();
+        assert!(mixed.values()[0] < 0.6 && mixed.values()[0] > 0.2);
+
+        let other = SampledSpectrum::new(400_f64, 10_f64, vec![1_f64; 4]).unwrap();
+        assert!(kubelka_munk(&[(&white, 1_f64), (&other, 1_f64)]).is_err());
+        assert!(kubelka_munk(&[(&white, 0_f64)]).is_err());
+        assert!(kubelka_munk(&[(&white, 1_f64), (&gray, -1_f64)]).is_err());
+        assert!(kubelka_munk(&[]).is_err());
+    }
+
+    #[test]
+    fn pigment_mixer_mix_test() {
+        let blue = RgbColor::new(0, 0, 255);
+        let yellow = RgbColor::new(255, 255, 0);
+        let green = PigmentMixer::new().mix(&blue, &yellow, 0.5).unwrap();
+        assert!(green.g() > green.r() && green.g() > green.b(), "{}", green);
+
+        // the broad Smits spectra mix into blue green, but not into gray
+        let mixer = PigmentMixer::new().with_upsampling(SpectralUpsampling::Smits);
+        let mixed = mixer.mix(&blue, &yellow, 0.5).unwrap();
+        assert!(mixed.r() < 50 && mixed.g() > 100, "{}", mixed);
+
+        let mixer = PigmentMixer::new();
+        let red = RgbColor::new(200, 30, 40);
+        assert_eq!(mixer.mix(&red, &yellow, 0_f64).unwrap(), red);
+        assert_eq!(mixer.mix(&red, &red, 0.3).unwrap(), red);
+
+        // black paint darkens
+        let gray = mixer
+            .mix(&RgbColor::new(0, 0, 0), &RgbColor::new(255, 255, 255), 0.5)
+            .unwrap();
+        assert!(gray.r() > 30 && gray.r() < 150, "{}", gray);
+        assert_eq!(gray.r(), gray.g());
+        assert!(mixer.mix_all(&[]).is_err());
+
+        let transparent = RgbColor::from_rgba(255, 255, 255, 0);
+        assert_eq!(mixer.mix(&red, &transparent, 0.5).unwrap().a(), 128);
+    }
+}
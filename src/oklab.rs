@@ -0,0 +1,212 @@
+use std::fmt::Display;
+
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::xyz::XyzColor;
+
+// https://bottosson.github.io/posts/oklab/
+const XYZ_TO_LMS: Matrix3 = [
+    [0.8189330101, 0.3618667424, -0.1288597137],
+    [0.0329845436, 0.9293118715, 0.0361456387],
+    [0.0482003018, 0.2643662691, 0.6338517070],
+];
+
+const LMS_TO_OKLAB: Matrix3 = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+const LMS_TO_XYZ: Matrix3 = [
+    [1.2270138511, -0.5577999807, 0.2812561490],
+    [-0.0405801784, 1.1122568696, -0.0716766787],
+    [-0.0763812845, -0.4214819784, 1.5861632204],
+];
+
+const OKLAB_TO_LMS: Matrix3 = [
+    [0.9999999985, 0.3963377922, 0.2158037581],
+    [1.0000000089, -0.1055613423, -0.0638541748],
+    [1.0000000547, -0.0894841821, -1.2914855379],
+];
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct OklabColor {
+    l: f64,
+    a: f64,
+    b: f64,
+    alpha: f64,
+}
+
+impl Display for OklabColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OklabColor L = {} a = {} b = {} A = {}",
+            self.l, self.a, self.b, self.alpha
+        )
+    }
+}
+
+impl OklabColor {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Self::from_laba(l, a, b, 1f64)
+    }
+
+    pub fn from_laba(l: f64, a: f64, b: f64, alpha: f64) -> Self {
+        Self { l, a, b, alpha }
+    }
+
+    // XYZ relative to D65
+    pub fn from_xyz(color: &XyzColor) -> Self {
+        let lms = matrix::multiply_vector(&XYZ_TO_LMS, color.to_array());
+        let lab =
+            matrix::multiply_vector(&LMS_TO_OKLAB, [lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()]);
+        Self::from_laba(lab[0], lab[1], lab[2], color.a())
+    }
+
+    pub fn to_xyz(&self) -> XyzColor {
+        let lms = matrix::multiply_vector(&OKLAB_TO_LMS, [self.l, self.a, self.b]);
+        XyzColor::from_array(
+            matrix::multiply_vector(
+                &LMS_TO_XYZ,
+                [lms[0].powi(3), lms[1].powi(3), lms[2].powi(3)],
+            ),
+            self.alpha,
+        )
+    }
+
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    pub fn b(&self) -> f64 {
+        self.b
+    }
+
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    // ΔEOK
+    pub fn distance(&self, other: &OklabColor) -> f64 {
+        ((self.l - other.l).powi(2) + (self.a - other.a).powi(2) + (self.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct OklchColor {
+    l: f64,
+    c: f64,
+    h: f64,
+    a: f64,
+}
+
+impl Display for OklchColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "OklchColor L = {} C = {} H = {} A = {}",
+            self.l, self.c, self.h, self.a
+        )
+    }
+}
+
+impl OklchColor {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Self::from_lcha(l, c, h, 1f64)
+    }
+
+    pub fn from_lcha(l: f64, c: f64, h: f64, a: f64) -> Self {
+        Self {
+            l,
+            c: c.max(0f64),
+            h: h.rem_euclid(360f64),
+            a,
+        }
+    }
+
+    pub fn from_oklab(color: &OklabColor) -> Self {
+        Self::from_lcha(
+            color.l(),
+            color.a().hypot(color.b()),
+            color.b().atan2(color.a()).to_degrees(),
+            color.alpha(),
+        )
+    }
+
+    pub fn to_oklab(&self) -> OklabColor {
+        let h = self.h.to_radians();
+        OklabColor::from_laba(self.l, self.c * h.cos(), self.c * h.sin(), self.a)
+    }
+
+    pub fn l(&self) -> f64 {
+        self.l
+    }
+
+    pub fn c(&self) -> f64 {
+        self.c
+    }
+
+    pub fn h(&self) -> f64 {
+        self.h
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oklab_from_xyz_test() {
+        // https://bottosson.github.io/posts/oklab/#table-of-example-xyz-and-oklab-pairs
+        let pairs = [
+            ([0.950, 1.000, 1.089], [1.000, 0.000, 0.000]),
+            ([1.000, 0.000, 0.000], [0.450, 1.236, -0.019]),
+            ([0.000, 1.000, 0.000], [0.922, -0.671, 0.263]),
+            ([0.000, 0.000, 1.000], [0.153, -1.415, -0.449]),
+        ];
+        for (xyz, lab) in pairs.iter() {
+            let oklab = OklabColor::from_xyz(&XyzColor::new(xyz[0], xyz[1], xyz[2]));
+            assert!((oklab.l() - lab[0]).abs() < 1e-3, "{}", oklab);
+            assert!((oklab.a() - lab[1]).abs() < 1e-3, "{}", oklab);
+            assert!((oklab.b() - lab[2]).abs() < 1e-3, "{}", oklab);
+        }
+    }
+
+    #[test]
+    fn oklab_round_trip_test() {
+        let xyz = XyzColor::from_xyza(0.2, 0.3, 0.4, 0.5);
+        let result = OklabColor::from_xyz(&xyz).to_xyz();
+        assert!((result.x() - xyz.x()).abs() < 1e-7);
+        assert!((result.y() - xyz.y()).abs() < 1e-7);
+        assert!((result.z() - xyz.z()).abs() < 1e-7);
+        assert_eq!(result.a(), 0.5);
+    }
+
+    #[test]
+    fn oklch_round_trip_test() {
+        let oklab = OklabColor::new(0.6, -0.1, -0.05);
+        let oklch = OklchColor::from_oklab(&oklab);
+        assert!(oklch.h() > 180f64 && oklch.h() < 270f64);
+
+        let result = oklch.to_oklab();
+        assert!((result.a() - oklab.a()).abs() < 1e-12);
+        assert!((result.b() - oklab.b()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn oklab_distance_test() {
+        let a = OklabColor::new(0.5, 0.1, 0f64);
+        let b = OklabColor::new(0.5, 0.1, 0.02);
+        assert!((a.distance(&b) - 0.02).abs() < 1e-12);
+    }
+}
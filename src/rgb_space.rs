@@ -0,0 +1,676 @@
+use std::sync::OnceLock;
+
+use crate::adaptation;
+use crate::adaptation::ChromaticAdaptation;
+use crate::chromaticity::Chromaticity;
+use crate::errors;
+use crate::errors::Result;
+use crate::float_rgb::FloatRgbColor;
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::transfer::TransferFunction;
+use crate::white_point::WhitePoint;
+use crate::xyz::XyzColor;
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RgbSpace {
+    red: Chromaticity,
+    green: Chromaticity,
+    blue: Chromaticity,
+    white_point: WhitePoint,
+    // red, green, blue
+    transfers: [TransferFunction; 3],
+    to_xyz: Matrix3,
+    from_xyz: Matrix3,
+}
+
+impl RgbSpace {
+    pub fn new(
+        red: Chromaticity,
+        green: Chromaticity,
+        blue: Chromaticity,
+        white_point: WhitePoint,
+        transfer: TransferFunction,
+    ) -> Result<Self> {
+        // http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
+        let r = red.to_xyz(1_f64);
+        let g = green.to_xyz(1_f64);
+        let b = blue.to_xyz(1_f64);
+        let primaries = [
+            [r.x(), g.x(), b.x()],
+            [r.y(), g.y(), b.y()],
+            [r.z(), g.z(), b.z()],
+        ];
+
+        let invalid = || {
+            errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("{} {} {} {}", red, green, blue, white_point),
+            )
+        };
+        adaptation::validate_white_point(&white_point).map_err(|_| invalid())?;
+        let inverse_primaries = matrix::inverse(&primaries).ok_or_else(invalid)?;
+        let s = matrix::multiply_vector(&inverse_primaries, white_point.xyz().to_array());
+        let to_xyz = matrix::multiply(&primaries, &matrix::diagonal(s));
+        let from_xyz = matrix::inverse(&to_xyz).ok_or_else(invalid)?;
+
+        Ok(Self {
+            red,
+            green,
+            blue,
+            white_point,
+            transfers: [transfer.clone(), transfer.clone(), transfer],
+            to_xyz,
+            from_xyz,
+        })
+    }
+
+    fn standard(
+        red: (f64, f64),
+        green: (f64, f64),
+        blue: (f64, f64),
+        white: (f64, f64),
+        transfer: TransferFunction,
+    ) -> Self {
+        Self::new(
+            Chromaticity::new(red.0, red.1),
+            Chromaticity::new(green.0, green.1),
+            Chromaticity::new(blue.0, blue.1),
+            WhitePoint::Custom(Chromaticity::new(white.0, white.1).to_xyz(1_f64)),
+            transfer,
+        )
+        .unwrap()
+    }
+
+    // IEC 61966-2-1
+    // the white is WhitePoint::D65 like the XYZ based converters (Lindbloom's matrix)
+    pub fn srgb() -> Self {
+        Self::new(
+            Chromaticity::new(0.64, 0.33),
+            Chromaticity::new(0.30, 0.60),
+            Chromaticity::new(0.15, 0.06),
+            WhitePoint::D65,
+            TransferFunction::Srgb,
+        )
+        .unwrap()
+    }
+
+    // SMPTE EG 432-1
+    pub fn display_p3() -> Self {
+        Self::standard(
+            (0.680, 0.320),
+            (0.265, 0.690),
+            (0.150, 0.060),
+            D65,
+            TransferFunction::Srgb,
+        )
+    }
+
+    // Adobe RGB (1998) Color Image Encoding
+    pub fn adobe_rgb() -> Self {
+        Self::standard(
+            (0.64, 0.33),
+            (0.21, 0.71),
+            (0.15, 0.06),
+            D65,
+            TransferFunction::Gamma(563_f64 / 256_f64),
+        )
+    }
+
+    // ITU-R BT.709
+    pub fn rec709() -> Self {
+        Self::standard(
+            (0.64, 0.33),
+            (0.30, 0.60),
+            (0.15, 0.06),
+            D65,
+            TransferFunction::Bt709,
+        )
+    }
+
+    // ITU-R BT.2020
+    pub fn rec2020() -> Self {
+        Self::standard(
+            (0.708, 0.292),
+            (0.170, 0.797),
+            (0.131, 0.046),
+            D65,
+            TransferFunction::Bt2020,
+        )
+    }
+
+    // ITU-R BT.2100 PQ, linear 1.0 is 10000 cd/m2
+    pub fn rec2100_pq() -> Self {
+        Self::standard(
+            (0.708, 0.292),
+            (0.170, 0.797),
+            (0.131, 0.046),
+            D65,
+            TransferFunction::Pq,
+        )
+    }
+
+    // ITU-R BT.2100 HLG, linear values are scene light
+    pub fn rec2100_hlg() -> Self {
+        Self::standard(
+            (0.708, 0.292),
+            (0.170, 0.797),
+            (0.131, 0.046),
+            D65,
+            TransferFunction::Hlg,
+        )
+    }
+
+    // sRGB primaries without the transfer function
+    pub fn linear_srgb() -> Self {
+        Self::srgb().with_transfers([
+            TransferFunction::Linear,
+            TransferFunction::Linear,
+            TransferFunction::Linear,
+        ])
+    }
+
+    // SMPTE ST 2065-1 (AP0, linear)
+    pub fn aces2065_1() -> Self {
+        Self::aces(AP0, TransferFunction::Linear)
+    }
+
+    // Academy S-2014-004 (AP1, linear)
+    pub fn acescg() -> Self {
+        Self::aces(AP1, TransferFunction::Linear)
+    }
+
+    // Academy S-2014-003 (AP1, logarithmic)
+    pub fn acescc() -> Self {
+        Self::aces(AP1, TransferFunction::AcesCc)
+    }
+
+    // Academy S-2016-001 (AP1, logarithmic with a toe)
+    pub fn acescct() -> Self {
+        Self::aces(AP1, TransferFunction::AcesCct)
+    }
+
+    fn aces(primaries: [(f64, f64); 3], transfer: TransferFunction) -> Self {
+        Self::standard(
+            primaries[0],
+            primaries[1],
+            primaries[2],
+            ACES_WHITE,
+            transfer,
+        )
+    }
+
+    // Sony S-Gamut3 / S-Log3
+    pub fn s_gamut3_s_log3() -> Self {
+        Self::standard(
+            (0.730, 0.280),
+            (0.140, 0.855),
+            (0.100, -0.050),
+            D65,
+            TransferFunction::SLog3,
+        )
+    }
+
+    // Sony S-Gamut3.Cine / S-Log3
+    pub fn s_gamut3_cine_s_log3() -> Self {
+        Self::standard(
+            (0.766, 0.275),
+            (0.225, 0.800),
+            (0.089, -0.087),
+            D65,
+            TransferFunction::SLog3,
+        )
+    }
+
+    // ARRI Wide Gamut 3 / LogC3
+    pub fn arri_wide_gamut3_logc3() -> Self {
+        Self::standard(
+            (0.6840, 0.3130),
+            (0.2210, 0.8480),
+            (0.0861, -0.1020),
+            D65,
+            TransferFunction::LogC3,
+        )
+    }
+
+    // Panasonic V-Gamut / V-Log
+    pub fn v_gamut_v_log() -> Self {
+        Self::standard(
+            (0.730, 0.280),
+            (0.165, 0.840),
+            (0.100, -0.030),
+            D65,
+            TransferFunction::VLog,
+        )
+    }
+
+    // Canon Cinema Gamut / Canon Log
+    pub fn cinema_gamut_canon_log() -> Self {
+        Self::standard(
+            (0.740, 0.270),
+            (0.170, 1.140),
+            (0.080, -0.100),
+            D65,
+            TransferFunction::CanonLog,
+        )
+    }
+
+    // REDWideGamutRGB / Log3G10
+    pub fn red_wide_gamut_log3g10() -> Self {
+        Self::standard(
+            (0.780308, 0.304253),
+            (0.121595, 1.493994),
+            (0.095612, -0.084589),
+            D65,
+            TransferFunction::Log3G10,
+        )
+    }
+
+    // ROMM RGB (ISO 22028-2)
+    pub fn prophoto_rgb() -> Self {
+        Self::standard(
+            (0.7347, 0.2653),
+            (0.1596, 0.8404),
+            (0.0366, 0.0001),
+            (0.3457, 0.3585),
+            TransferFunction::ProPhoto,
+        )
+    }
+
+    pub fn red(&self) -> Chromaticity {
+        self.red
+    }
+
+    pub fn green(&self) -> Chromaticity {
+        self.green
+    }
+
+    pub fn blue(&self) -> Chromaticity {
+        self.blue
+    }
+
+    pub fn white_point(&self) -> WhitePoint {
+        self.white_point
+    }
+
+    // per channel transfer functions, such as a calibrated display
+    pub fn with_transfers(self, transfers: [TransferFunction; 3]) -> Self {
+        Self { transfers, ..self }
+    }
+
+    // the transfer function shared by all channels, None if the channels differ (see transfers())
+    pub fn transfer(&self) -> Option<&TransferFunction> {
+        let [red, green, blue] = &self.transfers;
+        if red == green && red == blue {
+            Some(red)
+        } else {
+            None
+        }
+    }
+
+    pub fn transfers(&self) -> &[TransferFunction; 3] {
+        &self.transfers
+    }
+
+    // linear RGB => XYZ (relative to the white point of this space)
+    pub fn to_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        self.to_xyz
+    }
+
+    pub fn from_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        self.from_xyz
+    }
+
+    pub fn encode(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        FloatRgbColor::from_rgba(
+            self.transfers[0].encode(color.r()),
+            self.transfers[1].encode(color.g()),
+            self.transfers[2].encode(color.b()),
+            color.a(),
+        )
+    }
+
+    pub fn decode(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        FloatRgbColor::from_rgba(
+            self.transfers[0].decode(color.r()),
+            self.transfers[1].decode(color.g()),
+            self.transfers[2].decode(color.b()),
+            color.a(),
+        )
+    }
+
+    pub fn to_xyz(&self, color: &FloatRgbColor) -> XyzColor {
+        let linear = self.decode(color);
+        XyzColor::from_array(
+            matrix::multiply_vector(&self.to_xyz, linear.to_array()),
+            color.a(),
+        )
+    }
+
+    pub fn from_xyz(&self, color: &XyzColor) -> FloatRgbColor {
+        let linear = FloatRgbColor::from_array(
+            matrix::multiply_vector(&self.from_xyz, color.to_array()),
+            color.a(),
+        );
+        self.encode(&linear)
+    }
+
+    pub(crate) fn srgb_transfer_encode(linear: [f64; 3], alpha: f64) -> FloatRgbColor {
+        FloatRgbColor::from_rgba(
+            TransferFunction::Srgb.encode(linear[0]),
+            TransferFunction::Srgb.encode(linear[1]),
+            TransferFunction::Srgb.encode(linear[2]),
+            alpha,
+        )
+    }
+
+    // linear RGB (self) => linear RGB (destination), Bradford adaptation between white points
+    pub(crate) fn conversion_matrix(&self, destination: &RgbSpace) -> Matrix3 {
+        let to_xyz = if self.white_point == destination.white_point {
+            self.to_xyz
+        } else {
+            // the white points are validated in new()
+            let adaptation = ChromaticAdaptation::new(self.white_point, destination.white_point)
+                .unwrap()
+                .matrix();
+            matrix::multiply(&adaptation, &self.to_xyz)
+        };
+        matrix::multiply(&destination.from_xyz, &to_xyz)
+    }
+}
+
+// sRGB of the converters (XYZ, Luv, Oklab, ...), built once
+pub(crate) fn srgb() -> &'static RgbSpace {
+    static SRGB: OnceLock<RgbSpace> = OnceLock::new();
+    SRGB.get_or_init(RgbSpace::srgb)
+}
+
+const D65: (f64, f64) = (0.3127, 0.3290);
+const ACES_WHITE: (f64, f64) = (0.32168, 0.33767);
+const AP0: [(f64, f64); 3] = [(0.7347, 0.2653), (0.0, 1.0), (0.0001, -0.0770)];
+const AP1: [(f64, f64); 3] = [(0.713, 0.293), (0.165, 0.830), (0.128, 0.044)];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transfer::ParametricCurve;
+
+    fn assert_matrix_eq(actual: [[f64; 3]; 3], expected: [[f64; 3]; 3], tolerance: f64) {
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!(
+                    (actual[i][j] - expected[i][j]).abs() < tolerance,
+                    "{:?} {:?}",
+                    actual,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_space_srgb_matrix_test() {
+        assert_matrix_eq(
+            RgbSpace::srgb().to_xyz_matrix(),
+            // http://www.brucelindbloom.com/index.html?Eqn_RGB_XYZ_Matrix.html
+            [
+                [0.4124564, 0.3575761, 0.1804375],
+                [0.2126729, 0.7151522, 0.0721750],
+                [0.0193339, 0.1191920, 0.9503041],
+            ],
+            1e-6,
+        );
+        assert_eq!(RgbSpace::srgb().white_point(), WhitePoint::D65);
+        assert_eq!(
+            RgbSpace::srgb().conversion_matrix(srgb()),
+            matrix::multiply(&srgb().from_xyz_matrix(), &srgb().to_xyz_matrix())
+        );
+    }
+
+    #[test]
+    fn rgb_space_display_p3_matrix_test() {
+        assert_matrix_eq(
+            RgbSpace::display_p3().to_xyz_matrix(),
+            [
+                [0.4865709, 0.2656677, 0.1982173],
+                [0.2289746, 0.6917385, 0.0792869],
+                [0.0000000, 0.0451134, 1.0439444],
+            ],
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn rgb_space_rec2020_matrix_test() {
+        assert_matrix_eq(
+            RgbSpace::rec2020().to_xyz_matrix(),
+            [
+                [0.6369580, 0.1446169, 0.1688810],
+                [0.2627002, 0.6779981, 0.0593017],
+                [0.0000000, 0.0280727, 1.0609851],
+            ],
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn rgb_space_white_test() {
+        let spaces = [
+            RgbSpace::srgb(),
+            RgbSpace::display_p3(),
+            RgbSpace::adobe_rgb(),
+            RgbSpace::rec709(),
+            RgbSpace::rec2020(),
+            RgbSpace::prophoto_rgb(),
+            RgbSpace::linear_srgb(),
+            RgbSpace::aces2065_1(),
+            RgbSpace::acescg(),
+        ];
+        for space in spaces.iter() {
+            let white = space.to_xyz(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+            let expected = space.white_point().xyz();
+            assert!((white.x() - expected.x()).abs() < 1e-12);
+            assert!((white.y() - 1_f64).abs() < 1e-12);
+            assert!((white.z() - expected.z()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn rgb_space_conversion_matrix_test() {
+        let m = RgbSpace::srgb().conversion_matrix(&RgbSpace::display_p3());
+        let red = matrix::multiply_vector(&m, [1_f64, 0_f64, 0_f64]);
+        let p3 = RgbSpace::display_p3().encode(&FloatRgbColor::from_array(red, 1_f64));
+
+        assert!((p3.r() - 0.9175).abs() < 1e-4);
+        assert!((p3.g() - 0.2003).abs() < 1e-4);
+        assert!((p3.b() - 0.1386).abs() < 1e-4);
+    }
+
+    #[test]
+    fn rgb_space_rec2100_test() {
+        let pq = RgbSpace::rec2100_pq();
+        let hlg = RgbSpace::rec2100_hlg();
+        assert_eq!(pq.to_xyz_matrix(), RgbSpace::rec2020().to_xyz_matrix());
+        assert_eq!(hlg.to_xyz_matrix(), RgbSpace::rec2020().to_xyz_matrix());
+
+        // 100 cd/m2 white
+        let white = pq.encode(&FloatRgbColor::new(0.01, 0.01, 0.01));
+        assert!((white.g() - 0.508078).abs() < 1e-6);
+        let white = hlg.encode(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert!((white.g() - 1_f64).abs() < 1e-8);
+    }
+
+    #[test]
+    fn rgb_space_aces_matrix_test() {
+        // SMPTE ST 2065-1
+        assert_matrix_eq(
+            RgbSpace::aces2065_1().to_xyz_matrix(),
+            [
+                [0.9525523959, 0.0000000000, 0.0000936786],
+                [0.3439664498, 0.7281660966, -0.0721325464],
+                [0.0000000000, 0.0000000000, 1.0088251844],
+            ],
+            1e-8,
+        );
+        // Academy S-2014-004
+        assert_matrix_eq(
+            RgbSpace::acescg().to_xyz_matrix(),
+            [
+                [0.6624541811, 0.1340042065, 0.1561876870],
+                [0.2722287168, 0.6740817658, 0.0536895174],
+                [-0.0055746495, 0.0040607335, 1.0103391003],
+            ],
+            1e-8,
+        );
+        assert_eq!(
+            RgbSpace::acescc().to_xyz_matrix(),
+            RgbSpace::acescg().to_xyz_matrix()
+        );
+        assert_eq!(
+            RgbSpace::acescct().to_xyz_matrix(),
+            RgbSpace::acescg().to_xyz_matrix()
+        );
+    }
+
+    #[test]
+    fn rgb_space_aces_conversion_test() {
+        // the same white point, no adaptation between AP0 and AP1
+        let m = RgbSpace::aces2065_1().conversion_matrix(&RgbSpace::acescg());
+        assert_matrix_eq(
+            m,
+            [
+                [1.4514393161, -0.2365107469, -0.2149285693],
+                [-0.0765537734, 1.1762296998, -0.0996759264],
+                [0.0083161484, -0.0060324498, 0.9977163014],
+            ],
+            1e-8,
+        );
+
+        // 18% gray is kept through the log encodings
+        let gray = FloatRgbColor::new(0.18, 0.18, 0.18);
+        for space in [RgbSpace::acescc(), RgbSpace::acescct()] {
+            let encoded = space.encode(&gray);
+            assert!((encoded.r() - 0.413588).abs() < 1e-6, "{}", encoded);
+            let decoded = space.decode(&encoded);
+            assert!((decoded.g() - 0.18).abs() < 1e-12);
+        }
+
+        // linear sRGB white is ACES white after the adaptation
+        let m = RgbSpace::linear_srgb().conversion_matrix(&RgbSpace::acescg());
+        let white = matrix::multiply_vector(&m, [1_f64, 1_f64, 1_f64]);
+        assert!(
+            white.iter().all(|v| (v - 1_f64).abs() < 1e-9),
+            "{:?}",
+            white
+        );
+    }
+
+    #[test]
+    fn rgb_space_camera_matrix_test() {
+        // Sony technical summary
+        assert_matrix_eq(
+            RgbSpace::s_gamut3_s_log3().to_xyz_matrix(),
+            [
+                [0.7064827132, 0.1288010498, 0.1151721641],
+                [0.2709796708, 0.7866064112, -0.0575860820],
+                [-0.0096778454, 0.0046000375, 1.0941355587],
+            ],
+            1e-8,
+        );
+        // ARRI (rounded to 6 digits)
+        assert_matrix_eq(
+            RgbSpace::arri_wide_gamut3_logc3().to_xyz_matrix(),
+            [
+                [0.638008, 0.214704, 0.097744],
+                [0.291954, 0.823841, -0.115795],
+                [0.002798, -0.067034, 1.153294],
+            ],
+            1e-5,
+        );
+
+        for space in [
+            RgbSpace::s_gamut3_s_log3(),
+            RgbSpace::s_gamut3_cine_s_log3(),
+            RgbSpace::arri_wide_gamut3_logc3(),
+            RgbSpace::v_gamut_v_log(),
+            RgbSpace::cinema_gamut_canon_log(),
+            RgbSpace::red_wide_gamut_log3g10(),
+        ] {
+            let gray = space.encode(&FloatRgbColor::new(0.18, 0.18, 0.18));
+            let xyz = space.to_xyz(&gray);
+            assert!((xyz.y() - 0.18).abs() < 1e-9, "{:?}", space.transfer());
+        }
+    }
+
+    #[test]
+    fn rgb_space_transfers_test() {
+        let space = RgbSpace::srgb().with_transfers([
+            TransferFunction::Gamma(2_f64),
+            TransferFunction::Linear,
+            TransferFunction::Srgb,
+        ]);
+        assert_eq!(space.transfer(), None);
+        assert_eq!(RgbSpace::srgb().transfer(), Some(&TransferFunction::Srgb));
+        assert_eq!(space.transfers()[1], TransferFunction::Linear);
+        let decoded = space.decode(&FloatRgbColor::new(0.5, 0.5, 0.5));
+        assert_eq!(decoded.r(), 0.25);
+        assert_eq!(decoded.g(), 0.5);
+        assert!((decoded.b() - 0.2140).abs() < 1e-4);
+        let encoded = space.encode(&decoded);
+        assert!((encoded.r() - 0.5).abs() < 1e-12);
+        assert!((encoded.b() - 0.5).abs() < 1e-12);
+        assert_eq!(space.to_xyz_matrix(), RgbSpace::srgb().to_xyz_matrix());
+    }
+
+    #[test]
+    fn rgb_space_custom_test() {
+        let space = RgbSpace::new(
+            Chromaticity::new(0.69, 0.30),
+            Chromaticity::new(0.17, 0.72),
+            Chromaticity::new(0.135, 0.05),
+            WhitePoint::D50,
+            TransferFunction::Parametric(ParametricCurve::gamma(2.2).unwrap()),
+        )
+        .unwrap();
+
+        let white = space.to_xyz(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert!((white.x() - 0.96422).abs() < 1e-12);
+        assert!((white.z() - 0.82521).abs() < 1e-12);
+
+        let red = space.to_xyz(&FloatRgbColor::new(1_f64, 0_f64, 0_f64));
+        let xy = Chromaticity::from_xyz(&red);
+        assert!((xy.x() - 0.69).abs() < 1e-12);
+        assert!((xy.y() - 0.30).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rgb_space_collinear_primaries_test() {
+        let space = RgbSpace::new(
+            Chromaticity::new(0.1, 0.1),
+            Chromaticity::new(0.2, 0.2),
+            Chromaticity::new(0.3, 0.3),
+            WhitePoint::D65,
+            TransferFunction::Linear,
+        );
+        assert!(space.is_err());
+    }
+
+    #[test]
+    fn rgb_space_converter_srgb_test() {
+        assert_eq!(*srgb(), RgbSpace::srgb());
+        assert!(std::ptr::eq(srgb(), srgb()));
+    }
+
+    #[test]
+    fn rgb_space_invalid_white_point_test() {
+        let srgb = RgbSpace::srgb();
+        let space = RgbSpace::new(
+            srgb.red(),
+            srgb.green(),
+            srgb.blue(),
+            WhitePoint::Custom(XyzColor::new(0_f64, 0_f64, 0_f64)),
+            TransferFunction::Linear,
+        );
+        assert!(space.is_err());
+    }
+}
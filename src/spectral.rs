@@ -0,0 +1,284 @@
+use crate::cmf;
+use crate::errors;
+use crate::errors::Result;
+use crate::illuminant::{Illuminant, Observer};
+use crate::xyz::XyzColor;
+
+pub const MIN_WAVELENGTH: f64 = cmf::MIN_WAVELENGTH as f64;
+pub const MAX_WAVELENGTH: f64 = cmf::MAX_WAVELENGTH as f64;
+
+// CIE color matching functions (5nm tables, linearly interpolated, zero outside 380nm - 780nm)
+pub fn color_matching_function(observer: Observer, wavelength: f64) -> [f64; 3] {
+    let table = observer_table(observer);
+    let position = (wavelength - MIN_WAVELENGTH) / cmf::WAVELENGTH_STEP as f64;
+    if !(0_f64..=(table.len() - 1) as f64).contains(&position) {
+        return [0_f64; 3];
+    }
+    let index = (position.floor() as usize).min(table.len() - 2);
+    let t = position - index as f64;
+    let (low, high) = (table[index], table[index + 1]);
+    [0, 1, 2].map(|i| low[i] + (high[i] - low[i]) * t)
+}
+
+fn observer_table(observer: Observer) -> &'static [[f64; 3]; 81] {
+    match observer {
+        Observer::Cie1931 => &cmf::CIE1931,
+        Observer::Cie1964 => &cmf::CIE1964,
+    }
+}
+
+// spectrum sampled at regular wavelength intervals (nm)
+#[derive(Debug, PartialEq, Clone)]
+pub struct SampledSpectrum {
+    start: f64,
+    interval: f64,
+    values: Vec<f64>,
+}
+
+impl SampledSpectrum {
+    pub fn new(start: f64, interval: f64, values: Vec<f64>) -> Result<Self> {
+        if values.is_empty() || !start.is_finite() || !interval.is_finite() || interval <= 0_f64 {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!(
+                    "start {} interval {} samples {}",
+                    start,
+                    interval,
+                    values.len()
+                ),
+            ));
+        }
+        Ok(Self {
+            start,
+            interval,
+            values,
+        })
+    }
+
+    fn from_fn(f: impl Fn(f64) -> f64) -> Self {
+        let values = (cmf::MIN_WAVELENGTH..=cmf::MAX_WAVELENGTH)
+            .step_by(cmf::WAVELENGTH_STEP as usize)
+            .map(|wavelength| f(wavelength as f64))
+            .collect();
+        Self {
+            start: MIN_WAVELENGTH,
+            interval: cmf::WAVELENGTH_STEP as f64,
+            values,
+        }
+    }
+
+    // equal energy
+    pub fn constant(value: f64) -> Self {
+        Self::from_fn(|_| value)
+    }
+
+    // Planckian radiator normalized to 100 at 560nm
+    pub fn blackbody(kelvin: f64) -> Self {
+        let reference = cmf::planck(560_f64, kelvin);
+        Self::from_fn(|wavelength| 100_f64 * cmf::planck(wavelength, kelvin) / reference)
+    }
+
+    // CIE daylight illuminant (4000K - 25000K)
+    pub fn daylight(kelvin: f64) -> Result<Self> {
+        if !(4000_f64..=25000_f64).contains(&kelvin) {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                kelvin.to_string(),
+            ));
+        }
+
+        // CIE 15:2004 (3.3)
+        let x = if kelvin <= 7000_f64 {
+            -4.6070e9 / kelvin.powi(3) + 2.9678e6 / kelvin.powi(2) + 0.09911e3 / kelvin + 0.244063
+        } else {
+            -2.0064e9 / kelvin.powi(3) + 1.9018e6 / kelvin.powi(2) + 0.24748e3 / kelvin + 0.237040
+        };
+        let y = -3_f64 * x * x + 2.870 * x - 0.275;
+        let m = 0.0241 + 0.2562 * x - 0.7341 * y;
+        // M1 and M2 are rounded to three decimals as in CIE 15
+        let m1 = ((-1.3515 - 1.7703 * x + 5.9114 * y) / m * 1000_f64).round() / 1000_f64;
+        let m2 = ((0.0300 - 31.4424 * x + 30.0717 * y) / m * 1000_f64).round() / 1000_f64;
+
+        let components = &cmf::DAYLIGHT_COMPONENTS;
+        Ok(Self::from_fn(|wavelength| {
+            // the 10nm components are linearly interpolated to 5nm
+            let position = (wavelength - MIN_WAVELENGTH) / 10_f64;
+            let index = (position.floor() as usize).min(components.len() - 2);
+            let t = position - index as f64;
+            let [s0, s1, s2] = [0, 1, 2]
+                .map(|i| components[index][i] * (1_f64 - t) + components[index + 1][i] * t);
+            s0 + m1 * s1 + m2 * s2
+        }))
+    }
+
+    // only the illuminants defined by a formula or the daylight basis functions are available
+    pub fn from_illuminant(illuminant: Illuminant) -> Option<Self> {
+        // nominal CCT of the daylight illuminants are scaled by the change of c2 (1.4380e-2 to 1.4388e-2)
+        let daylight = |kelvin: f64| Self::daylight(kelvin * 1.4388 / 1.4380).ok();
+        match illuminant {
+            Illuminant::A => Some(Self::blackbody(2856_f64)),
+            Illuminant::D50 => daylight(5000_f64),
+            Illuminant::D55 => daylight(5500_f64),
+            Illuminant::D65 => daylight(6500_f64),
+            Illuminant::D75 => daylight(7500_f64),
+            Illuminant::E => Some(Self::constant(100_f64)),
+            _ => None,
+        }
+    }
+
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    pub fn end(&self) -> f64 {
+        self.start + self.interval * (self.values.len() - 1) as f64
+    }
+
+    pub fn interval(&self) -> f64 {
+        self.interval
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    // linear interpolation, outside of the sampled range the nearest sample is repeated (CIE 15:2004 7.2.2.1)
+    pub fn value_at(&self, wavelength: f64) -> f64 {
+        let last = self.values.len() - 1;
+        let position = (wavelength - self.start) / self.interval;
+        if position <= 0_f64 {
+            return self.values[0];
+        }
+        if position >= last as f64 {
+            return self.values[last];
+        }
+        let index = position.floor() as usize;
+        let t = position - index as f64;
+        self.values[index] + (self.values[index + 1] - self.values[index]) * t
+    }
+
+    // emission, normalized to Y = 1
+    pub fn to_xyz(&self, observer: Observer) -> XyzColor {
+        let [x, y, z] = integrate(observer, |wavelength| self.value_at(wavelength));
+        if y == 0_f64 {
+            return XyzColor::new(0_f64, 0_f64, 0_f64);
+        }
+        XyzColor::new(x / y, 1_f64, z / y)
+    }
+
+    // reflectance (or transmittance) under the illuminant, the perfect reflecting diffuser is Y = 1
+    pub fn reflectance_to_xyz(&self, illuminant: &SampledSpectrum, observer: Observer) -> XyzColor {
+        let [_, white, _] = integrate(observer, |wavelength| illuminant.value_at(wavelength));
+        let [x, y, z] = integrate(observer, |wavelength| {
+            self.value_at(wavelength) * illuminant.value_at(wavelength)
+        });
+        if white == 0_f64 {
+            return XyzColor::new(0_f64, 0_f64, 0_f64);
+        }
+        XyzColor::new(x / white, y / white, z / white)
+    }
+}
+
+fn integrate(observer: Observer, spectrum: impl Fn(f64) -> f64) -> [f64; 3] {
+    let wavelengths =
+        (cmf::MIN_WAVELENGTH..=cmf::MAX_WAVELENGTH).step_by(cmf::WAVELENGTH_STEP as usize);
+    let mut xyz = [0_f64; 3];
+    for (wavelength, matching) in wavelengths.zip(observer_table(observer)) {
+        let power = spectrum(wavelength as f64);
+        for (sum, value) in xyz.iter_mut().zip(matching) {
+            *sum += power * value;
+        }
+    }
+    xyz
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chromaticity::Chromaticity;
+
+    fn assert_chromaticity(actual: &XyzColor, expected: Option<Chromaticity>) {
+        let actual = Chromaticity::from_xyz(actual);
+        let expected = expected.unwrap();
+        assert!(actual.distance(&expected) < 1e-4, "{} {}", actual, expected);
+    }
+
+    #[test]
+    fn sampled_spectrum_new_test() {
+        assert!(SampledSpectrum::new(380_f64, 10_f64, vec![]).is_err());
+        assert!(SampledSpectrum::new(380_f64, 0_f64, vec![1_f64]).is_err());
+        assert!(SampledSpectrum::new(f64::NAN, 10_f64, vec![1_f64]).is_err());
+
+        let spectrum = SampledSpectrum::new(380_f64, 10_f64, vec![0_f64, 1_f64, 0.5]).unwrap();
+        assert_eq!(spectrum.end(), 400_f64);
+        assert_eq!(spectrum.value_at(385_f64), 0.5);
+        assert_eq!(spectrum.value_at(395_f64), 0.75);
+        assert_eq!(spectrum.value_at(300_f64), 0_f64);
+        assert_eq!(spectrum.value_at(700_f64), 0.5);
+    }
+
+    #[test]
+    fn color_matching_function_test() {
+        assert_eq!(
+            color_matching_function(Observer::Cie1931, 555_f64),
+            [0.51205, 1.0, 0.00575]
+        );
+        let [x, y, z] = color_matching_function(Observer::Cie1931, 557.5);
+        assert!((x - 0.553275).abs() < 1e-9);
+        assert!((y - 0.9975).abs() < 1e-9);
+        assert!((z - 0.004825).abs() < 1e-9);
+        assert_eq!(
+            color_matching_function(Observer::Cie1964, 780_f64),
+            [0.000033, 0.000013, 0.0]
+        );
+        assert_eq!(
+            color_matching_function(Observer::Cie1964, 300_f64),
+            [0_f64; 3]
+        );
+    }
+
+    #[test]
+    fn illuminant_spectrum_test() {
+        for illuminant in [
+            Illuminant::A,
+            Illuminant::D50,
+            Illuminant::D65,
+            Illuminant::D75,
+            Illuminant::E,
+        ] {
+            let spectrum = SampledSpectrum::from_illuminant(illuminant).unwrap();
+            for observer in [Observer::Cie1931, Observer::Cie1964] {
+                assert_chromaticity(
+                    &spectrum.to_xyz(observer),
+                    illuminant.chromaticity(observer),
+                );
+            }
+        }
+        assert!(SampledSpectrum::from_illuminant(Illuminant::F2).is_none());
+    }
+
+    #[test]
+    fn daylight_test() {
+        // CIE 15:2004 Table T.1 (D65)
+        let d65 = SampledSpectrum::from_illuminant(Illuminant::D65).unwrap();
+        assert!((d65.value_at(380_f64) - 49.9755).abs() < 1e-3);
+        assert!((d65.value_at(560_f64) - 100_f64).abs() < 1e-3);
+        assert!((d65.value_at(780_f64) - 63.3828).abs() < 1e-3);
+
+        assert!(SampledSpectrum::daylight(3000_f64).is_err());
+    }
+
+    #[test]
+    fn reflectance_to_xyz_test() {
+        let d65 = SampledSpectrum::from_illuminant(Illuminant::D65).unwrap();
+        let white = SampledSpectrum::new(380_f64, 10_f64, vec![1_f64; 36]).unwrap();
+        let xyz = white.reflectance_to_xyz(&d65, Observer::Cie1931);
+        assert!((xyz.x() - 0.95047).abs() < 1e-4);
+        assert_eq!(xyz.y(), 1_f64);
+        assert!((xyz.z() - 1.08883).abs() < 1e-4);
+
+        let gray = SampledSpectrum::new(380_f64, 10_f64, vec![0.2; 36]).unwrap();
+        let xyz = gray.reflectance_to_xyz(&d65, Observer::Cie1964);
+        assert!((xyz.y() - 0.2).abs() < 1e-12);
+    }
+}
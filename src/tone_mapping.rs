@@ -0,0 +1,215 @@
+use crate::float_rgb::FloatRgbColor;
+use crate::matrix;
+use crate::matrix::Matrix3;
+use crate::transfer::TransferFunction;
+
+// J. Hable, "Filmic Tonemapping Operators" (Uncharted 2)
+const HABLE_A: f64 = 0.15;
+const HABLE_B: f64 = 0.50;
+const HABLE_C: f64 = 0.10;
+const HABLE_D: f64 = 0.20;
+const HABLE_E: f64 = 0.02;
+const HABLE_F: f64 = 0.30;
+const HABLE_EXPOSURE_BIAS: f64 = 2_f64;
+pub const HABLE_WHITE: f64 = 11.2;
+
+// AgX base (T. Sobotka), rec.709 primaries inset and outset for the log encoding
+const AGX_INSET: Matrix3 = [
+    [0.842479062253094, 0.0784335999999992, 0.0792237451477643],
+    [0.0423282422610123, 0.878468636469772, 0.0791661274605434],
+    [0.0423756549057051, 0.0784336, 0.879142973793104],
+];
+
+const AGX_OUTSET: Matrix3 = [
+    [1.19687900512017, -0.0980208811401368, -0.0990297440797205],
+    [-0.0528968517574562, 1.15190312990417, -0.0989611768448433],
+    [-0.0529716355144438, -0.0980434501171241, 1.15107367264116],
+];
+
+const AGX_MIN_EV: f64 = -12.47393;
+const AGX_MAX_EV: f64 = 4.026069;
+// the AgX sigmoid produces display values for a 2.2 gamma
+const AGX_DISPLAY_GAMMA: f64 = 2.2;
+
+// linear HDR values (1.0 is the diffuse white) => linear display values (0 - 1)
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub enum ToneMapping {
+    // per channel clamp to [0, 1]
+    #[default]
+    Clip,
+    // x / (1 + x)
+    Reinhard,
+    // Reinhard with the white point (the smallest value mapped to 1)
+    ExtendedReinhard(f64),
+    // Hable (Uncharted 2) filmic curve with the white point, HABLE_WHITE is the original
+    Hable(f64),
+    // K. Narkowicz, "ACES Filmic Tone Mapping Curve"
+    AcesFitted,
+    // AgX base without look
+    AgX,
+    // ITU-R BT.2390 EETF in the PQ domain, source and target peak luminance in cd/m2
+    // the linear value 1.0 is the target peak
+    Bt2390 {
+        source_peak: f64,
+        target_peak: f64,
+    },
+}
+
+impl ToneMapping {
+    pub fn map(&self, color: &FloatRgbColor) -> FloatRgbColor {
+        let rgb = color.to_array();
+        let mapped = match self {
+            Self::Clip => rgb.map(|v| v.clamp(0_f64, 1_f64)),
+            Self::Reinhard => rgb.map(|v| {
+                let v = v.max(0_f64);
+                v / (1_f64 + v)
+            }),
+            Self::ExtendedReinhard(white) => {
+                let white_squared = white.max(f64::EPSILON).powi(2);
+                rgb.map(|v| {
+                    let v = v.max(0_f64);
+                    (v * (1_f64 + v / white_squared) / (1_f64 + v)).min(1_f64)
+                })
+            }
+            Self::Hable(white) => {
+                let scale = hable(*white);
+                rgb.map(|v| (hable(HABLE_EXPOSURE_BIAS * v.max(0_f64)) / scale).min(1_f64))
+            }
+            Self::AcesFitted => rgb.map(|v| {
+                let v = v.max(0_f64);
+                (v * (2.51 * v + 0.03) / (v * (2.43 * v + 0.59) + 0.14)).clamp(0_f64, 1_f64)
+            }),
+            Self::AgX => agx(rgb),
+            Self::Bt2390 {
+                source_peak,
+                target_peak,
+            } => rgb.map(|v| bt2390(v, *source_peak, *target_peak)),
+        };
+        FloatRgbColor::from_array(mapped, color.a())
+    }
+}
+
+fn hable(x: f64) -> f64 {
+    (x * (HABLE_A * x + HABLE_C * HABLE_B) + HABLE_D * HABLE_E)
+        / (x * (HABLE_A * x + HABLE_B) + HABLE_D * HABLE_F)
+        - HABLE_E / HABLE_F
+}
+
+fn agx(rgb: [f64; 3]) -> [f64; 3] {
+    let inset = matrix::multiply_vector(&AGX_INSET, rgb);
+    let encoded = inset.map(|v| {
+        let ev = v
+            .max(f64::MIN_POSITIVE)
+            .log2()
+            .clamp(AGX_MIN_EV, AGX_MAX_EV);
+        agx_contrast((ev - AGX_MIN_EV) / (AGX_MAX_EV - AGX_MIN_EV))
+    });
+    matrix::multiply_vector(&AGX_OUTSET, encoded)
+        .map(|v| v.clamp(0_f64, 1_f64).powf(AGX_DISPLAY_GAMMA))
+}
+
+// 6th order polynomial fit of the AgX default contrast sigmoid
+fn agx_contrast(x: f64) -> f64 {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    15.5 * x4 * x2 - 40.14 * x4 * x + 31.96 * x4 - 6.868 * x2 * x + 0.4298 * x2 + 0.1191 * x
+        - 0.00232
+}
+
+// ITU-R BT.2390-10 5.4 (black level 0)
+fn bt2390(value: f64, source_peak: f64, target_peak: f64) -> f64 {
+    let pq = |luminance: f64| TransferFunction::Pq.encode(luminance / 10000_f64);
+    let source = pq(source_peak);
+    if source <= 0_f64 || target_peak <= 0_f64 {
+        return 0_f64;
+    }
+    let e1 = pq(value.max(0_f64) * target_peak) / source;
+    let max_luminance = pq(target_peak) / source;
+    let knee = 1.5 * max_luminance - 0.5;
+
+    let e2 = if e1 < knee || knee >= 1_f64 {
+        e1
+    } else {
+        let t = (e1.min(1_f64) - knee) / (1_f64 - knee);
+        let (t2, t3) = (t * t, t * t * t);
+        (2_f64 * t3 - 3_f64 * t2 + 1_f64) * knee
+            + (t3 - 2_f64 * t2 + t) * (1_f64 - knee)
+            + (-2_f64 * t3 + 3_f64 * t2) * max_luminance
+    };
+    let luminance = TransferFunction::Pq.decode(e2 * source) * 10000_f64;
+    (luminance / target_peak).clamp(0_f64, 1_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gray(value: f64) -> FloatRgbColor {
+        FloatRgbColor::new(value, value, value)
+    }
+
+    #[test]
+    fn reinhard_test() {
+        assert_eq!(ToneMapping::Reinhard.map(&gray(1_f64)).r(), 0.5);
+        assert_eq!(ToneMapping::Reinhard.map(&gray(-1_f64)).r(), 0_f64);
+
+        let extended = ToneMapping::ExtendedReinhard(4_f64);
+        assert!((extended.map(&gray(4_f64)).g() - 1_f64).abs() < 1e-12);
+        assert_eq!(extended.map(&gray(10_f64)).g(), 1_f64);
+        assert!(extended.map(&gray(1_f64)).g() > 0.5);
+    }
+
+    #[test]
+    fn hable_test() {
+        let hable = ToneMapping::Hable(HABLE_WHITE);
+        assert_eq!(hable.map(&gray(0_f64)).r(), 0_f64);
+        assert!((hable.map(&gray(HABLE_WHITE / 2_f64)).r() - 1_f64).abs() < 1e-12);
+        assert_eq!(hable.map(&gray(100_f64)).r(), 1_f64);
+    }
+
+    #[test]
+    fn aces_fitted_test() {
+        let aces = ToneMapping::AcesFitted;
+        assert!(aces.map(&gray(0_f64)).r().abs() < 1e-12);
+        // 0.18 scene gray
+        assert!((aces.map(&gray(0.18)).r() - 0.26690).abs() < 1e-5);
+        assert_eq!(aces.map(&gray(100_f64)).r(), 1_f64);
+    }
+
+    #[test]
+    fn agx_test() {
+        let agx = ToneMapping::AgX;
+        let black = agx.map(&gray(0_f64));
+        assert!(black.r() < 1e-4);
+        let middle = agx.map(&gray(0.18));
+        assert!((middle.r() - middle.g()).abs() < 1e-3);
+        assert!(middle.r() > 0.1 && middle.r() < 0.3, "{}", middle);
+        let bright = agx.map(&FloatRgbColor::new(1000_f64, 0_f64, 0_f64));
+        assert!(bright.r() > 0.9 && bright.g() > 0.1, "{}", bright);
+    }
+
+    #[test]
+    fn bt2390_test() {
+        let eetf = ToneMapping::Bt2390 {
+            source_peak: 4000_f64,
+            target_peak: 1000_f64,
+        };
+        // the source peak is mapped to the target peak
+        assert!((eetf.map(&gray(4_f64)).r() - 1_f64).abs() < 1e-9);
+        // dark values are untouched
+        assert!((eetf.map(&gray(0.1)).r() - 0.1).abs() < 1e-9);
+        let mut previous = 0_f64;
+        for i in 0..=400 {
+            let value = eetf.map(&gray(i as f64 / 100_f64)).r();
+            assert!(value >= previous);
+            previous = value;
+        }
+
+        // nothing to compress
+        let identity = ToneMapping::Bt2390 {
+            source_peak: 1000_f64,
+            target_peak: 1000_f64,
+        };
+        assert!((identity.map(&gray(0.7)).r() - 0.7).abs() < 1e-9);
+    }
+}
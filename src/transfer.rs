@@ -0,0 +1,930 @@
+use crate::errors;
+use crate::errors::Result;
+use crate::float_rgb::FloatRgbColor;
+
+// encode: linear => non-linear, decode: non-linear => linear
+// Negative values are mirrored so that out of gamut colors survive a round trip.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TransferFunction {
+    Linear,
+    Gamma(f64),
+    // IEC 61966-2-1
+    Srgb,
+    // ITU-R BT.709
+    Bt709,
+    // ITU-R BT.2020
+    Bt2020,
+    // ROMM RGB (ISO 22028-2)
+    ProPhoto,
+    // ITU-R BT.1886 EOTF with the black level relative to white (0 is a pure 2.4 gamma)
+    // linear light below the black level is not reachable, negative values are clipped
+    Bt1886(f64),
+    // SMPTE ST 2084, linear 1.0 is 10000 cd/m2
+    // the encoding of 0 is not 0 (about 7.3e-7), negative values are clipped instead of mirrored
+    Pq,
+    // ITU-R BT.2100 HLG OETF, scene linear light 0 - 1
+    Hlg,
+    // Academy S-2014-003, negative linear values are clipped
+    AcesCc,
+    // Academy S-2016-001, ACEScc with a linear toe
+    AcesCct,
+    // camera log curves, scene linear reflection (0.18 is the middle gray) <=> 10 bit code value / 1023
+    // Sony S-Log3
+    SLog3,
+    // ARRI LogC3 (EI 800)
+    LogC3,
+    // Panasonic V-Log
+    VLog,
+    // Canon Log (legal range code value)
+    CanonLog,
+    // RED Log3G10
+    Log3G10,
+    Parametric(ParametricCurve),
+    // table of the decoded values (ICC curveType), input outside of 0 - 1 is clamped
+    Sampled(SampledCurve),
+}
+
+impl TransferFunction {
+    pub fn encode(&self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Gamma(gamma) => mirror(value, |v| v.powf(1_f64 / gamma)),
+            Self::Srgb => mirror(value, |v| {
+                if v <= 0.0031308 {
+                    v * 12.92
+                } else {
+                    1.055 * v.powf(1_f64 / 2.4) - 0.055
+                }
+            }),
+            Self::Bt709 => mirror(value, |v| bt709_encode(v, 1.099, 0.018)),
+            Self::Bt2020 => mirror(value, |v| bt709_encode(v, BT2020_ALPHA, BT2020_BETA)),
+            Self::ProPhoto => mirror(value, |v| {
+                if v < PROPHOTO_ET {
+                    v * 16_f64
+                } else {
+                    v.powf(1_f64 / 1.8)
+                }
+            }),
+            Self::Bt1886(black) => {
+                let (gain, offset) = bt1886_parameters(*black);
+                (value.max(0_f64) / gain).powf(1_f64 / BT1886_GAMMA) - offset
+            }
+            Self::Pq => pq_encode(value.max(0_f64)),
+            Self::Hlg => mirror(value, hlg_encode),
+            Self::AcesCc => acescc_encode(value),
+            Self::AcesCct => acescct_encode(value),
+            Self::SLog3 => slog3_encode(value),
+            Self::LogC3 => logc3_encode(value),
+            Self::VLog => vlog_encode(value),
+            Self::CanonLog => canon_log_encode(value),
+            Self::Log3G10 => log3g10_encode(value),
+            Self::Parametric(curve) => mirror(value, |v| curve.encode(v)),
+            Self::Sampled(curve) => curve.encode(value),
+        }
+    }
+
+    pub fn decode(&self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Gamma(gamma) => mirror(value, |v| v.powf(*gamma)),
+            Self::Srgb => mirror(value, |v| {
+                if v <= 0.04045 {
+                    v / 12.92
+                } else {
+                    ((v + 0.055) / 1.055).powf(2.4)
+                }
+            }),
+            Self::Bt709 => mirror(value, |v| bt709_decode(v, 1.099, 0.018)),
+            Self::Bt2020 => mirror(value, |v| bt709_decode(v, BT2020_ALPHA, BT2020_BETA)),
+            Self::ProPhoto => mirror(value, |v| {
+                if v < PROPHOTO_ET * 16_f64 {
+                    v / 16_f64
+                } else {
+                    v.powf(1.8)
+                }
+            }),
+            Self::Bt1886(black) => {
+                let (gain, offset) = bt1886_parameters(*black);
+                gain * (value + offset).max(0_f64).powf(BT1886_GAMMA)
+            }
+            Self::Pq => pq_decode(value.max(0_f64)),
+            Self::Hlg => mirror(value, hlg_decode),
+            Self::AcesCc => acescc_decode(value),
+            Self::AcesCct => acescct_decode(value),
+            Self::SLog3 => slog3_decode(value),
+            Self::LogC3 => logc3_decode(value),
+            Self::VLog => vlog_decode(value),
+            Self::CanonLog => canon_log_decode(value),
+            Self::Log3G10 => log3g10_decode(value),
+            Self::Parametric(curve) => mirror(value, |v| curve.decode(v)),
+            Self::Sampled(curve) => curve.decode(value),
+        }
+    }
+
+    pub fn encode_slice(&self, values: &mut [f64]) {
+        for value in values.iter_mut() {
+            *value = self.encode(*value);
+        }
+    }
+
+    pub fn decode_slice(&self, values: &mut [f64]) {
+        for value in values.iter_mut() {
+            *value = self.decode(*value);
+        }
+    }
+
+    // the same decoding as an ICC parametricCurveType, if there is one
+    pub(crate) fn parametric(&self) -> Option<ParametricCurve> {
+        let bt709 = |alpha: f64, beta: f64| {
+            ParametricCurve::new(
+                3,
+                &[
+                    1_f64 / 0.45,
+                    1_f64 / alpha,
+                    (alpha - 1_f64) / alpha,
+                    1_f64 / 4.5,
+                    4.5 * beta,
+                ],
+            )
+            .ok()
+        };
+        match self {
+            Self::Gamma(gamma) => ParametricCurve::new(0, &[*gamma]).ok(),
+            Self::Srgb => Some(ParametricCurve::srgb()),
+            Self::Bt709 => bt709(1.099, 0.018),
+            Self::Bt2020 => bt709(BT2020_ALPHA, BT2020_BETA),
+            Self::ProPhoto => ParametricCurve::new(
+                3,
+                &[1.8, 1_f64, 0_f64, 1_f64 / 16_f64, PROPHOTO_ET * 16_f64],
+            )
+            .ok(),
+            Self::Parametric(curve) => Some(*curve),
+            _ => None,
+        }
+    }
+}
+
+// ITU-R BT.2100 HLG system gamma of the nominal peak luminance (cd/m2)
+pub fn hlg_system_gamma(peak_luminance: f64) -> f64 {
+    1.2 + 0.42 * (peak_luminance / 1000_f64).log10()
+}
+
+// ITU-R BT.2100 HLG OOTF, scene light (0 - 1) to display light (cd/m2) of ITU-R BT.2020 RGB
+pub fn hlg_ootf(color: &FloatRgbColor, peak_luminance: f64) -> FloatRgbColor {
+    let gamma = hlg_system_gamma(peak_luminance);
+    let rgb = color.to_array();
+    let scene = rec2020_luminance(rgb).max(0_f64);
+    let gain = peak_luminance * scene.powf(gamma - 1_f64);
+    FloatRgbColor::from_array(rgb.map(|value| value * gain), color.a())
+}
+
+pub fn hlg_inverse_ootf(color: &FloatRgbColor, peak_luminance: f64) -> FloatRgbColor {
+    let gamma = hlg_system_gamma(peak_luminance);
+    let rgb = color.to_array();
+    let display = rec2020_luminance(rgb).max(0_f64) / peak_luminance;
+    if display == 0_f64 {
+        return FloatRgbColor::from_rgba(0_f64, 0_f64, 0_f64, color.a());
+    }
+    let scene = display.powf(1_f64 / gamma);
+    let gain = peak_luminance * scene.powf(gamma - 1_f64);
+    FloatRgbColor::from_array(rgb.map(|value| value / gain), color.a())
+}
+
+// ITU-R BT.2100 PQ reference OOTF, scene light (0 - 1) to display light (cd/m2)
+pub fn pq_ootf(color: &FloatRgbColor) -> FloatRgbColor {
+    let ootf = |value: f64| {
+        let encoded = if value <= 0.0003024 {
+            267.84 * value
+        } else {
+            1.099 * (59.5208 * value).powf(0.45) - 0.099
+        };
+        100_f64 * encoded.max(0_f64).powf(BT1886_GAMMA)
+    };
+    FloatRgbColor::from_rgba(
+        mirror(color.r(), ootf),
+        mirror(color.g(), ootf),
+        mirror(color.b(), ootf),
+        color.a(),
+    )
+}
+
+// ICC.1 parametricCurveType
+// 0: Y = X^g
+// 1: Y = (aX + b)^g             (X >= -b/a), 0            (X < -b/a)
+// 2: Y = (aX + b)^g + c         (X >= -b/a), c            (X < -b/a)
+// 3: Y = (aX + b)^g             (X >= d),    cX           (X < d)
+// 4: Y = (aX + b)^g + e         (X >= d),    cX + f       (X < d)
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ParametricCurve {
+    function_type: u16,
+    parameters: [f64; 7],
+}
+
+impl ParametricCurve {
+    pub fn new(function_type: u16, parameters: &[f64]) -> Result<Self> {
+        if parameter_count(function_type) != Some(parameters.len())
+            || parameters.iter().any(|v| !v.is_finite())
+            || parameters[0] <= 0_f64
+        {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!(
+                    "function type {} parameters {:?}",
+                    function_type, parameters
+                ),
+            ));
+        }
+        if function_type > 0 && parameters[1] == 0_f64 {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!(
+                    "function type {} parameters {:?}",
+                    function_type, parameters
+                ),
+            ));
+        }
+
+        let mut values = [0_f64; 7];
+        values[..parameters.len()].copy_from_slice(parameters);
+        Ok(Self {
+            function_type,
+            parameters: values,
+        })
+    }
+
+    pub fn gamma(gamma: f64) -> Result<Self> {
+        Self::new(0, &[gamma])
+    }
+
+    // IEC 61966-2-1
+    pub fn srgb() -> Self {
+        Self::new(
+            3,
+            &[2.4, 1_f64 / 1.055, 0.055 / 1.055, 1_f64 / 12.92, 0.04045],
+        )
+        .unwrap()
+    }
+
+    pub fn function_type(&self) -> u16 {
+        self.function_type
+    }
+
+    pub fn parameters(&self) -> &[f64] {
+        &self.parameters[..parameter_count(self.function_type).unwrap_or_default()]
+    }
+
+    // (g, a, b, c, d, e, f) of function type 4
+    fn normalize(&self) -> [f64; 7] {
+        let p = &self.parameters;
+        match self.function_type {
+            0 => [p[0], 1_f64, 0_f64, 0_f64, 0_f64, 0_f64, 0_f64],
+            1 => [p[0], p[1], p[2], 0_f64, -p[2] / p[1], 0_f64, 0_f64],
+            2 => [p[0], p[1], p[2], 0_f64, -p[2] / p[1], p[3], p[3]],
+            3 => [p[0], p[1], p[2], p[3], p[4], 0_f64, 0_f64],
+            _ => *p,
+        }
+    }
+
+    pub fn decode(&self, value: f64) -> f64 {
+        let [g, a, b, c, d, e, f] = self.normalize();
+        if value >= d {
+            (a * value + b).max(0_f64).powf(g) + e
+        } else {
+            c * value + f
+        }
+    }
+
+    pub fn encode(&self, value: f64) -> f64 {
+        let [g, a, b, c, d, e, f] = self.normalize();
+        let threshold = (a * d + b).max(0_f64).powf(g) + e;
+        if value >= threshold {
+            ((value - e).max(0_f64).powf(1_f64 / g) - b) / a
+        } else if c != 0_f64 {
+            (value - f) / c
+        } else {
+            d
+        }
+    }
+}
+
+// equally spaced samples of the decoding function over 0 - 1
+// encode inverts the table, which is assumed to be non-decreasing
+#[derive(Debug, PartialEq, Clone)]
+pub struct SampledCurve {
+    values: Vec<f64>,
+}
+
+impl SampledCurve {
+    pub fn new(values: Vec<f64>) -> Result<Self> {
+        if values.len() < 2 || values.iter().any(|v| !v.is_finite()) {
+            return Err(errors::Error::new(
+                errors::ErrorCode::InvalidArgument,
+                format!("values {:?}", values),
+            ));
+        }
+        Ok(Self { values })
+    }
+
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    pub fn decode(&self, value: f64) -> f64 {
+        let last = self.values.len() - 1;
+        let position = value.clamp(0_f64, 1_f64) * last as f64;
+        let index = (position.floor() as usize).min(last - 1);
+        let fraction = position - index as f64;
+        self.values[index] + (self.values[index + 1] - self.values[index]) * fraction
+    }
+
+    pub fn encode(&self, value: f64) -> f64 {
+        let last = self.values.len() - 1;
+        if value <= self.values[0] {
+            return 0_f64;
+        }
+        if value >= self.values[last] {
+            return 1_f64;
+        }
+        // the first segment containing the value
+        let index = self.values.partition_point(|&v| v < value).clamp(1, last) - 1;
+        let (lower, upper) = (self.values[index], self.values[index + 1]);
+        let fraction = if upper > lower {
+            (value - lower) / (upper - lower)
+        } else {
+            0_f64
+        };
+        (index as f64 + fraction) / last as f64
+    }
+}
+
+// number of parameters of an ICC parametricCurveType
+pub(crate) fn parameter_count(function_type: u16) -> Option<usize> {
+    match function_type {
+        0 => Some(1),
+        1 => Some(3),
+        2 => Some(4),
+        3 => Some(5),
+        4 => Some(7),
+        _ => None,
+    }
+}
+
+const BT2020_ALPHA: f64 = 1.09929682680944;
+const BT1886_GAMMA: f64 = 2.4;
+const REC2020_LUMINANCE: [f64; 3] = [0.2627, 0.6780, 0.0593];
+const BT2020_BETA: f64 = 0.018053968510807;
+const PROPHOTO_ET: f64 = 1_f64 / 512_f64;
+
+fn mirror(value: f64, f: impl Fn(f64) -> f64) -> f64 {
+    if value < 0_f64 {
+        -f(-value)
+    } else {
+        f(value)
+    }
+}
+
+fn rec2020_luminance(rgb: [f64; 3]) -> f64 {
+    rgb.iter().zip(REC2020_LUMINANCE).map(|(v, w)| v * w).sum()
+}
+
+// (a, b) of L = a * max(V + b, 0)^2.4 with the white level 1
+fn bt1886_parameters(black: f64) -> (f64, f64) {
+    let black_root = black
+        .clamp(0_f64, 1_f64 - f64::EPSILON)
+        .powf(1_f64 / BT1886_GAMMA);
+    let range = 1_f64 - black_root;
+    (range.powf(BT1886_GAMMA), black_root / range)
+}
+
+fn bt709_encode(value: f64, alpha: f64, beta: f64) -> f64 {
+    if value < beta {
+        4.5 * value
+    } else {
+        alpha * value.powf(0.45) - (alpha - 1_f64)
+    }
+}
+
+fn bt709_decode(value: f64, alpha: f64, beta: f64) -> f64 {
+    if value < 4.5 * beta {
+        value / 4.5
+    } else {
+        ((value + alpha - 1_f64) / alpha).powf(1_f64 / 0.45)
+    }
+}
+
+// SMPTE ST 2084 (PQ), linear value is the ratio to 10000 cd/m2
+const PQ_M1: f64 = 2610_f64 / 16384_f64;
+const PQ_M2: f64 = 2523_f64 / 4096_f64 * 128_f64;
+const PQ_C1: f64 = 3424_f64 / 4096_f64;
+const PQ_C2: f64 = 2413_f64 / 4096_f64 * 32_f64;
+const PQ_C3: f64 = 2392_f64 / 4096_f64 * 32_f64;
+
+fn pq_encode(value: f64) -> f64 {
+    let y = value.powf(PQ_M1);
+    ((PQ_C1 + PQ_C2 * y) / (1_f64 + PQ_C3 * y)).powf(PQ_M2)
+}
+
+fn pq_decode(value: f64) -> f64 {
+    let e = value.powf(1_f64 / PQ_M2);
+    ((e - PQ_C1).max(0_f64) / (PQ_C2 - PQ_C3 * e)).powf(1_f64 / PQ_M1)
+}
+
+// ITU-R BT.2100 HLG OETF, scene linear light 0 - 1
+const HLG_A: f64 = 0.17883277;
+const HLG_B: f64 = 1_f64 - 4_f64 * HLG_A;
+const HLG_C: f64 = 0.559910729529562;
+
+fn hlg_encode(value: f64) -> f64 {
+    if value <= 1_f64 / 12_f64 {
+        (3_f64 * value).sqrt()
+    } else {
+        HLG_A * (12_f64 * value - HLG_B).ln() + HLG_C
+    }
+}
+
+fn hlg_decode(value: f64) -> f64 {
+    if value <= 0.5 {
+        value * value / 3_f64
+    } else {
+        (((value - HLG_C) / HLG_A).exp() + HLG_B) / 12_f64
+    }
+}
+
+// ACES log encodings
+const ACES_LOG_OFFSET: f64 = 9.72;
+const ACES_LOG_SCALE: f64 = 17.52;
+const ACES_HALF_MAX: f64 = 65504_f64;
+const ACESCCT_X_BREAK: f64 = 0.0078125;
+const ACESCCT_Y_BREAK: f64 = 0.155251141552511;
+const ACESCCT_A: f64 = 10.5402377416545;
+const ACESCCT_B: f64 = 0.0729055341958355;
+
+fn aces_log(value: f64) -> f64 {
+    (value.log2() + ACES_LOG_OFFSET) / ACES_LOG_SCALE
+}
+
+fn aces_exp(value: f64) -> f64 {
+    (value * ACES_LOG_SCALE - ACES_LOG_OFFSET).exp2()
+}
+
+fn acescc_encode(value: f64) -> f64 {
+    let min = 2_f64.powi(-16);
+    if value <= 0_f64 {
+        aces_log(min)
+    } else if value < 2_f64.powi(-15) {
+        aces_log(min + value * 0.5)
+    } else {
+        aces_log(value)
+    }
+}
+
+fn acescc_decode(value: f64) -> f64 {
+    if value < (ACES_LOG_OFFSET - 15_f64) / ACES_LOG_SCALE {
+        (aces_exp(value) - 2_f64.powi(-16)) * 2_f64
+    } else if value < aces_log(ACES_HALF_MAX) {
+        aces_exp(value)
+    } else {
+        ACES_HALF_MAX
+    }
+}
+
+fn acescct_encode(value: f64) -> f64 {
+    if value <= ACESCCT_X_BREAK {
+        ACESCCT_A * value + ACESCCT_B
+    } else {
+        aces_log(value)
+    }
+}
+
+fn acescct_decode(value: f64) -> f64 {
+    if value <= ACESCCT_Y_BREAK {
+        (value - ACESCCT_B) / ACESCCT_A
+    } else {
+        aces_exp(value).min(ACES_HALF_MAX)
+    }
+}
+
+// Sony "Technical Summary for S-Gamut3.Cine/S-Log3 and S-Gamut3/S-Log3"
+const SLOG3_CUT: f64 = 0.01125;
+const SLOG3_TOE: f64 = 171.2102946929;
+
+fn slog3_encode(value: f64) -> f64 {
+    if value >= SLOG3_CUT {
+        (420_f64 + ((value + 0.01) / (0.18 + 0.01)).log10() * 261.5) / 1023_f64
+    } else {
+        (value * (SLOG3_TOE - 95_f64) / SLOG3_CUT + 95_f64) / 1023_f64
+    }
+}
+
+fn slog3_decode(value: f64) -> f64 {
+    let code = value * 1023_f64;
+    if code >= SLOG3_TOE {
+        10_f64.powf((code - 420_f64) / 261.5) * (0.18 + 0.01) - 0.01
+    } else {
+        (code - 95_f64) * SLOG3_CUT / (SLOG3_TOE - 95_f64)
+    }
+}
+
+// ARRI "ALEXA Log C Curve - Usage in VFX"
+const LOGC3_CUT: f64 = 0.010591;
+const LOGC3_A: f64 = 5.555556;
+const LOGC3_B: f64 = 0.052272;
+const LOGC3_C: f64 = 0.247190;
+const LOGC3_D: f64 = 0.385537;
+const LOGC3_E: f64 = 5.367655;
+const LOGC3_F: f64 = 0.092809;
+
+fn logc3_encode(value: f64) -> f64 {
+    if value > LOGC3_CUT {
+        LOGC3_C * (LOGC3_A * value + LOGC3_B).log10() + LOGC3_D
+    } else {
+        LOGC3_E * value + LOGC3_F
+    }
+}
+
+fn logc3_decode(value: f64) -> f64 {
+    if value > LOGC3_E * LOGC3_CUT + LOGC3_F {
+        (10_f64.powf((value - LOGC3_D) / LOGC3_C) - LOGC3_B) / LOGC3_A
+    } else {
+        (value - LOGC3_F) / LOGC3_E
+    }
+}
+
+// Panasonic "V-Log/V-Gamut Reference manual"
+const VLOG_CUT: f64 = 0.01;
+const VLOG_B: f64 = 0.00873;
+const VLOG_C: f64 = 0.241514;
+const VLOG_D: f64 = 0.598206;
+
+fn vlog_encode(value: f64) -> f64 {
+    if value < VLOG_CUT {
+        5.6 * value + 0.125
+    } else {
+        VLOG_C * (value + VLOG_B).log10() + VLOG_D
+    }
+}
+
+fn vlog_decode(value: f64) -> f64 {
+    // the two segments differ by 1e-7 at the cut, the log side is used for the round trip
+    if value < VLOG_C * (VLOG_CUT + VLOG_B).log10() + VLOG_D {
+        (value - 0.125) / 5.6
+    } else {
+        10_f64.powf((value - VLOG_D) / VLOG_C) - VLOG_B
+    }
+}
+
+// Canon "Canon Log Gamma Curves", the curve is defined for 90% reflection at 1.0
+const CANON_LOG_REFLECTION: f64 = 0.9;
+const CANON_LOG_A: f64 = 0.529136;
+const CANON_LOG_B: f64 = 10.1596;
+const CANON_LOG_C: f64 = 0.0730597;
+
+fn canon_log_encode(value: f64) -> f64 {
+    let encoded = mirror(value / CANON_LOG_REFLECTION, |v| {
+        CANON_LOG_A * (CANON_LOG_B * v + 1_f64).log10()
+    }) + CANON_LOG_C;
+    // full range to 10 bit legal range
+    (encoded * 876_f64 + 64_f64) / 1023_f64
+}
+
+fn canon_log_decode(value: f64) -> f64 {
+    let encoded = (value * 1023_f64 - 64_f64) / 876_f64;
+    mirror(encoded - CANON_LOG_C, |v| {
+        (10_f64.powf(v / CANON_LOG_A) - 1_f64) / CANON_LOG_B
+    }) * CANON_LOG_REFLECTION
+}
+
+// RED "White Paper on REDWideGamutRGB and Log3G10"
+const LOG3G10_A: f64 = 0.224282;
+const LOG3G10_B: f64 = 155.975327;
+const LOG3G10_C: f64 = 0.01;
+const LOG3G10_G: f64 = 15.1927;
+
+fn log3g10_encode(value: f64) -> f64 {
+    let v = value + LOG3G10_C;
+    if v < 0_f64 {
+        v * LOG3G10_G
+    } else {
+        LOG3G10_A * (v * LOG3G10_B + 1_f64).log10()
+    }
+}
+
+fn log3g10_decode(value: f64) -> f64 {
+    if value < 0_f64 {
+        value / LOG3G10_G - LOG3G10_C
+    } else {
+        (10_f64.powf(value / LOG3G10_A) - 1_f64) / LOG3G10_B - LOG3G10_C
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFER_FUNCTIONS: [TransferFunction; 6] = [
+        TransferFunction::Linear,
+        TransferFunction::Gamma(2.2),
+        TransferFunction::Srgb,
+        TransferFunction::Bt709,
+        TransferFunction::Bt2020,
+        TransferFunction::ProPhoto,
+    ];
+
+    #[test]
+    fn transfer_round_trip_test() {
+        for transfer in TRANSFER_FUNCTIONS.iter() {
+            for i in -100..=200 {
+                let value = i as f64 / 100_f64;
+                assert!((transfer.decode(transfer.encode(value)) - value).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn transfer_end_point_test() {
+        for transfer in TRANSFER_FUNCTIONS.iter() {
+            assert!(transfer.encode(0_f64).abs() < 1e-12);
+            assert!((transfer.encode(1_f64) - 1_f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn pq_test() {
+        assert_eq!(pq_encode(0_f64), PQ_C1.powf(PQ_M2));
+        assert!((pq_encode(1_f64) - 1_f64).abs() < 1e-12);
+        // 100 cd/m2
+        assert!((pq_encode(0.01) - 0.508078).abs() < 1e-6);
+        for i in 0..=100 {
+            let value = (i as f64 / 100_f64).powi(4);
+            assert!((pq_decode(pq_encode(value)) - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn hlg_test() {
+        assert!((hlg_encode(1_f64 / 12_f64) - 0.5).abs() < 1e-12);
+        assert!((hlg_encode(1_f64) - 1_f64).abs() < 1e-8);
+        for i in 0..=100 {
+            let value = i as f64 / 100_f64;
+            assert!((hlg_decode(hlg_encode(value)) - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn hdr_transfer_test() {
+        for (transfer, min) in [(TransferFunction::Pq, 0), (TransferFunction::Hlg, -100)] {
+            for i in min..=200 {
+                let value = i as f64 / 100_f64;
+                assert!((transfer.decode(transfer.encode(value)) - value).abs() < 1e-9);
+            }
+        }
+        let pq = TransferFunction::Pq;
+        assert_eq!(pq.encode(-0.5), pq.encode(0_f64));
+        assert_eq!(pq.decode(pq.encode(-0.5)), 0_f64);
+        assert_eq!(pq.decode(-0.5), 0_f64);
+        assert_eq!(
+            TransferFunction::Hlg.encode(-0.25),
+            -TransferFunction::Hlg.encode(0.25)
+        );
+        assert!((TransferFunction::Pq.encode(0.01) - 0.508078).abs() < 1e-6);
+        assert!((TransferFunction::Hlg.encode(0.5) - 0.87164).abs() < 1e-5);
+    }
+
+    #[test]
+    fn bt1886_test() {
+        let pure = TransferFunction::Bt1886(0_f64);
+        assert_eq!(pure.decode(0.5), 0.5_f64.powf(2.4));
+        assert_eq!(pure.decode(-0.5), 0_f64);
+
+        // 0.1 cd/m2 black of a 100 cd/m2 display
+        let transfer = TransferFunction::Bt1886(0.001);
+        assert!((transfer.decode(0_f64) - 0.001).abs() < 1e-12);
+        assert!((transfer.decode(1_f64) - 1_f64).abs() < 1e-12);
+        assert!(transfer.encode(0_f64) < 0_f64);
+        for i in 0..=100 {
+            let value = 0.001 + i as f64 / 100_f64 * 0.999;
+            assert!((transfer.decode(transfer.encode(value)) - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn aces_log_test() {
+        // S-2014-003, S-2016-001: 0.18 is about 0.4136
+        for transfer in [TransferFunction::AcesCc, TransferFunction::AcesCct] {
+            assert!((transfer.encode(0.18) - 0.413588).abs() < 1e-6);
+            assert!((transfer.encode(1_f64) - 0.554795).abs() < 1e-6);
+            for i in 0..=200 {
+                let value = 2_f64.powf(i as f64 / 10_f64 - 14_f64);
+                let actual = transfer.decode(transfer.encode(value));
+                assert!(
+                    (actual - value).abs() < 1e-9 * value.max(1_f64),
+                    "{:?} {}",
+                    transfer,
+                    value
+                );
+            }
+        }
+
+        let cc = TransferFunction::AcesCc;
+        assert!((cc.encode(0_f64) - (-0.358447)).abs() < 1e-6);
+        assert_eq!(cc.encode(-1_f64), cc.encode(0_f64));
+        assert_eq!(cc.decode(2_f64), 65504_f64);
+
+        // the toe of ACEScct is continuous and keeps negative values
+        let cct = TransferFunction::AcesCct;
+        assert!((cct.encode(0.0078125) - aces_log(0.0078125)).abs() < 1e-9);
+        assert!((cct.decode(cct.encode(-0.01)) + 0.01).abs() < 1e-12);
+        assert!((cct.encode(0_f64) - 0.0729055341958355).abs() < 1e-12);
+    }
+
+    #[test]
+    fn camera_log_test() {
+        // middle gray (colour-science 0.4)
+        for (transfer, expected) in [
+            (TransferFunction::SLog3, 0.410557184750733),
+            (TransferFunction::LogC3, 0.391006832034084),
+            (TransferFunction::VLog, 0.423311448760136),
+            (TransferFunction::CanonLog, 0.343389651726069),
+            (TransferFunction::Log3G10, 0.333333644207707),
+        ] {
+            let actual = transfer.encode(0.18);
+            assert!(
+                (actual - expected).abs() < 1e-6,
+                "{:?} {}",
+                transfer,
+                actual
+            );
+            for value in [-0.005, 0_f64, 0.001, 0.01, 0.18, 1_f64, 16_f64] {
+                let actual = transfer.decode(transfer.encode(value));
+                assert!(
+                    (actual - value).abs() < 1e-9,
+                    "{:?} {} {}",
+                    transfer,
+                    value,
+                    actual
+                );
+            }
+        }
+
+        // black code values
+        assert!((TransferFunction::SLog3.encode(0_f64) - 95_f64 / 1023_f64).abs() < 1e-12);
+        assert!((TransferFunction::VLog.encode(0_f64) - 0.125).abs() < 1e-12);
+        assert!(TransferFunction::Log3G10.encode(-LOG3G10_C).abs() < 1e-12);
+        // the linear segments are continuous
+        for (transfer, cut) in [
+            (TransferFunction::SLog3, SLOG3_CUT),
+            (TransferFunction::LogC3, LOGC3_CUT),
+            (TransferFunction::VLog, VLOG_CUT),
+        ] {
+            let below = transfer.encode(cut - 1e-9);
+            let above = transfer.encode(cut + 1e-9);
+            assert!((below - above).abs() < 1e-6, "{:?}", transfer);
+        }
+    }
+
+    #[test]
+    fn sampled_curve_test() {
+        let values = (0..=1024)
+            .map(|i| (i as f64 / 1024_f64).powf(2.2))
+            .collect::<Vec<_>>();
+        let transfer = TransferFunction::Sampled(SampledCurve::new(values).unwrap());
+        let gamma = TransferFunction::Gamma(2.2);
+        for i in 0..=20 {
+            let value = i as f64 / 20_f64;
+            assert!((transfer.decode(value) - gamma.decode(value)).abs() < 1e-5);
+            assert!((transfer.encode(value) - gamma.encode(value)).abs() < 1e-3);
+            let round_trip = transfer.encode(transfer.decode(value));
+            assert!((round_trip - value).abs() < 1e-9, "{}", round_trip);
+        }
+        assert_eq!(transfer.decode(-1_f64), 0_f64);
+        assert_eq!(transfer.decode(2_f64), 1_f64);
+        assert_eq!(transfer.encode(2_f64), 1_f64);
+
+        // a flat segment is inverted to its start
+        let flat = SampledCurve::new(vec![0_f64, 0.5, 0.5, 1_f64]).unwrap();
+        assert!((flat.encode(0.5) - 1_f64 / 3_f64).abs() < 1e-12);
+
+        assert!(SampledCurve::new(vec![0_f64]).is_err());
+        assert!(SampledCurve::new(vec![0_f64, f64::NAN]).is_err());
+    }
+
+    #[test]
+    fn transfer_parametric_test() {
+        for transfer in [
+            TransferFunction::Gamma(2.2),
+            TransferFunction::Srgb,
+            TransferFunction::Bt709,
+            TransferFunction::Bt2020,
+            TransferFunction::ProPhoto,
+        ] {
+            let curve = transfer.parametric().unwrap();
+            for i in 0..=100 {
+                let value = i as f64 / 100_f64;
+                let expected = transfer.decode(value);
+                assert!(
+                    (curve.decode(value) - expected).abs() < 1e-12,
+                    "{:?}",
+                    transfer
+                );
+            }
+        }
+        assert_eq!(TransferFunction::Linear.parametric(), None);
+        assert_eq!(TransferFunction::Pq.parametric(), None);
+        assert_eq!(TransferFunction::Gamma(-1_f64).parametric(), None);
+    }
+
+    #[test]
+    fn transfer_slice_test() {
+        let mut values = [0_f64, 0.25, 0.5, 1_f64];
+        TransferFunction::Pq.encode_slice(&mut values);
+        assert_eq!(values[2], TransferFunction::Pq.encode(0.5));
+        TransferFunction::Pq.decode_slice(&mut values);
+        for (actual, expected) in values.iter().zip([0_f64, 0.25, 0.5, 1_f64]) {
+            assert!((actual - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn hlg_ootf_test() {
+        assert_eq!(hlg_system_gamma(1000_f64), 1.2);
+        assert!((hlg_system_gamma(2000_f64) - 1.32643).abs() < 1e-5);
+
+        let white = hlg_ootf(&FloatRgbColor::new(1_f64, 1_f64, 1_f64), 1000_f64);
+        assert!((white.g() - 1000_f64).abs() < 1e-9);
+        let gray = hlg_ootf(&FloatRgbColor::new(0.2, 0.2, 0.2), 1000_f64);
+        assert!((gray.r() - 1000_f64 * 0.2_f64.powf(1.2)).abs() < 1e-9);
+
+        let scene = FloatRgbColor::new(0.3, 0.1, 0.05);
+        for peak in [400_f64, 1000_f64, 4000_f64] {
+            let actual = hlg_inverse_ootf(&hlg_ootf(&scene, peak), peak);
+            assert!((actual.r() - scene.r()).abs() < 1e-12);
+            assert!((actual.g() - scene.g()).abs() < 1e-12);
+            assert!((actual.b() - scene.b()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn pq_ootf_test() {
+        // the reference OOTF maps the scene white to about 10000 cd/m2
+        let white = pq_ootf(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert!(
+            (white.r() - 100_f64 * (1.099 * 59.5208_f64.powf(0.45) - 0.099).powf(2.4)).abs() < 1e-9
+        );
+        assert!(white.r() > 9000_f64 && white.r() < 11000_f64);
+        assert_eq!(pq_ootf(&FloatRgbColor::new(0_f64, 0_f64, 0_f64)).r(), 0_f64);
+    }
+
+    #[test]
+    fn parametric_curve_srgb_test() {
+        let parametric = TransferFunction::Parametric(ParametricCurve::srgb());
+        for i in 0..=100 {
+            let value = i as f64 / 100_f64;
+            assert!(
+                (parametric.decode(value) - TransferFunction::Srgb.decode(value)).abs() < 1e-12
+            );
+            assert!((parametric.encode(value) - TransferFunction::Srgb.encode(value)).abs() < 1e-6);
+            assert!((parametric.decode(parametric.encode(value)) - value).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn parametric_curve_type_test() {
+        let curves = [
+            ParametricCurve::new(0, &[2.2]).unwrap(),
+            ParametricCurve::new(1, &[2.2, 1.1, -0.1]).unwrap(),
+            ParametricCurve::new(2, &[2.2, 1.1, -0.1, 0.05]).unwrap(),
+            ParametricCurve::new(3, &[2.4, 0.947867, 0.052133, 0.077399, 0.04045]).unwrap(),
+            ParametricCurve::new(4, &[2.4, 0.9, 0.1, 0.08, 0.05, 0.01, 0.01]).unwrap(),
+        ];
+        for curve in curves.iter() {
+            for i in 20..=100 {
+                let value = i as f64 / 100_f64;
+                assert!((curve.encode(curve.decode(value)) - value).abs() < 1e-9);
+            }
+        }
+
+        assert_eq!(curves[1].decode(0.05), 0_f64);
+        assert_eq!(curves[2].decode(0.05), 0.05);
+        assert_eq!(curves[2].parameters(), &[2.2, 1.1, -0.1, 0.05]);
+    }
+
+    #[test]
+    fn parametric_curve_invalid_test() {
+        assert!(ParametricCurve::new(5, &[2.2]).is_err());
+        assert!(ParametricCurve::new(3, &[2.2]).is_err());
+        assert!(ParametricCurve::new(1, &[2.2, 0_f64, 0.1]).is_err());
+        assert!(ParametricCurve::new(0, &[f64::NAN]).is_err());
+        assert!(ParametricCurve::new(1, &[2.2, f64::NAN, 0.1]).is_err());
+        assert!(ParametricCurve::new(3, &[2.4, 1_f64, 0_f64, f64::INFINITY, 0.04]).is_err());
+        assert!(ParametricCurve::gamma(0_f64).is_err());
+        assert!(ParametricCurve::gamma(-2.2).is_err());
+        assert_eq!(ParametricCurve::gamma(2.2).unwrap().parameters(), &[2.2]);
+    }
+
+    #[test]
+    fn transfer_srgb_test() {
+        assert!((TransferFunction::Srgb.decode(0.5) - 0.214041).abs() < 1e-6);
+        assert!((TransferFunction::Srgb.encode(0.214041) - 0.5).abs() < 1e-6);
+        assert!((TransferFunction::Srgb.encode(-0.214041) + 0.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::spectral::SampledSpectrum;
+
+// wavelengths of the upsampled reflectance (380nm - 730nm, 10nm)
+pub const START_WAVELENGTH: f64 = 380_f64;
+pub const WAVELENGTH_INTERVAL: f64 = 10_f64;
+pub const SAMPLES: usize = 36;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum SpectralUpsampling {
+    // B. Smits, "An RGB-to-Spectrum Conversion for Reflectances", 1999
+    Smits,
+    // S. A. Burns, "Generating Reflectance Curves from sRGB Triplets" (LHTSS), 2019
+    // smooth reflectance within 0..1 that reproduces the sRGB color exactly
+    #[default]
+    Smooth,
+}
+
+// Smits basis spectra, 10 bins from 380nm to 720nm
+const SMITS_WHITE: [f64; 10] = [1.0, 1.0, 0.9999, 0.9993, 0.9992, 0.9998, 1.0, 1.0, 1.0, 1.0];
+const SMITS_CYAN: [f64; 10] = [
+    0.971, 0.9426, 1.0007, 1.0007, 1.0007, 1.0007, 0.1564, 0.0, 0.0, 0.0,
+];
+const SMITS_MAGENTA: [f64; 10] = [
+    1.0, 1.0, 0.9685, 0.2229, 0.0, 0.0458, 0.8369, 1.0, 1.0, 0.9959,
+];
+const SMITS_YELLOW: [f64; 10] = [
+    0.0001, 0.0, 0.1088, 0.6651, 1.0, 1.0, 0.9996, 0.9586, 0.9685, 0.9840,
+];
+const SMITS_RED: [f64; 10] = [
+    0.1012, 0.0515, 0.0, 0.0, 0.0, 0.0, 0.8325, 1.0149, 1.0149, 1.0149,
+];
+const SMITS_GREEN: [f64; 10] = [
+    0.0, 0.0, 0.0273, 0.7937, 1.0, 0.9418, 0.1719, 0.0, 0.0, 0.0025,
+];
+const SMITS_BLUE: [f64; 10] = [
+    1.0, 1.0, 0.8916, 0.3323, 0.0, 0.0, 0.0003, 0.0369, 0.0483, 0.0496,
+];
+
+pub(crate) fn smits(linear: [f64; 3]) -> SampledSpectrum {
+    let [r, g, b] = linear;
+    let mut values = [0_f64; 10];
+    let mut add = |basis: &[f64; 10], weight: f64| {
+        for (value, basis) in values.iter_mut().zip(basis) {
+            *value += weight * basis;
+        }
+    };
+
+    if r <= g && r <= b {
+        add(&SMITS_WHITE, r);
+        if g <= b {
+            add(&SMITS_CYAN, g - r);
+            add(&SMITS_BLUE, b - g);
+        } else {
+            add(&SMITS_CYAN, b - r);
+            add(&SMITS_GREEN, g - b);
+        }
+    } else if g <= r && g <= b {
+        add(&SMITS_WHITE, g);
+        if r <= b {
+            add(&SMITS_MAGENTA, r - g);
+            add(&SMITS_BLUE, b - r);
+        } else {
+            add(&SMITS_MAGENTA, b - g);
+            add(&SMITS_RED, r - b);
+        }
+    } else {
+        add(&SMITS_WHITE, b);
+        if r <= g {
+            add(&SMITS_YELLOW, r - b);
+            add(&SMITS_GREEN, g - r);
+        } else {
+            add(&SMITS_YELLOW, g - b);
+            add(&SMITS_RED, r - g);
+        }
+    }
+
+    // resampled to the common wavelengths
+    let bins = SampledSpectrum::new(380_f64, 340_f64 / 9_f64, values.to_vec())
+        .unwrap_or_else(|_| SampledSpectrum::constant(0_f64));
+    resample(|wavelength| bins.value_at(wavelength))
+}
+
+fn resample(f: impl Fn(f64) -> f64) -> SampledSpectrum {
+    let values = (0..SAMPLES)
+        .map(|i| f(START_WAVELENGTH + WAVELENGTH_INTERVAL * i as f64))
+        .collect();
+    SampledSpectrum::new(START_WAVELENGTH, WAVELENGTH_INTERVAL, values)
+        .unwrap_or_else(|_| SampledSpectrum::constant(0_f64))
+}
+
+// `response[c][i]` is the linear sRGB channel c of the reflectance which is 1 at the i-th wavelength only
+pub(crate) fn smooth(linear: [f64; 3], response: &[[f64; SAMPLES]; 3]) -> SampledSpectrum {
+    const MIN_REFLECTANCE: f64 = 0.0001;
+    const MAX_REFLECTANCE: f64 = 0.9999;
+
+    if linear.iter().all(|&value| value <= MIN_REFLECTANCE) {
+        return resample(|_| MIN_REFLECTANCE);
+    }
+    if linear.iter().all(|&value| value >= MAX_REFLECTANCE) {
+        return resample(|_| MAX_REFLECTANCE);
+    }
+
+    // Newton's method on z (reflectance = (tanh(z) + 1) / 2) and the Lagrange multipliers
+    let n = SAMPLES;
+    let mut z = [0_f64; SAMPLES];
+    let mut lambda = [0_f64; 3];
+    for _ in 0..100 {
+        let mut jacobian = vec![vec![0_f64; n + 3]; n + 3];
+        let mut residual = vec![0_f64; n + 3];
+
+        for i in 0..n {
+            let sech2 = 1_f64 / z[i].cosh().powi(2);
+            let d1 = sech2 / 2_f64;
+            let d2 = -sech2 * z[i].tanh();
+            let projected: f64 = (0..3).map(|c| response[c][i] * lambda[c]).sum();
+
+            // second difference of z (slope penalty)
+            let diagonal = if i == 0 || i == n - 1 { 2_f64 } else { 4_f64 };
+            let mut dz = diagonal * z[i];
+            if i > 0 {
+                dz -= 2_f64 * z[i - 1];
+                jacobian[i][i - 1] = -2_f64;
+            }
+            if i < n - 1 {
+                dz -= 2_f64 * z[i + 1];
+                jacobian[i][i + 1] = -2_f64;
+            }
+            residual[i] = dz + d1 * projected;
+            jacobian[i][i] = diagonal + d2 * projected;
+            for c in 0..3 {
+                jacobian[i][n + c] = d1 * response[c][i];
+                jacobian[n + c][i] = d1 * response[c][i];
+            }
+        }
+        for c in 0..3 {
+            let reflected: f64 = (0..n)
+                .map(|i| response[c][i] * (z[i].tanh() + 1_f64) / 2_f64)
+                .sum();
+            residual[n + c] = reflected - linear[c];
+        }
+
+        if residual.iter().all(|value| value.abs() < 1e-8) {
+            break;
+        }
+        let Some(delta) = solve(jacobian, residual.iter().map(|value| -value).collect()) else {
+            break;
+        };
+        for i in 0..n {
+            z[i] += delta[i];
+        }
+        for c in 0..3 {
+            lambda[c] += delta[n + c];
+        }
+    }
+
+    let values = z
+        .iter()
+        .map(|value| (value.tanh() + 1_f64) / 2_f64)
+        .collect();
+    SampledSpectrum::new(START_WAVELENGTH, WAVELENGTH_INTERVAL, values)
+        .unwrap_or_else(|_| SampledSpectrum::constant(0_f64))
+}
+
+// Gaussian elimination with partial pivoting
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    for column in 0..n {
+        let pivot = (column..n)
+            .max_by(|&a, &b| matrix[a][column].abs().total_cmp(&matrix[b][column].abs()))?;
+        if matrix[pivot][column].abs() < 1e-15 {
+            return None;
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        let (upper, lower) = matrix.split_at_mut(column + 1);
+        let pivot_row = &upper[column];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[column] / pivot_row[column];
+            if factor == 0_f64 {
+                continue;
+            }
+            for (value, pivot_value) in row[column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot_value;
+            }
+            rhs[column + 1 + offset] -= factor * rhs[column];
+        }
+    }
+
+    let mut solution = vec![0_f64; n];
+    for row in (0..n).rev() {
+        let sum: f64 = (row + 1..n).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smits_white_test() {
+        let spectrum = smits([1_f64, 1_f64, 1_f64]);
+        assert_eq!(spectrum.values().len(), SAMPLES);
+        assert!(spectrum
+            .values()
+            .iter()
+            .all(|&value| (value - 1_f64).abs() < 1e-3));
+
+        let spectrum = smits([0_f64, 0_f64, 0_f64]);
+        assert!(spectrum.values().iter().all(|&value| value == 0_f64));
+    }
+
+    #[test]
+    fn solve_test() {
+        let matrix = vec![vec![2_f64, 1_f64], vec![1_f64, 3_f64]];
+        let solution = solve(matrix, vec![3_f64, 5_f64]).unwrap();
+        assert!((solution[0] - 0.8).abs() < 1e-12);
+        assert!((solution[1] - 1.4).abs() < 1e-12);
+
+        assert!(solve(
+            vec![vec![1_f64, 2_f64], vec![2_f64, 4_f64]],
+            vec![1_f64, 2_f64]
+        )
+        .is_none());
+    }
+}
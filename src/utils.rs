@@ -18,3 +18,7 @@ where
 {
     value1.min(value2.min(value3))
 }
+
+pub(crate) fn float_to_u8(value: f64) -> u8 {
+    (value * 255_f64).round().clamp(0_f64, 255_f64) as u8
+}
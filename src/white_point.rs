@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use crate::chromaticity::Chromaticity;
+use crate::illuminant::{Illuminant, Observer};
+use crate::xyz::XyzColor;
+
+// CIE 1931 2° observer, normalized to Y = 1 (ASTM E308)
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum WhitePoint {
+    A,
+    D50,
+    D65,
+    E,
+    F2,
+    Custom(XyzColor),
+}
+
+impl Display for WhitePoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Custom(xyz) => write!(f, "Custom({})", xyz),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl WhitePoint {
+    pub fn from_illuminant(illuminant: Illuminant, observer: Observer) -> Option<Self> {
+        illuminant.white_point(observer).map(Self::Custom)
+    }
+
+    pub fn chromaticity(&self) -> Chromaticity {
+        Chromaticity::from_xyz(&self.xyz())
+    }
+
+    pub fn xyz(&self) -> XyzColor {
+        match self {
+            Self::A => XyzColor::new(1.09850, 1f64, 0.35585),
+            Self::D50 => XyzColor::new(0.96422, 1f64, 0.82521),
+            Self::D65 => XyzColor::new(0.95047, 1f64, 1.08883),
+            Self::E => XyzColor::new(1f64, 1f64, 1f64),
+            Self::F2 => XyzColor::new(0.99186, 1f64, 0.67393),
+            Self::Custom(xyz) => *xyz,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn white_point_xyz_test() {
+        assert_eq!(WhitePoint::D65.xyz(), XyzColor::new(0.95047, 1f64, 1.08883));
+        assert_eq!(WhitePoint::E.xyz(), XyzColor::new(1f64, 1f64, 1f64));
+
+        let custom = XyzColor::new(0.9, 1f64, 1.1);
+        assert_eq!(WhitePoint::Custom(custom).xyz(), custom);
+    }
+
+    #[test]
+    fn white_point_from_illuminant_test() {
+        let white = WhitePoint::from_illuminant(Illuminant::D55, Observer::Cie1964).unwrap();
+        let xy = white.chromaticity();
+        assert!((xy.x() - 0.33411).abs() < 1e-12);
+        assert!((xy.y() - 0.34877).abs() < 1e-12);
+
+        assert_eq!(
+            WhitePoint::from_illuminant(Illuminant::LedV1, Observer::Cie1964),
+            None
+        );
+    }
+
+    #[test]
+    fn white_point_name_test() {
+        assert_eq!(WhitePoint::D50.to_string(), "D50");
+        assert_eq!(WhitePoint::F2.to_string(), "F2");
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt::Display;
+
+use crate::chromaticity::{Chromaticity, UvChromaticity, UvPrimeChromaticity};
+use crate::xyz::XyzColor;
+
+// CIE xyY (chromaticity xy and luminance Y)
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct XyYColor {
+    x: f64,
+    y: f64,
+    luminance: f64,
+    a: f64,
+}
+
+impl Display for XyYColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "XyYColor x = {} y = {} Y = {} A = {}",
+            self.x, self.y, self.luminance, self.a
+        )
+    }
+}
+
+impl XyYColor {
+    pub fn new(x: f64, y: f64, luminance: f64) -> Self {
+        Self::from_xyya(x, y, luminance, 1f64)
+    }
+
+    pub fn from_xyya(x: f64, y: f64, luminance: f64, a: f64) -> Self {
+        Self { x, y, luminance, a }
+    }
+
+    pub fn from_xyz(color: &XyzColor) -> Self {
+        let xy = Chromaticity::from_xyz(color);
+        Self::from_xyya(xy.x(), xy.y(), color.y(), color.a())
+    }
+
+    pub fn to_xyz(&self) -> XyzColor {
+        let xyz = self.chromaticity().to_xyz(self.luminance);
+        XyzColor::from_xyza(xyz.x(), xyz.y(), xyz.z(), self.a)
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn luminance(&self) -> f64 {
+        self.luminance
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    pub fn chromaticity(&self) -> Chromaticity {
+        Chromaticity::new(self.x, self.y)
+    }
+
+    pub fn uv(&self) -> UvChromaticity {
+        self.chromaticity().to_uv()
+    }
+
+    pub fn uv_prime(&self) -> UvPrimeChromaticity {
+        self.chromaticity().to_uv_prime()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xyy_from_xyz_test() {
+        let xyy = XyYColor::from_xyz(&XyzColor::new(0.4124564, 0.2126729, 0.0193339));
+        assert!((xyy.x() - 0.64).abs() < 1e-4);
+        assert!((xyy.y() - 0.33).abs() < 1e-4);
+        assert_eq!(xyy.luminance(), 0.2126729);
+
+        let xyz = xyy.to_xyz();
+        assert!((xyz.x() - 0.4124564).abs() < 1e-12);
+        assert!((xyz.y() - 0.2126729).abs() < 1e-12);
+        assert!((xyz.z() - 0.0193339).abs() < 1e-12);
+    }
+
+    #[test]
+    fn xyy_black_test() {
+        let xyy = XyYColor::from_xyz(&XyzColor::new(0_f64, 0_f64, 0_f64));
+        assert_eq!(xyy, XyYColor::new(0_f64, 0_f64, 0_f64));
+        assert_eq!(xyy.to_xyz(), XyzColor::new(0_f64, 0_f64, 0_f64));
+    }
+}
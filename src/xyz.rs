@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct XyzColor {
+    x: f64,
+    y: f64,
+    z: f64,
+    a: f64,
+}
+
+impl Display for XyzColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "XyzColor X = {} Y = {} Z = {} A = {}",
+            self.x, self.y, self.z, self.a
+        )
+    }
+}
+
+impl XyzColor {
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Self::from_xyza(x, y, z, 1f64)
+    }
+
+    pub const fn from_xyza(x: f64, y: f64, z: f64, a: f64) -> Self {
+        Self { x, y, z, a }
+    }
+
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn z(&self) -> f64 {
+        self.z
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+
+    pub(crate) fn to_array(self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    pub(crate) fn from_array(xyz: [f64; 3], a: f64) -> Self {
+        Self::from_xyza(xyz[0], xyz[1], xyz[2], a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn xyz_new_test() {
+        let white = XyzColor::new(0.95047, 1f64, 1.08883);
+
+        assert_eq!(white.x(), 0.95047);
+        assert_eq!(white.y(), 1f64);
+        assert_eq!(white.z(), 1.08883);
+        assert_eq!(white.a(), 1f64);
+    }
+}
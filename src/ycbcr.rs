@@ -0,0 +1,235 @@
+use std::fmt::Display;
+
+use crate::float_rgb::FloatRgbColor;
+use crate::utils;
+
+pub const MIN_BIT_DEPTH: u8 = 8;
+pub const MAX_BIT_DEPTH: u8 = 16;
+
+// Y'CbCr code values. The meaning of the values (matrix, range, bit depth) is given by YCbCrFormat.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct YCbCrColor {
+    y: u16,
+    cb: u16,
+    cr: u16,
+    a: u8,
+}
+
+impl Display for YCbCrColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "YCbCrColor Y = {} Cb = {} Cr = {} A = {}",
+            self.y, self.cb, self.cr, self.a
+        )
+    }
+}
+
+impl YCbCrColor {
+    pub fn new(y: u16, cb: u16, cr: u16) -> Self {
+        Self::from_ycbcra(y, cb, cr, 255)
+    }
+
+    pub fn from_ycbcra(y: u16, cb: u16, cr: u16, a: u8) -> Self {
+        Self { y, cb, cr, a }
+    }
+
+    pub fn y(&self) -> u16 {
+        self.y
+    }
+
+    pub fn cb(&self) -> u16 {
+        self.cb
+    }
+
+    pub fn cr(&self) -> u16 {
+        self.cr
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum YCbCrMatrix {
+    // ITU-R BT.601
+    Bt601,
+    // ITU-R BT.709
+    #[default]
+    Bt709,
+    // ITU-R BT.2020 (non-constant luminance)
+    Bt2020,
+}
+
+impl YCbCrMatrix {
+    // (Kr, Kb)
+    pub fn coefficients(&self) -> (f64, f64) {
+        match self {
+            Self::Bt601 => (0.299, 0.114),
+            Self::Bt709 => (0.2126, 0.0722),
+            Self::Bt2020 => (0.2627, 0.0593),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum YCbCrRange {
+    Full,
+    // studio swing: Y 16..235, Cb/Cr 16..240 (8-bit)
+    #[default]
+    Limited,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct YCbCrFormat {
+    matrix: YCbCrMatrix,
+    range: YCbCrRange,
+    bit_depth: u8,
+}
+
+impl Default for YCbCrFormat {
+    fn default() -> Self {
+        Self::new(YCbCrMatrix::default(), YCbCrRange::default())
+    }
+}
+
+impl YCbCrFormat {
+    pub fn new(matrix: YCbCrMatrix, range: YCbCrRange) -> Self {
+        Self {
+            matrix,
+            range,
+            bit_depth: MIN_BIT_DEPTH,
+        }
+    }
+
+    pub fn with_bit_depth(self, bit_depth: u8) -> Self {
+        Self {
+            bit_depth: utils::crop_range(bit_depth, MIN_BIT_DEPTH, MAX_BIT_DEPTH),
+            ..self
+        }
+    }
+
+    pub fn matrix(&self) -> YCbCrMatrix {
+        self.matrix
+    }
+
+    pub fn range(&self) -> YCbCrRange {
+        self.range
+    }
+
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth
+    }
+
+    pub fn max_code_value(&self) -> u16 {
+        ((1_u32 << self.bit_depth) - 1) as u16
+    }
+
+    // https://www.itu.int/rec/R-REC-BT.2100 (Table 9)
+    pub fn encode(&self, color: &FloatRgbColor) -> YCbCrColor {
+        let (kr, kb) = self.matrix.coefficients();
+        let y = kr * color.r() + (1_f64 - kr - kb) * color.g() + kb * color.b();
+        let cb = (color.b() - y) / (2_f64 * (1_f64 - kb));
+        let cr = (color.r() - y) / (2_f64 * (1_f64 - kr));
+
+        let (luma_scale, luma_offset, chroma_scale) = self.quantization();
+        let chroma_offset = (1_u32 << (self.bit_depth - 1)) as f64;
+        let quantize = |value: f64| value.round().clamp(0_f64, self.max_code_value() as f64) as u16;
+
+        YCbCrColor::from_ycbcra(
+            quantize(y * luma_scale + luma_offset),
+            quantize(cb * chroma_scale + chroma_offset),
+            quantize(cr * chroma_scale + chroma_offset),
+            utils::float_to_u8(color.a()),
+        )
+    }
+
+    pub fn decode(&self, color: &YCbCrColor) -> FloatRgbColor {
+        let (kr, kb) = self.matrix.coefficients();
+        let (luma_scale, luma_offset, chroma_scale) = self.quantization();
+        let chroma_offset = (1_u32 << (self.bit_depth - 1)) as f64;
+
+        let y = (color.y() as f64 - luma_offset) / luma_scale;
+        let cb = (color.cb() as f64 - chroma_offset) / chroma_scale;
+        let cr = (color.cr() as f64 - chroma_offset) / chroma_scale;
+
+        let r = y + 2_f64 * (1_f64 - kr) * cr;
+        let b = y + 2_f64 * (1_f64 - kb) * cb;
+        let g = (y - kr * r - kb * b) / (1_f64 - kr - kb);
+
+        FloatRgbColor::from_rgba(r, g, b, color.a() as f64 / 255_f64)
+    }
+
+    // (luma scale, luma offset, chroma scale)
+    fn quantization(&self) -> (f64, f64, f64) {
+        let max = self.max_code_value() as f64;
+        match self.range {
+            YCbCrRange::Full => (max, 0_f64, max),
+            YCbCrRange::Limited => {
+                let scale = (1_u32 << (self.bit_depth - 8)) as f64;
+                (219_f64 * scale, 16_f64 * scale, 224_f64 * scale)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ycbcr_format_bit_depth_test() {
+        let format = YCbCrFormat::default();
+        assert_eq!(format.matrix(), YCbCrMatrix::Bt709);
+        assert_eq!(format.range(), YCbCrRange::Limited);
+        assert_eq!(format.bit_depth(), 8);
+        assert_eq!(format.max_code_value(), 255);
+
+        assert_eq!(format.with_bit_depth(10).max_code_value(), 1023);
+        assert_eq!(format.with_bit_depth(4).bit_depth(), 8);
+        assert_eq!(format.with_bit_depth(20).max_code_value(), 65535);
+    }
+
+    #[test]
+    fn ycbcr_format_limited_range_test() {
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt709, YCbCrRange::Limited);
+        let white = format.encode(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert_eq!(white, YCbCrColor::new(235, 128, 128));
+        let black = format.encode(&FloatRgbColor::new(0_f64, 0_f64, 0_f64));
+        assert_eq!(black, YCbCrColor::new(16, 128, 128));
+        let blue = format.encode(&FloatRgbColor::new(0_f64, 0_f64, 1_f64));
+        assert_eq!(blue.cb(), 240);
+
+        let format = format.with_bit_depth(10);
+        let white = format.encode(&FloatRgbColor::new(1_f64, 1_f64, 1_f64));
+        assert_eq!(white, YCbCrColor::new(940, 512, 512));
+        let red = format.encode(&FloatRgbColor::new(1_f64, 0_f64, 0_f64));
+        assert_eq!(red, YCbCrColor::new(250, 409, 960));
+    }
+
+    #[test]
+    fn ycbcr_format_full_range_test() {
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt601, YCbCrRange::Full);
+        let red = format.encode(&FloatRgbColor::new(1_f64, 0_f64, 0_f64));
+        assert_eq!(red, YCbCrColor::new(76, 85, 255));
+
+        let format = YCbCrFormat::new(YCbCrMatrix::Bt2020, YCbCrRange::Full).with_bit_depth(10);
+        let green = format.encode(&FloatRgbColor::new(0_f64, 1_f64, 0_f64));
+        assert_eq!(green, YCbCrColor::new(694, 143, 42));
+    }
+
+    #[test]
+    fn ycbcr_format_decode_test() {
+        for matrix in [YCbCrMatrix::Bt601, YCbCrMatrix::Bt709, YCbCrMatrix::Bt2020] {
+            for range in [YCbCrRange::Full, YCbCrRange::Limited] {
+                let format = YCbCrFormat::new(matrix, range).with_bit_depth(12);
+                let color = FloatRgbColor::new(0.25, 0.5, 0.75);
+                let decoded = format.decode(&format.encode(&color));
+                assert!((decoded.r() - color.r()).abs() < 1e-3);
+                assert!((decoded.g() - color.g()).abs() < 1e-3);
+                assert!((decoded.b() - color.b()).abs() < 1e-3);
+            }
+        }
+    }
+}
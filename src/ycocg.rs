@@ -0,0 +1,116 @@
+use std::fmt::Display;
+
+// https://en.wikipedia.org/wiki/YCoCg
+#[derive(Debug, Default, PartialEq, Clone, Copy)]
+pub struct YCoCgColor {
+    y: f64,
+    co: f64,
+    cg: f64,
+    a: f64,
+}
+
+impl Display for YCoCgColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "YCoCgColor Y = {} Co = {} Cg = {} A = {}",
+            self.y, self.co, self.cg, self.a
+        )
+    }
+}
+
+impl YCoCgColor {
+    pub fn new(y: f64, co: f64, cg: f64) -> Self {
+        Self::from_ycocga(y, co, cg, 1_f64)
+    }
+
+    pub fn from_ycocga(y: f64, co: f64, cg: f64, a: f64) -> Self {
+        Self { y, co, cg, a }
+    }
+
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+
+    pub fn co(&self) -> f64 {
+        self.co
+    }
+
+    pub fn cg(&self) -> f64 {
+        self.cg
+    }
+
+    pub fn a(&self) -> f64 {
+        self.a
+    }
+}
+
+// lifting based YCoCg-R. Co and Cg need one extra bit (-255..=255).
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct YCoCgRColor {
+    y: u8,
+    co: i16,
+    cg: i16,
+    a: u8,
+}
+
+impl Display for YCoCgRColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "YCoCgRColor Y = {} Co = {} Cg = {} A = {}",
+            self.y, self.co, self.cg, self.a
+        )
+    }
+}
+
+impl YCoCgRColor {
+    pub fn new(y: u8, co: i16, cg: i16) -> Self {
+        Self::from_ycocga(y, co, cg, 255)
+    }
+
+    pub fn from_ycocga(y: u8, co: i16, cg: i16, a: u8) -> Self {
+        Self {
+            y,
+            co: co.clamp(-255, 255),
+            cg: cg.clamp(-255, 255),
+            a,
+        }
+    }
+
+    pub fn y(&self) -> u8 {
+        self.y
+    }
+
+    pub fn co(&self) -> i16 {
+        self.co
+    }
+
+    pub fn cg(&self) -> i16 {
+        self.cg
+    }
+
+    pub fn a(&self) -> u8 {
+        self.a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ycocg_new_test() {
+        let color = YCoCgColor::new(0.5, 0.25, -0.25);
+        assert_eq!(color.y(), 0.5);
+        assert_eq!(color.co(), 0.25);
+        assert_eq!(color.cg(), -0.25);
+        assert_eq!(color.a(), 1_f64);
+    }
+
+    #[test]
+    fn ycocg_r_new_test() {
+        let color = YCoCgRColor::from_ycocga(10, -300, 300, 128);
+        assert_eq!(color, YCoCgRColor::from_ycocga(10, -255, 255, 128));
+    }
+}
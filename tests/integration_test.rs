@@ -1,5 +1,12 @@
 extern crate irospace;
 use irospace::{colors::Colors, converter::*, ColorConverterBuilder};
+use irospace::{hald_identity, Curve, Curves, Levels, Lut1D, Lut3D, LutInterpolation};
+use irospace::{
+    BlackGeneration, CmykColor, CmykSeparation, FloatRgbColor, PigmentMixer, RgbColor,
+    SampledSpectrum,
+};
+use irospace::{Chromaticity, ParametricCurve, RgbSpace, TransferFunction, WhitePoint};
+use irospace::{IccProfile, IccProfileWriter, IccTransform, IccVersion, RenderingIntent};
 
 #[test]
 fn colors_test() {
@@ -74,3 +81,326 @@ fn common_test_from_hsl_to_hsv() {
         Colors::Red.to_hsv()
     );
 }
+
+#[test]
+fn common_test_from_rgb_to_hwb() {
+    let converter = ColorConverterBuilder::new().from_rgb().to_hwb().build();
+    assert_eq!(
+        converter.convert(&Colors::Red.to_rgb()).unwrap(),
+        Colors::Red.to_hwb()
+    );
+}
+
+#[test]
+fn common_test_from_hwb_to_rgb() {
+    let converter = ColorConverterBuilder::new().from_hwb().to_rgb().build();
+    assert_eq!(
+        converter.convert(&Colors::Red.to_hwb()).unwrap(),
+        Colors::Red.to_rgb()
+    );
+}
+
+#[test]
+fn common_test_from_hwb_to_hsv() {
+    let converter = ColorConverterBuilder::new().from_hwb().to_hsv().build();
+    assert_eq!(
+        converter.convert(&Colors::Red.to_hwb()).unwrap(),
+        Colors::Red.to_hsv()
+    );
+}
+
+#[test]
+fn common_test_from_hsl_to_hwb() {
+    let converter = ColorConverterBuilder::new().from_hsl().to_hwb().build();
+    assert_eq!(
+        converter.convert(&Colors::Red.to_hsl()).unwrap(),
+        Colors::Red.to_hwb()
+    );
+}
+
+#[test]
+fn common_test_custom_rgb_space() {
+    let panel = RgbSpace::new(
+        Chromaticity::new(0.700, 0.295),
+        Chromaticity::new(0.165, 0.735),
+        Chromaticity::new(0.135, 0.045),
+        WhitePoint::D65,
+        TransferFunction::Parametric(ParametricCurve::gamma(2.2).unwrap()),
+    )
+    .unwrap();
+
+    let to_panel = ColorConverterBuilder::new()
+        .from_rgb()
+        .to_rgb_space(panel.clone())
+        .build();
+    let from_panel = ColorConverterBuilder::new()
+        .from_rgb_space(panel)
+        .to_rgb()
+        .build();
+
+    let color = to_panel.convert(&Colors::Orange.to_rgb()).unwrap();
+    assert_eq!(from_panel.convert(&color).unwrap(), Colors::Orange.to_rgb());
+}
+
+#[test]
+fn common_test_cmyk() {
+    let separation = CmykSeparation::new()
+        .with_black_generation(BlackGeneration::UnderColorRemoval {
+            threshold: 0.5,
+            amount: 1.0,
+        })
+        .with_ink_limit(300);
+    let converter = RgbToCmykConverter::new().with_separation(separation);
+
+    let cmyk = converter.convert(&Colors::Navy.to_rgb()).unwrap();
+    assert!(cmyk.total_ink() <= 300);
+
+    let converter = ColorConverterBuilder::new().from_cmyk().to_rgb().build();
+    assert_eq!(
+        converter.convert(&CmykColor::new(0, 0, 0, 100)).unwrap(),
+        Colors::Black.to_rgb()
+    );
+}
+
+#[test]
+fn common_test_spectral_reflectance() {
+    // spectrophotometer reading (380nm - 730nm, 10nm)
+    let gray = SampledSpectrum::new(380_f64, 10_f64, vec![0.18; 36]).unwrap();
+    let converter = ReflectanceToRgbConverter::new();
+    let rgb = converter.convert(&gray).unwrap();
+    assert_eq!(rgb.r(), rgb.g());
+    assert_eq!(rgb.g(), rgb.b());
+    assert_eq!(rgb.r(), 118);
+}
+
+#[test]
+fn common_test_pigment_mixing() {
+    let blue = RgbColor::new(0, 0, 255);
+    let yellow = RgbColor::new(255, 255, 0);
+    let mixer = PigmentMixer::new();
+    let mixed = mixer.mix(&blue, &yellow, 0.5).unwrap();
+    assert!(mixed.g() > mixed.r() && mixed.g() > mixed.b(), "{}", mixed);
+
+    let reflectance = RgbToReflectanceConverter::new().convert(&yellow).unwrap();
+    let rgb = ReflectanceToRgbConverter::new()
+        .convert(&reflectance)
+        .unwrap();
+    assert_eq!(rgb, yellow);
+}
+
+#[test]
+fn common_test_aces() {
+    let to_acescct = ColorConverterBuilder::new()
+        .from_rgb()
+        .to_rgb_space(RgbSpace::acescct())
+        .build();
+    let from_acescct = ColorConverterBuilder::new()
+        .from_rgb_space(RgbSpace::acescct())
+        .to_rgb()
+        .build();
+    let color = to_acescct.convert(&Colors::Orange.to_rgb()).unwrap();
+    assert_eq!(
+        from_acescct.convert(&color).unwrap(),
+        Colors::Orange.to_rgb()
+    );
+
+    // linear sRGB => ACEScg working space => ACES2065-1 interchange
+    let to_acescg = RgbSpaceToRgbSpaceConverter::new(RgbSpace::linear_srgb(), RgbSpace::acescg());
+    let to_aces = RgbSpaceToRgbSpaceConverter::new(RgbSpace::acescg(), RgbSpace::aces2065_1());
+    let gray = FloatRgbColor::new(0.18, 0.18, 0.18);
+    let aces = to_aces.convert(&to_acescg.convert(&gray).unwrap()).unwrap();
+    assert!((aces.r() - 0.18).abs() < 1e-9);
+    assert!((aces.b() - 0.18).abs() < 1e-9);
+}
+
+#[test]
+fn common_test_camera_log() {
+    // 10 bit code values of a middle gray chart in each camera's log encoding
+    for (space, code) in [
+        (RgbSpace::s_gamut3_s_log3(), 420_f64),
+        (RgbSpace::arri_wide_gamut3_logc3(), 400_f64),
+        (RgbSpace::v_gamut_v_log(), 433_f64),
+        (RgbSpace::cinema_gamut_canon_log(), 351_f64),
+        (RgbSpace::red_wide_gamut_log3g10(), 341_f64),
+    ] {
+        let value = code / 1023_f64;
+        let frame = FloatRgbColor::new(value, value, value);
+        let xyz = RgbSpaceToXyzConverter::new(space.clone())
+            .convert(&frame)
+            .unwrap();
+        assert!(
+            (xyz.y() - 0.18).abs() < 0.01,
+            "{:?} {}",
+            space.transfer(),
+            xyz
+        );
+
+        let rgb = RgbSpaceToRgbConverter::new(space).convert(&frame).unwrap();
+        assert!(rgb.r().abs_diff(118) <= 3, "{}", rgb);
+        assert!(rgb.r().abs_diff(rgb.b()) <= 1, "{}", rgb);
+    }
+}
+
+#[test]
+fn common_test_srgb_white() {
+    // the sRGB space and the XYZ converters share one white
+    let white = RgbColor::new(255, 255, 255);
+    let expected = RgbToXyzConverter {}.convert(&white).unwrap();
+    let actual = ColorConverterBuilder::new()
+        .from_rgb_space(RgbSpace::srgb())
+        .to_xyz()
+        .build()
+        .convert(&FloatRgbColor::new(1_f64, 1_f64, 1_f64))
+        .unwrap();
+    assert!((actual.x() - expected.x()).abs() < 1e-9, "{}", actual);
+    assert!((actual.y() - expected.y()).abs() < 1e-9, "{}", actual);
+    assert!((actual.z() - expected.z()).abs() < 1e-9, "{}", actual);
+    assert_eq!(RgbSpace::srgb().white_point(), WhitePoint::D65);
+}
+
+#[test]
+fn common_test_lut3d() {
+    // bake a builder pipeline into a LUT
+    let converter = ColorConverterBuilder::new()
+        .from_rgb_space(RgbSpace::srgb())
+        .to_rgb_space(RgbSpace::display_p3())
+        .build();
+    let lut = Lut3D::from_converter(33, &converter)
+        .unwrap()
+        .with_title("sRGB to Display P3");
+
+    let path = std::env::temp_dir().join(format!("irospace_{}.cube", std::process::id()));
+    lut.write_cube(&path).unwrap();
+    let loaded = Lut3D::read_cube(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, lut);
+    assert!(Lut3D::read_cube(&path).is_err());
+
+    // pixel buffer
+    let lut = Lut3D::from_rgb_converter(18, &RgbToRgbConverter {})
+        .unwrap()
+        .with_interpolation(LutInterpolation::Trilinear);
+    let pixels = [
+        Colors::Orange.to_rgb(),
+        Colors::Teal.to_rgb(),
+        RgbColor::from_rgba(10, 20, 30, 40),
+    ];
+    let mut output = [RgbColor::default(); 3];
+    lut.convert_slice(&pixels, &mut output).unwrap();
+    assert_eq!(output, pixels);
+    assert!(lut.convert_slice(&pixels, &mut output[..2]).is_err());
+}
+
+#[test]
+fn common_test_curves_and_levels() {
+    let curves = Curves::new()
+        .with_rgb(Curve::new(&[(0_f64, 0_f64), (0.25, 0.2), (0.75, 0.8), (1_f64, 1_f64)]).unwrap())
+        .with_blue(Curve::new(&[(0_f64, 0.1), (1_f64, 0.9)]).unwrap());
+    let levels = Levels::new().with_input(0.05, 0.95).with_gamma(1.2);
+
+    // bake both adjustments into a 1D LUT for the image editor
+    let lut = Lut1D::from_fn(4096, |color| Ok(curves.apply(&levels.apply(color)))).unwrap();
+
+    let pixels = [
+        Colors::Orange.to_rgb(),
+        Colors::Navy.to_rgb(),
+        RgbColor::from_rgba(30, 120, 220, 0),
+    ];
+    let mut expected = [RgbColor::default(); 3];
+    let mut adjusted = [RgbColor::default(); 3];
+    levels.convert_slice(&pixels, &mut adjusted).unwrap();
+    curves.convert_slice(&adjusted, &mut expected).unwrap();
+    let mut baked = [RgbColor::default(); 3];
+    lut.convert_slice(&pixels, &mut baked).unwrap();
+    for (actual, expected) in baked.iter().zip(expected) {
+        assert!(
+            actual.r().abs_diff(expected.r()) <= 1,
+            "{} {}",
+            actual,
+            expected
+        );
+        assert!(
+            actual.g().abs_diff(expected.g()) <= 1,
+            "{} {}",
+            actual,
+            expected
+        );
+        assert!(
+            actual.b().abs_diff(expected.b()) <= 1,
+            "{} {}",
+            actual,
+            expected
+        );
+        assert_eq!(actual.a(), expected.a());
+    }
+}
+
+#[test]
+fn common_test_hald_clut() {
+    // a warm film preset edited on the identity image (level 8, 512 x 512)
+    let mut preset = hald_identity(8).unwrap();
+    assert_eq!(preset.len(), 512 * 512 * 3);
+    for pixel in preset.chunks_exact_mut(3) {
+        pixel[0] = pixel[0].saturating_add(10);
+        pixel[2] = pixel[2].saturating_sub(10);
+    }
+
+    let lut = Lut3D::from_hald(8, &preset).unwrap();
+    let pixels = [Colors::Gray.to_rgb(), Colors::Silver.to_rgb()];
+    let mut output = [RgbColor::default(); 2];
+    lut.convert_slice(&pixels, &mut output).unwrap();
+    for (actual, color) in output.iter().zip(pixels) {
+        assert!(actual.r().abs_diff(color.r() + 10) <= 1, "{}", actual);
+        assert!(actual.g().abs_diff(color.g()) <= 1, "{}", actual);
+        assert!(actual.b().abs_diff(color.b() - 10) <= 1, "{}", actual);
+    }
+}
+
+#[test]
+fn common_test_icc_writer() {
+    // a display measured by a calibration tool
+    let display = RgbSpace::new(
+        Chromaticity::new(0.681, 0.317),
+        Chromaticity::new(0.266, 0.691),
+        Chromaticity::new(0.151, 0.056),
+        WhitePoint::Custom(Chromaticity::new(0.3134, 0.3291).to_xyz(1_f64)),
+        TransferFunction::Gamma(2.2),
+    )
+    .unwrap();
+
+    for version in [IccVersion::V4, IccVersion::V2] {
+        let path =
+            std::env::temp_dir().join(format!("irospace_{}_{:?}.icc", std::process::id(), version));
+        IccProfileWriter::new()
+            .with_version(version)
+            .with_description("Calibrated display")
+            .write(&display, &path)
+            .unwrap();
+        let profile = IccProfile::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(profile.description().unwrap(), "Calibrated display");
+
+        // the embedded space round trips through the converters
+        let space = profile.to_rgb_space().unwrap();
+        assert!((space.red().x() - 0.681).abs() < 1e-4);
+        assert!((space.white_point().chromaticity().x() - 0.3134).abs() < 1e-4);
+        let to_display = ColorConverterBuilder::new()
+            .from_rgb()
+            .to_rgb_space(space.clone())
+            .build();
+        let from_display = ColorConverterBuilder::new()
+            .from_rgb_space(space)
+            .to_rgb()
+            .build();
+        for color in [Colors::Orange.to_rgb(), Colors::Teal.to_rgb()] {
+            let encoded = to_display.convert(&color).unwrap();
+            assert_eq!(from_display.convert(&encoded).unwrap(), color);
+        }
+
+        let transform =
+            IccTransform::to_rgb_space(&profile, &RgbSpace::srgb(), RenderingIntent::Perceptual)
+                .unwrap();
+        let white: RgbColor = transform.convert(&Colors::White.to_rgb()).unwrap();
+        assert_eq!(white, Colors::White.to_rgb());
+    }
+}